@@ -5,11 +5,14 @@ use std::process::Command;
 // CPU features we want to detect
 #[derive(PartialEq, Eq, Debug)]
 struct CpuFeature {
+    // Name of the feature in `CARGO_CFG_TARGET_FEATURE`
     name: &'static str,
-    rustc_flag: &'static str,
-    cfg_flag: &'static str,
+    // Value of `CARGO_CFG_TARGET_ARCH` the feature belongs to
+    arch: &'static str,
+    // Backend cfg emitted when this is the best detected feature,
+    // `None` for extensions that do not select a backend on their own
+    cfg_flag: Option<&'static str>,
     detected: bool,
-    nightly_only: bool,
 }
 
 impl CpuFeature {
@@ -18,70 +21,85 @@ impl CpuFeature {
         match self.name {
             "avx512f" => 0,
             "avx2" => 1,
-            "sse41" => 2,
+            "sse4.1" => 2,
             _ => usize::MAX, // lowest priority by default
         }
     }
 
     // Groups all supported CPU features that use optimizations in this crate
-    // used in stable build
     fn features() -> Vec<CpuFeature> {
         vec![
             CpuFeature {
-                name: "sse41",
-                rustc_flag: "+sse4.1",
-                cfg_flag: "sse",
+                name: "sse4.1",
+                arch: "x86_64",
+                cfg_flag: Some("sse"),
+                detected: false,
+            },
+            CpuFeature {
+                name: "avx512f",
+                arch: "x86_64",
+                cfg_flag: Some("avx512"),
                 detected: false,
-                nightly_only: false,
             },
             CpuFeature {
                 name: "avx2",
-                rustc_flag: "+avx2",
-                cfg_flag: "avx2",
+                arch: "x86_64",
+                cfg_flag: Some("avx2"),
                 detected: false,
-                nightly_only: false,
             },
             CpuFeature {
                 name: "neon",
-                rustc_flag: "+neon",
-                cfg_flag: "neon",
+                arch: "aarch64",
+                cfg_flag: Some("neon"),
                 detected: false,
-                nightly_only: false,
             },
-        ]
-    }
-
-    // Groups all supported CPU features that use optimizations in this crate
-    // used in stable build
-    fn nightly_features() -> Vec<CpuFeature> {
-        vec![
             CpuFeature {
-                name: "sse41",
-                rustc_flag: "+sse4.1",
-                cfg_flag: "sse",
+                name: "fma",
+                arch: "x86_64",
+                cfg_flag: None,
                 detected: false,
-                nightly_only: false,
             },
             CpuFeature {
-                name: "avx512f",
-                rustc_flag: "+avx512f",
-                cfg_flag: "avx512",
+                name: "f16c",
+                arch: "x86_64",
+                cfg_flag: None,
                 detected: false,
-                nightly_only: true,
             },
             CpuFeature {
-                name: "avx2",
-                rustc_flag: "+avx2",
-                cfg_flag: "avx2",
+                name: "bmi2",
+                arch: "x86_64",
+                cfg_flag: None,
                 detected: false,
-                nightly_only: false,
             },
             CpuFeature {
-                name: "neon",
-                rustc_flag: "+neon",
-                cfg_flag: "neon",
+                name: "avx512bw",
+                arch: "x86_64",
+                cfg_flag: None,
+                detected: false,
+            },
+            CpuFeature {
+                name: "avx512vl",
+                arch: "x86_64",
+                cfg_flag: None,
+                detected: false,
+            },
+            CpuFeature {
+                name: "avx512dq",
+                arch: "x86_64",
+                cfg_flag: None,
+                detected: false,
+            },
+            CpuFeature {
+                name: "fp16",
+                arch: "aarch64",
+                cfg_flag: None,
+                detected: false,
+            },
+            CpuFeature {
+                name: "dotprod",
+                arch: "aarch64",
+                cfg_flag: None,
                 detected: false,
-                nightly_only: false,
             },
         ]
     }
@@ -99,125 +117,58 @@ impl PartialOrd for CpuFeature {
     }
 }
 
-// Feature detection trait to make implementations more modular
-trait CpuFeatureDetector {
-    fn detect_features(&self, features: &mut [CpuFeature]);
-    fn is_applicable(&self) -> bool;
-}
-
-// Linux CPU feature detector
-struct LinuxDetector;
-impl CpuFeatureDetector for LinuxDetector {
-    fn detect_features(&self, features: &mut [CpuFeature]) {
-        if let Ok(cpuinfo) = std::fs::read_to_string("/proc/cpuinfo") {
-            let contents = cpuinfo.to_lowercase();
-            for feature in features.iter_mut() {
-                feature.detected = contents.contains(feature.name);
-            }
-        }
-    }
-
-    fn is_applicable(&self) -> bool {
-        cfg!(target_os = "linux")
-    }
-}
-
-// macOS CPU feature detector
-struct MacOSDetector;
-impl CpuFeatureDetector for MacOSDetector {
-    fn detect_features(&self, features: &mut [CpuFeature]) {
-        let output = Command::new("sysctl").args(["-a"]).output();
-
-        if let Ok(output) = output {
-            let contents = String::from_utf8_lossy(&output.stdout).to_lowercase();
-
-            for feature in features.iter_mut() {
-                match feature.name {
-                    "avx512f" => feature.detected = contents.contains("hw.optional.avx512f: 1"),
-                    "avx2" => feature.detected = contents.contains("hw.optional.avx2: 1"),
-                    "sse41" => feature.detected = contents.contains("hw.optional.sse4_1: 1"),
-                    "neon" => feature.detected = contents.contains("hw.optional.neon: 1"),
-                    _ => {}
-                }
-            }
-        }
-    }
-
-    fn is_applicable(&self) -> bool {
-        cfg!(target_os = "macos")
+// Marks the features Cargo enables for the compilation target, derived from
+// the target triple, `-C target-cpu` and `-C target-feature`. Unlike probing
+// the build host, this gives the same result for native and cross builds.
+fn detect_cpu_features(features: &mut [CpuFeature]) {
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let target_features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+
+    for feature in features.iter_mut() {
+        feature.detected = feature.arch == arch
+            && target_features
+                .split(',')
+                .any(|target_feature| target_feature == feature.name);
     }
 }
 
-// Factory that creates the appropriate detector for the current OS
-struct PlatformDetector;
-impl PlatformDetector {
-    fn cpu_features_detectors() -> Vec<Box<dyn CpuFeatureDetector>> {
-        vec![Box::new(LinuxDetector), Box::new(MacOSDetector)]
-    }
+fn compiler_channel() -> String {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let output = Command::new(rustc)
+        .args(["--version", "--verbose"])
+        .output()
+        .expect("Failed to execute rustc");
 
-    fn compiler_channel() -> String {
-        let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
-        let output = Command::new(rustc)
-            .args(["--version", "--verbose"])
-            .output()
-            .expect("Failed to execute rustc");
-
-        let version_info = String::from_utf8_lossy(&output.stdout);
-
-        if version_info.contains("nightly") {
-            "nightly".to_string()
-        } else if version_info.contains("beta") {
-            "beta".to_string()
-        } else {
-            "stable".to_string()
-        }
-    }
+    let version_info = String::from_utf8_lossy(&output.stdout);
 
-    fn detect_cpu_features(features: &mut [CpuFeature]) {
-        // Get detectors for all supported platforms
-        let detectors = Self::cpu_features_detectors();
-
-        // Find the applicable detector and use it
-        for detector in detectors {
-            if detector.is_applicable() {
-                detector.detect_features(features);
-                break;
-            }
-        }
+    if version_info.contains("nightly") {
+        "nightly".to_string()
+    } else if version_info.contains("beta") {
+        "beta".to_string()
+    } else {
+        "stable".to_string()
     }
 }
 
 fn main() {
     // Detect rustc channel (stable, beta, nightly)
-    let rustc_channel = PlatformDetector::compiler_channel();
+    let rustc_channel = compiler_channel();
 
-    // Create a flag for modules that can be used in nighlty build only
-    // Some features like avx512 are available only with nighlty build
+    // Expose the channel to the crate, reported by `backend_info`
     println!("cargo:rustc-cfg=rustc_channel=\"{}\"", rustc_channel);
     // Disable flag warnings for build
-    println!("cargo::rustc-check-cfg=cfg(rustc_channel, values(\"nightly\", \"stable\"))");
-
-    let nightly_build = rustc_channel == "nightly";
+    println!(
+        "cargo::rustc-check-cfg=cfg(rustc_channel, values(\"nightly\", \"beta\", \"stable\"))"
+    );
 
     // Define the CPU features we're interested in
-    let mut features = if nightly_build {
-        CpuFeature::nightly_features()
-    } else {
-        CpuFeature::features()
-    };
-
-    // Determine if we're cross-compiling
-    let host = env::var("HOST").unwrap_or_default();
-    let target = env::var("TARGET").unwrap_or_default();
+    let mut features = CpuFeature::features();
 
-    let is_native_build = host == target;
+    // Features come from the target, so cross builds are detected the same way
+    detect_cpu_features(&mut features);
 
-    // Only run CPU detection for native builds
-    if is_native_build {
-        PlatformDetector::detect_cpu_features(&mut features);
-    }
-
-    // Pass RUSTFLAGS for enabling target features
+    // Emit the cfg of the best backend the target features guarantee,
+    // and one `cpu_feature = "..."` cfg per detected feature
     apply_detected_cpu_features(&mut features);
 }
 
@@ -227,21 +178,34 @@ fn apply_detected_cpu_features(features: &mut [CpuFeature]) {
 
     // Find and use the highest detected feature (if any)
     // if no feature is detected, use baseline implementation
+    // Wider backends can still be picked at runtime by `Backend::detect`
     let cfg_flag = features
         .iter()
-        .find(|cpu_feature| cpu_feature.detected)
-        .map(|cpu_feature| {
-            println!("cargo:rustc-flag=-C");
-            println!("cargo:rustc-flag=target-feature={}", cpu_feature.rustc_flag);
-            cpu_feature.cfg_flag
-        })
-        .unwrap_or_else(|| "baseline");
+        .filter(|cpu_feature| cpu_feature.detected)
+        .find_map(|cpu_feature| cpu_feature.cfg_flag)
+        .unwrap_or("baseline");
 
     println!("cargo:rustc-cfg={}", cfg_flag);
 
+    // Expose every detected feature, so kernels can combine them
+    // e.g. `#[cfg(all(avx2, cpu_feature = "fma"))]`, read by `CpuFeatures::compiled`
+    for cpu_feature in features.iter().filter(|cpu_feature| cpu_feature.detected) {
+        println!("cargo:rustc-cfg=cpu_feature=\"{}\"", cpu_feature.name);
+    }
+
+    let names: Vec<String> = features
+        .iter()
+        .map(|cpu_feature| format!("\"{}\"", cpu_feature.name))
+        .collect();
+    println!(
+        "cargo::rustc-check-cfg=cfg(cpu_feature, values({}))",
+        names.join(", ")
+    );
+
     // Avoid `#[cfg(...)]` on different architectures implementations
     println!("cargo::rustc-check-cfg=cfg(avx512)");
     println!("cargo::rustc-check-cfg=cfg(avx2)");
     println!("cargo::rustc-check-cfg=cfg(sse)");
     println!("cargo::rustc-check-cfg=cfg(neon)");
+    println!("cargo::rustc-check-cfg=cfg(baseline)");
 }
@@ -0,0 +1,515 @@
+use std::mem::MaybeUninit;
+
+use rayon::prelude::*;
+
+use crate::error::ArithmeticsError;
+use crate::ops::add::{AddOp, SimdAdd};
+use crate::ops::dispatch::{
+    binary_scalar_slice_into, binary_slice_scalar_into, binary_slices_into, collect_uninit,
+    BinaryOp, PAR_CHUNK,
+};
+use crate::ops::div::{DivOp, SimdDiv};
+use crate::ops::expr::expr;
+use crate::ops::max::{MaxOp, SimdMax};
+use crate::ops::min::{MinOp, SimdMin};
+use crate::ops::mul::{MulOp, SimdMul};
+use crate::ops::strided::StridedSlice;
+use crate::ops::sub::{SimdSub, SubOp};
+
+/// An N-dimensional array of f32, a shape and strides over a `Vec<f32>`
+///
+/// The arithmetic traits follow NumPy broadcasting: shapes are aligned on their
+/// last axis, and axes of size 1 (or missing) are stretched to the size of the
+/// other operand, e.g. `(N, M) + (M,)` adds the row to every row of the matrix.
+/// Results are always row-major (C order).
+#[derive(Clone, Debug)]
+pub struct NdArray {
+    data: Vec<f32>,
+    shape: Vec<usize>,
+    // In elements, one per axis
+    strides: Vec<usize>,
+}
+
+impl NdArray {
+    /// Row-major array of the given shape over `data`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape does not have `data.len()` elements.
+    pub fn new(data: Vec<f32>, shape: &[usize]) -> Self {
+        Self::try_new(data, shape).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Row-major array of the given shape over `data`
+    ///
+    /// Fails with `InvalidShape` instead of panicking if the shape does not
+    /// have `data.len()` elements.
+    pub fn try_new(data: Vec<f32>, shape: &[usize]) -> Result<Self, ArithmeticsError> {
+        let size = shape.iter().product();
+
+        if size != data.len() {
+            return Err(ArithmeticsError::InvalidShape {
+                len: data.len(),
+                size,
+            });
+        }
+
+        Ok(Self {
+            data,
+            shape: shape.to_vec(),
+            strides: row_major_strides(shape),
+        })
+    }
+
+    /// Size of every axis
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Distance between two consecutive elements of every axis, in f32
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// Number of axes
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the elements are stored in row-major order
+    pub fn is_contiguous(&self) -> bool {
+        let mut expected = 1;
+
+        for (&size, &stride) in self.shape.iter().zip(&self.strides).rev() {
+            // The stride of a single element axis is never used
+            if size != 1 && stride != expected {
+                return false;
+            }
+            expected *= size;
+        }
+
+        true
+    }
+
+    /// Elements in row-major order, `None` if the array is not contiguous
+    pub fn as_slice(&self) -> Option<&[f32]> {
+        if self.is_contiguous() {
+            Some(&self.data)
+        } else {
+            None
+        }
+    }
+
+    /// Element at `index`, `None` if out of bounds
+    pub fn get(&self, index: &[usize]) -> Option<f32> {
+        if index.len() != self.ndim() {
+            return None;
+        }
+
+        let mut offset = 0;
+
+        for ((&i, &size), &stride) in index.iter().zip(&self.shape).zip(&self.strides) {
+            if i >= size {
+                return None;
+            }
+            offset += i * stride;
+        }
+
+        Some(self.data[offset])
+    }
+
+    /// Reverses the axes, without moving the elements
+    pub fn transpose(mut self) -> Self {
+        self.shape.reverse();
+        self.strides.reverse();
+
+        self
+    }
+
+    /// Copies the elements into a `Vec`, in row-major order
+    pub fn to_vec(&self) -> Vec<f32> {
+        if let Some(data) = self.as_slice() {
+            return data.to_vec();
+        }
+
+        let len = self.len();
+        let mut out = Vec::with_capacity(len);
+        let mut index = vec![0; self.ndim()];
+        let mut offset = 0;
+
+        for _ in 0..len {
+            out.push(self.data[offset]);
+
+            // Moves to the next index, carrying over the full axes
+            for axis in (0..self.ndim()).rev() {
+                index[axis] += 1;
+                offset += self.strides[axis];
+
+                if index[axis] < self.shape[axis] {
+                    break;
+                }
+
+                offset -= index[axis] * self.strides[axis];
+                index[axis] = 0;
+            }
+        }
+
+        out
+    }
+
+    fn operand(&self) -> Operand<'_> {
+        Operand {
+            data: &self.data,
+            shape: &self.shape,
+            strides: &self.strides,
+        }
+    }
+}
+
+impl From<Vec<f32>> for NdArray {
+    /// One dimensional array over `data`
+    fn from(data: Vec<f32>) -> Self {
+        let shape = [data.len()];
+
+        Self::new(data, &shape)
+    }
+}
+
+fn row_major_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![0; shape.len()];
+    let mut stride = 1;
+
+    for (s, &size) in strides.iter_mut().zip(shape).rev() {
+        *s = stride;
+        stride *= size;
+    }
+
+    strides
+}
+
+// Operand of a broadcast operation, scalars are 0-d operands
+#[derive(Copy, Clone)]
+struct Operand<'a> {
+    data: &'a [f32],
+    shape: &'a [usize],
+    strides: &'a [usize],
+}
+
+impl<'a> Operand<'a> {
+    fn scalar(value: &'a f32) -> Self {
+        Operand {
+            data: std::slice::from_ref(value),
+            shape: &[],
+            strides: &[],
+        }
+    }
+
+    // Size of `axis` of a `ndim` broadcast shape, 1 for the missing leading axes
+    fn size_at(&self, ndim: usize, axis: usize) -> usize {
+        let pad = ndim - self.shape.len();
+
+        if axis < pad {
+            1
+        } else {
+            self.shape[axis - pad]
+        }
+    }
+
+    // Stride of `axis` of a `ndim` broadcast shape, 0 for stretched axes
+    fn stride_at(&self, ndim: usize, axis: usize) -> usize {
+        if self.size_at(ndim, axis) == 1 {
+            0
+        } else {
+            self.strides[axis - (ndim - self.shape.len())]
+        }
+    }
+}
+
+fn broadcast_shape(left: Operand, right: Operand) -> Result<Vec<usize>, ArithmeticsError> {
+    let ndim = left.shape.len().max(right.shape.len());
+
+    (0..ndim)
+        .map(
+            |axis| match (left.size_at(ndim, axis), right.size_at(ndim, axis)) {
+                (l, r) if l == r => Ok(l),
+                (1, r) => Ok(r),
+                (l, 1) => Ok(l),
+                (l, r) => Err(ArithmeticsError::BroadcastMismatch {
+                    axis,
+                    left: l,
+                    right: r,
+                }),
+            },
+        )
+        .collect()
+}
+
+// Axes of the loop over a row-major output as `(size, left stride, right stride)`,
+// single element axes are dropped and axes contiguous in both operands merged
+fn loop_axes(shape: &[usize], left: Operand, right: Operand) -> Vec<(usize, usize, usize)> {
+    let ndim = shape.len();
+    let mut axes: Vec<(usize, usize, usize)> = Vec::with_capacity(ndim);
+
+    for (axis, &size) in shape.iter().enumerate() {
+        if size == 1 {
+            continue;
+        }
+
+        let (l, r) = (left.stride_at(ndim, axis), right.stride_at(ndim, axis));
+
+        match axes.last_mut() {
+            // The previous axis steps over whole runs of this one
+            Some(last) if last.1 == l * size && last.2 == r * size => {
+                *last = (last.0 * size, l, r);
+            }
+            _ => axes.push((size, l, r)),
+        }
+    }
+
+    if axes.is_empty() {
+        axes.push((1, 0, 0));
+    }
+
+    axes
+}
+
+// Offsets in both operands of the run number `index` of the innermost axis
+fn run_offsets(outer: &[(usize, usize, usize)], mut index: usize) -> (usize, usize) {
+    let (mut left, mut right) = (0, 0);
+
+    for &(size, l, r) in outer.iter().rev() {
+        let i = index % size;
+        index /= size;
+
+        left += i * l;
+        right += i * r;
+    }
+
+    (left, right)
+}
+
+// View of `len` elements spaced `stride` apart, starting at `offset`
+fn strided(data: &[f32], offset: usize, stride: usize, len: usize) -> StridedSlice<'_> {
+    StridedSlice::new(&data[offset..offset + (len - 1) * stride + 1], stride)
+}
+
+// Writes one run of `left op right`, through the kernel matching the strides
+fn binary_run<O: BinaryOp>(
+    (left, l, ls): (&[f32], usize, usize),
+    (right, r, rs): (&[f32], usize, usize),
+    out: &mut [MaybeUninit<f32>],
+) {
+    let n = out.len();
+
+    match (ls, rs) {
+        (1, 1) => binary_slices_into::<O>(&left[l..l + n], &right[r..r + n], out),
+        (0, 1) => binary_scalar_slice_into::<O>(left[l], &right[r..r + n], out),
+        (1, 0) => binary_slice_scalar_into::<O>(&left[l..l + n], right[r], out),
+        (0, 0) => {
+            expr(left[l]).binary::<_, O>(right[r]).eval_into_uninit(out);
+        }
+        (0, _) => {
+            expr(left[l])
+                .binary::<_, O>(strided(right, r, rs, n))
+                .eval_into_uninit(out);
+        }
+        (_, 0) => {
+            expr(strided(left, l, ls, n))
+                .binary::<_, O>(right[r])
+                .eval_into_uninit(out);
+        }
+        _ => {
+            expr(strided(left, l, ls, n))
+                .binary::<_, O>(strided(right, r, rs, n))
+                .eval_into_uninit(out);
+        }
+    }
+}
+
+// Short runs of an operand broadcast along the next axis, e.g. the `(M,)` rows of
+// `(N, M) + (M,)`, are repeated into a tile of about this many elements, so a single
+// kernel call covers several runs
+const TILE_LEN: usize = 1024;
+
+// Operand repeated into a tile, see `TILE_LEN`
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Left,
+    Right,
+}
+
+fn tile_side(outer: &[(usize, usize, usize)], run: usize, ls: usize, rs: usize) -> Option<Tile> {
+    let &(_, l, r) = outer.last()?;
+
+    if 2 * run > TILE_LEN {
+        return None;
+    }
+
+    // The other operand must be contiguous over consecutive runs, like the output
+    match (ls, rs) {
+        (1, _) if l == run && r == 0 => Some(Tile::Right),
+        (_, 1) if r == run && l == 0 => Some(Tile::Left),
+        _ => None,
+    }
+}
+
+// Writes the runs of `out` starting at run number `first`, several at once
+// against a tile of the broadcast operand
+fn tiled_runs<O: BinaryOp>(
+    side: Tile,
+    outer: &[(usize, usize, usize)],
+    run: usize,
+    (left, ls): (Operand, usize),
+    (right, rs): (Operand, usize),
+    first: usize,
+    out: &mut [MaybeUninit<f32>],
+) {
+    let (axis_size, _, _) = outer[outer.len() - 1];
+    let rows = TILE_LEN / run;
+    let runs = out.len() / run;
+
+    let mut tile = Vec::with_capacity(rows * run);
+    let mut tile_offset = None;
+    let mut done = 0;
+
+    while done < runs {
+        let index = first + done;
+        let (l, r) = run_offsets(outer, index);
+
+        // Blocks stop at the end of the axis, the broadcast operand moves on afterwards
+        let n = rows.min(axis_size - index % axis_size).min(runs - done);
+        let len = n * run;
+
+        let (data, offset, stride) = match side {
+            Tile::Left => (left.data, l, ls),
+            Tile::Right => (right.data, r, rs),
+        };
+
+        if tile_offset != Some(offset) {
+            tile.clear();
+            for _ in 0..rows {
+                tile.extend((0..run).map(|i| data[offset + i * stride]));
+            }
+            tile_offset = Some(offset);
+        }
+
+        let out_block = &mut out[done * run..done * run + len];
+
+        match side {
+            Tile::Left => binary_slices_into::<O>(&tile[..len], &right.data[r..r + len], out_block),
+            Tile::Right => binary_slices_into::<O>(&left.data[l..l + len], &tile[..len], out_block),
+        }
+
+        done += n;
+    }
+}
+
+/// Core broadcasting binary function (Processes runs in parallel)
+fn broadcast_binary<O: BinaryOp>(
+    left: Operand,
+    right: Operand,
+) -> Result<NdArray, ArithmeticsError> {
+    let shape = broadcast_shape(left, right)?;
+    let strides = row_major_strides(&shape);
+    let len = shape.iter().product();
+
+    if len == 0 {
+        return Ok(NdArray {
+            data: Vec::new(),
+            shape,
+            strides,
+        });
+    }
+
+    let axes = loop_axes(&shape, left, right);
+    let (outer, inner) = axes.split_at(axes.len() - 1);
+    let (run, ls, rs) = inner[0];
+
+    // Short runs are grouped, so each task still gets about `PAR_CHUNK` elements
+    let runs_per_task = (PAR_CHUNK / run).max(1);
+
+    let tile = tile_side(outer, run, ls, rs);
+
+    let data = collect_uninit(len, |out| {
+        out.par_chunks_mut(run * runs_per_task)
+            .enumerate()
+            .for_each(|(task, out_chunk)| {
+                let first = task * runs_per_task;
+
+                if let Some(side) = tile {
+                    return tiled_runs::<O>(
+                        side,
+                        outer,
+                        run,
+                        (left, ls),
+                        (right, rs),
+                        first,
+                        out_chunk,
+                    );
+                }
+
+                for (i, out_run) in out_chunk.chunks_mut(run).enumerate() {
+                    let (l, r) = run_offsets(outer, first + i);
+
+                    binary_run::<O>((left.data, l, ls), (right.data, r, rs), out_run);
+                }
+            })
+    });
+
+    Ok(NdArray {
+        data,
+        shape,
+        strides,
+    })
+}
+
+/// Implements each binary trait on arrays and f32 broadcasts through
+/// `broadcast_binary` with its `BinaryOp`
+macro_rules! array_binary {
+    ($($Trait:ident::$try_op:ident for $Op:ty;)*) => {$(
+        impl<'rhsl> $Trait<&'rhsl NdArray> for &NdArray {
+            type Output = NdArray;
+
+            #[inline(always)]
+            fn $try_op(self, rhs: &'rhsl NdArray) -> Result<Self::Output, ArithmeticsError> {
+                broadcast_binary::<$Op>(self.operand(), rhs.operand())
+            }
+        }
+
+        impl $Trait<f32> for &NdArray {
+            type Output = NdArray;
+
+            #[inline(always)]
+            fn $try_op(self, rhs: f32) -> Result<Self::Output, ArithmeticsError> {
+                broadcast_binary::<$Op>(self.operand(), Operand::scalar(&rhs))
+            }
+        }
+
+        impl<'rhsl> $Trait<&'rhsl NdArray> for f32 {
+            type Output = NdArray;
+
+            #[inline(always)]
+            fn $try_op(self, rhs: &'rhsl NdArray) -> Result<Self::Output, ArithmeticsError> {
+                broadcast_binary::<$Op>(Operand::scalar(&self), rhs.operand())
+            }
+        }
+    )*};
+}
+
+array_binary! {
+    SimdAdd::try_simd_add for AddOp;
+    SimdSub::try_simd_sub for SubOp;
+    SimdMul::try_simd_mul for MulOp;
+    SimdDiv::try_simd_div for DivOp;
+    SimdMin::try_simd_min for MinOp;
+    SimdMax::try_simd_max for MaxOp;
+}
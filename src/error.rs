@@ -0,0 +1,71 @@
+use std::fmt;
+
+use crate::simd::backend::Backend;
+
+/// Errors returned by the `try_*` operations instead of panicking
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ArithmeticsError {
+    /// Operands (or the output buffer) have different lengths
+    LengthMismatch { left: usize, right: usize },
+    /// Backend not compiled for this target or not supported by the CPU
+    UnsupportedBackend(Backend),
+    /// Environment variable whose value is not a recognized name
+    InvalidEnv(&'static str),
+    /// Clamp bounds with `lo > hi`, or a NaN bound
+    InvalidBounds { lo: f32, hi: f32 },
+    /// Strided view with a stride of 0
+    ZeroStride,
+    /// Shape whose number of elements differs from the length of the data
+    InvalidShape { len: usize, size: usize },
+    /// Array shapes that do not broadcast, `axis` counts from the first
+    /// axis of the broadcast shape
+    BroadcastMismatch {
+        axis: usize,
+        left: usize,
+        right: usize,
+    },
+}
+
+impl ArithmeticsError {
+    /// Fails with `LengthMismatch` unless `left == right`
+    #[inline(always)]
+    pub(crate) fn check_len(left: usize, right: usize) -> Result<(), ArithmeticsError> {
+        if left == right {
+            Ok(())
+        } else {
+            Err(ArithmeticsError::LengthMismatch { left, right })
+        }
+    }
+}
+
+impl fmt::Display for ArithmeticsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticsError::LengthMismatch { left, right } => {
+                write!(f, "Operands must have the same size {} != {}", left, right)
+            }
+            ArithmeticsError::UnsupportedBackend(backend) => write!(
+                f,
+                "{:?} backend is not available on this target or CPU",
+                backend
+            ),
+            ArithmeticsError::InvalidEnv(var) => write!(f, "{} has an unrecognized value", var),
+            ArithmeticsError::InvalidBounds { lo, hi } => {
+                write!(f, "Lower bound {} must not exceed upper bound {}", lo, hi)
+            }
+            ArithmeticsError::ZeroStride => write!(f, "Stride must be > 0"),
+            ArithmeticsError::InvalidShape { len, size } => write!(
+                f,
+                "Shape of {} elements does not match the {} elements of the data",
+                size, len
+            ),
+            ArithmeticsError::BroadcastMismatch { axis, left, right } => write!(
+                f,
+                "Shapes cannot be broadcast together, axis {} has sizes {} and {}",
+                axis, left, right
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ArithmeticsError {}
@@ -0,0 +1,108 @@
+use std::fmt;
+
+use crate::error::ArithmeticsError;
+use crate::ops::accuracy::Accuracy;
+use crate::ops::dispatch::PAR_CHUNK;
+use crate::simd::backend::Backend;
+use crate::simd::features::CpuFeatures;
+
+/// Snapshot of the kernels and parallelism the slice operations run with
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BackendInfo {
+    /// Backend picked at runtime (detected or forced)
+    pub backend: Backend,
+    /// Why `ARITHMETICS_BACKEND` was ignored, see `Backend::env_error`
+    pub backend_env_error: Option<ArithmeticsError>,
+    /// Instruction set of the backend, e.g. `"avx2"`
+    pub instruction_set: &'static str,
+    /// Number of f32 lanes per register
+    pub lanes: usize,
+    /// Accuracy of the transcendental functions (set or read from the environment)
+    pub accuracy: Accuracy,
+    /// Why `ARITHMETICS_ACCURACY` was ignored, see `Accuracy::env_error`
+    pub accuracy_env_error: Option<ArithmeticsError>,
+    /// Every CPU feature detected at runtime, not only the backend's one
+    pub features: CpuFeatures,
+    /// CPU features guaranteed by the target at build time, see `CpuFeatures::compiled`
+    pub build_features: CpuFeatures,
+    /// Backend guaranteed by the target features at build time
+    /// (the cfg emitted by `build.rs`), `"baseline"` if none
+    pub build_backend: &'static str,
+    /// Channel of the rustc that compiled the crate (the `rustc_channel` cfg)
+    pub rustc_channel: &'static str,
+    /// Number of threads in the rayon pool running the kernels
+    pub threads: usize,
+    /// Number of elements handed to a single rayon task
+    pub chunk_size: usize,
+}
+
+/// Describes the backend and parallelism settings currently in use
+pub fn backend_info() -> BackendInfo {
+    let backend = Backend::active();
+
+    BackendInfo {
+        backend,
+        backend_env_error: Backend::env_error(),
+        instruction_set: backend.name(),
+        lanes: backend.lanes(),
+        accuracy: Accuracy::active(),
+        accuracy_env_error: Accuracy::env_error(),
+        features: *CpuFeatures::get(),
+        build_features: CpuFeatures::compiled(),
+        build_backend: build_backend(),
+        rustc_channel: rustc_channel(),
+        threads: rayon::current_num_threads(),
+        chunk_size: PAR_CHUNK,
+    }
+}
+
+fn build_backend() -> &'static str {
+    if cfg!(avx512) {
+        "avx512"
+    } else if cfg!(avx2) {
+        "avx2"
+    } else if cfg!(sse) {
+        "sse4.1"
+    } else if cfg!(neon) {
+        "neon"
+    } else {
+        "baseline"
+    }
+}
+
+fn rustc_channel() -> &'static str {
+    if cfg!(rustc_channel = "nightly") {
+        "nightly"
+    } else if cfg!(rustc_channel = "beta") {
+        "beta"
+    } else {
+        "stable"
+    }
+}
+
+impl fmt::Display for BackendInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "backend={} lanes={} accuracy={} features={} build={} rustc={} threads={} chunk={}",
+            self.instruction_set,
+            self.lanes,
+            self.accuracy.name(),
+            self.features,
+            self.build_backend,
+            self.rustc_channel,
+            self.threads,
+            self.chunk_size
+        )?;
+
+        if let Some(err) = self.backend_env_error {
+            write!(f, " ({})", err)?;
+        }
+
+        if let Some(err) = self.accuracy_env_error {
+            write!(f, " ({})", err)?;
+        }
+
+        Ok(())
+    }
+}
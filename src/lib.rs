@@ -1,4 +1,9 @@
-#![cfg_attr(rustc_channel = "nightly", feature(stdarch_x86_avx512))]
-
+pub mod array;
+pub mod error;
+pub mod info;
 pub mod ops;
 pub mod simd;
+
+pub use array::NdArray;
+pub use error::ArithmeticsError;
+pub use info::{backend_info, BackendInfo};
@@ -0,0 +1,265 @@
+use std::env::VarError;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+use super::dispatch::{BinaryOp, UnaryOp, MAX_SIZE};
+use crate::error::ArithmeticsError;
+use crate::simd::utils::SimdVec;
+
+/// Environment variable setting the accuracy picked by `Accuracy::active`
+///
+/// Accepts the names returned by `Accuracy::name` (case-insensitive).
+pub const ACCURACY_ENV: &str = "ARITHMETICS_ACCURACY";
+
+/// Accuracy tiers of the transcendental functions, trading precision for throughput
+///
+/// The functions of `SimdUnary` past rounding, `SimdActivation`, `SimdAtan2` and
+/// the `UnaryFn` constants run the kernels of `Accuracy::active`, unless pinned
+/// with `UnaryFn::with_accuracy`. The other operations are exact whatever the tier.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Accuracy {
+    /// Reductions in a single part with shorter polynomials (relative error below
+    /// 1e-4, except `lgamma` of negative arguments)
+    ///
+    /// `sin`, `cos` and `tan` fall back to f64 past `|x| = 8192` like `Default`.
+    /// `tgamma` and `lgamma` keep their results in a single part, within 3.5 and
+    /// 3 ulp for positive arguments. The reflection of `lgamma` cancels near its
+    /// negative roots, where the error is absolute (below 2e-6) rather than relative.
+    Fast,
+    /// The vectorized kernels, within 1 ulp, the results kept in two parts until
+    /// the last rounding
+    #[default]
+    Default,
+    /// Computed lane by lane in f64 and rounded once to f32, about an order of
+    /// magnitude slower than `Default`
+    ///
+    /// Correctly rounded bar the rare arguments whose f64 result lies within its own
+    /// error of the midpoint of two f32.
+    CorrectlyRounded,
+}
+
+// Sentinel stored in `ACTIVE` until the environment has been read
+const UNSET: u8 = u8::MAX;
+
+// Accuracy picked by the first call to `Accuracy::active`, or by `Accuracy::set_active`
+static ACTIVE: AtomicU8 = AtomicU8::new(UNSET);
+
+// Why the first call to `Accuracy::active` ignored `ARITHMETICS_ACCURACY`, see
+// `Accuracy::env_error`
+static ENV_ERROR: OnceLock<ArithmeticsError> = OnceLock::new();
+
+impl Accuracy {
+    /// Every accuracy, from the fastest to the most accurate
+    pub const ALL: [Accuracy; 3] = [
+        Accuracy::Fast,
+        Accuracy::Default,
+        Accuracy::CorrectlyRounded,
+    ];
+
+    /// Accuracy of the transcendental functions.
+    ///
+    /// On the first call, the accuracy named by `ARITHMETICS_ACCURACY` is used if
+    /// set and valid (see `Accuracy::from_env`), otherwise `Accuracy::Default` is
+    /// used and the reason the variable was ignored is kept in `Accuracy::env_error`,
+    /// nothing is printed. The result is cached for the lifetime of the process,
+    /// see `Accuracy::set_active` to change it afterwards.
+    #[inline(always)]
+    pub fn active() -> Accuracy {
+        match ACTIVE.load(Ordering::Relaxed) {
+            UNSET => {
+                let accuracy = match Self::from_env() {
+                    Ok(accuracy) => accuracy.unwrap_or_default(),
+                    Err(err) => {
+                        let _ = ENV_ERROR.set(err);
+                        Accuracy::Default
+                    }
+                };
+
+                // A concurrent `set_active` or first call may have won the race
+                match ACTIVE.compare_exchange(
+                    UNSET,
+                    accuracy as u8,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => accuracy,
+                    Err(value) => Self::from_u8(value),
+                }
+            }
+            value => Self::from_u8(value),
+        }
+    }
+
+    /// Why the first call to `Accuracy::active` ignored `ARITHMETICS_ACCURACY`,
+    /// `None` if the variable was unset, valid, or never read
+    pub fn env_error() -> Option<ArithmeticsError> {
+        ENV_ERROR.get().copied()
+    }
+
+    /// Sets the accuracy of the transcendental functions from now on
+    pub fn set_active(accuracy: Accuracy) {
+        ACTIVE.store(accuracy as u8, Ordering::Relaxed);
+    }
+
+    /// Name of the accuracy, as accepted by `ARITHMETICS_ACCURACY`
+    pub fn name(self) -> &'static str {
+        match self {
+            Accuracy::Fast => "fast",
+            Accuracy::Default => "default",
+            Accuracy::CorrectlyRounded => "correctly_rounded",
+        }
+    }
+
+    /// Parses an accuracy name, see `Accuracy::name`
+    pub fn from_name(name: &str) -> Option<Accuracy> {
+        let name = name.trim().to_lowercase().replace('-', "_");
+
+        match name.as_str() {
+            "fast" => Some(Accuracy::Fast),
+            "default" => Some(Accuracy::Default),
+            "correctly_rounded" | "exact" => Some(Accuracy::CorrectlyRounded),
+            _ => None,
+        }
+    }
+
+    /// Accuracy requested through `ARITHMETICS_ACCURACY`, `None` when unset
+    ///
+    /// Fails with `InvalidEnv` if the value is not an accuracy name.
+    pub fn from_env() -> Result<Option<Accuracy>, ArithmeticsError> {
+        match std::env::var(ACCURACY_ENV) {
+            Ok(value) => Self::from_name(&value)
+                .map(Some)
+                .ok_or(ArithmeticsError::InvalidEnv(ACCURACY_ENV)),
+            Err(VarError::NotPresent) => Ok(None),
+            Err(VarError::NotUnicode(_)) => Err(ArithmeticsError::InvalidEnv(ACCURACY_ENV)),
+        }
+    }
+
+    fn from_u8(value: u8) -> Accuracy {
+        match value {
+            0 => Accuracy::Fast,
+            1 => Accuracy::Default,
+            2 => Accuracy::CorrectlyRounded,
+            _ => {
+                let msg = format!("Invalid accuracy {}", value);
+                panic!("{}", msg);
+            }
+        }
+    }
+}
+
+/// A `UnaryOp`, the default tier, with the variants of the other tiers
+pub(crate) trait TieredOp: UnaryOp {
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V;
+
+    fn apply_exact(a: f64) -> f64;
+}
+
+/// A `BinaryOp`, the default tier, with the variants of the other tiers
+pub(crate) trait TieredBinaryOp: BinaryOp {
+    fn apply_fast<V: SimdVec<f32>>(a: V, b: V) -> V;
+
+    fn apply_exact(a: f64, b: f64) -> f64;
+}
+
+/// The `Accuracy::Fast` variant of `O`
+pub(crate) struct FastOp<O>(PhantomData<O>);
+
+impl<O: TieredOp> UnaryOp for FastOp<O> {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        O::apply_fast(a)
+    }
+}
+
+impl<O: TieredBinaryOp> BinaryOp for FastOp<O> {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V {
+        O::apply_fast(a, b)
+    }
+}
+
+/// The `Accuracy::CorrectlyRounded` variant of `O`
+pub(crate) struct CorrectlyRoundedOp<O>(PhantomData<O>);
+
+impl<O: TieredOp> UnaryOp for CorrectlyRoundedOp<O> {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        let mut lanes = [0f32; MAX_SIZE];
+        unsafe { a.store_at(lanes.as_mut_ptr()) }
+
+        for lane in &mut lanes[..V::SIZE] {
+            *lane = O::apply_exact(*lane as f64) as f32;
+        }
+
+        V::new(&lanes[..V::SIZE])
+    }
+}
+
+impl<O: TieredBinaryOp> BinaryOp for CorrectlyRoundedOp<O> {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V {
+        let mut lanes = [0f32; MAX_SIZE];
+        let mut rhs = [0f32; MAX_SIZE];
+        unsafe {
+            a.store_at(lanes.as_mut_ptr());
+            b.store_at(rhs.as_mut_ptr());
+        }
+
+        for (lane, b) in lanes[..V::SIZE].iter_mut().zip(rhs) {
+            *lane = O::apply_exact(*lane as f64, b as f64) as f32;
+        }
+
+        V::new(&lanes[..V::SIZE])
+    }
+}
+
+/// The functions of the C math library std has no counterpart for, in f64
+pub(crate) mod libm {
+    use libc::c_double;
+
+    extern "C" {
+        #[link_name = "erf"]
+        fn c_erf(x: c_double) -> c_double;
+        #[link_name = "erfc"]
+        fn c_erfc(x: c_double) -> c_double;
+        #[link_name = "tgamma"]
+        fn c_tgamma(x: c_double) -> c_double;
+        // The reentrant variant, lgamma writes the sign of gamma to a global
+        #[cfg(unix)]
+        fn lgamma_r(x: c_double, sign: *mut libc::c_int) -> c_double;
+        #[cfg(not(unix))]
+        #[link_name = "lgamma"]
+        fn c_lgamma(x: c_double) -> c_double;
+    }
+
+    #[inline(always)]
+    pub(crate) fn erf(x: f64) -> f64 {
+        unsafe { c_erf(x) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn erfc(x: f64) -> f64 {
+        unsafe { c_erfc(x) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn tgamma(x: f64) -> f64 {
+        unsafe { c_tgamma(x) }
+    }
+
+    #[cfg(unix)]
+    #[inline(always)]
+    pub(crate) fn lgamma(x: f64) -> f64 {
+        let mut sign = 0;
+
+        unsafe { lgamma_r(x, &mut sign) }
+    }
+
+    #[cfg(not(unix))]
+    #[inline(always)]
+    pub(crate) fn lgamma(x: f64) -> f64 {
+        unsafe { c_lgamma(x) }
+    }
+}
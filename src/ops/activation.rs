@@ -0,0 +1,434 @@
+use std::mem::MaybeUninit;
+
+use super::accuracy::{libm, TieredOp};
+use super::dispatch::{
+    as_uninit_mut, assume_init_mut, collect_uninit, dispatch, unary, unary_assign, Kernel, Sealed,
+    UnaryOp,
+};
+use super::unary::{SimdUnary, SimdUnaryAssign, UnaryFn};
+use crate::error::ArithmeticsError;
+use crate::simd::fast_math;
+use crate::simd::math::{self, two_sum};
+use crate::simd::utils::SimdVec;
+
+impl UnaryFn {
+    /// Rectified linear unit `max(x, 0)`, propagating NaN
+    pub const RELU: UnaryFn = UnaryFn::of::<ReluOp>("relu");
+    /// Logistic sigmoid `1 / (1 + exp(-x))` (within 1 ulp)
+    pub const SIGMOID: UnaryFn = UnaryFn::tiered::<SigmoidOp>("sigmoid");
+    /// `ln(1 + exp(x))`, without overflow for large `x` (within 1 ulp)
+    pub const SOFTPLUS: UnaryFn = UnaryFn::tiered::<SoftplusOp>("softplus");
+    /// Sigmoid linear unit `x * sigmoid(x)` (within 1 ulp)
+    pub const SILU: UnaryFn = UnaryFn::tiered::<SiluOp>("silu");
+    /// Gaussian error linear unit `x * Phi(x)`, with the exact normal CDF (within 1 ulp)
+    pub const GELU: UnaryFn = UnaryFn::tiered::<GeluOp>("gelu");
+    /// Gaussian error linear unit with the tanh approximation of the normal CDF
+    /// (within 1 ulp of that approximation)
+    pub const GELU_TANH: UnaryFn = UnaryFn::tiered::<GeluTanhOp>("gelu_tanh");
+}
+
+/// Element-wise activation functions of neural networks
+///
+/// `tanh` is `SimdUnary::simd_tanh`, the ones without parameters are also
+/// `UnaryFn` constants for `simd_map` and its variants. `-inf` gives `-0` for the
+/// gated units. All but the ReLUs run with `Accuracy::active`.
+pub trait SimdActivation: SimdUnary {
+    /// `x` where positive, `alpha * x` elsewhere
+    fn simd_leaky_relu(self, alpha: f32) -> Self::Output;
+
+    #[inline(always)]
+    fn simd_relu(self) -> Self::Output {
+        self.simd_map(UnaryFn::RELU)
+    }
+
+    #[inline(always)]
+    fn simd_sigmoid(self) -> Self::Output {
+        self.simd_map(UnaryFn::SIGMOID)
+    }
+
+    #[inline(always)]
+    fn simd_softplus(self) -> Self::Output {
+        self.simd_map(UnaryFn::SOFTPLUS)
+    }
+
+    #[inline(always)]
+    fn simd_silu(self) -> Self::Output {
+        self.simd_map(UnaryFn::SILU)
+    }
+
+    #[inline(always)]
+    fn simd_gelu(self) -> Self::Output {
+        self.simd_map(UnaryFn::GELU)
+    }
+
+    #[inline(always)]
+    fn simd_gelu_tanh(self) -> Self::Output {
+        self.simd_map(UnaryFn::GELU_TANH)
+    }
+}
+
+/// In-place element-wise activation functions, see `SimdActivation`
+pub trait SimdActivationAssign: SimdUnaryAssign {
+    /// `x` where positive, `alpha * x` elsewhere
+    fn simd_leaky_relu_assign(&mut self, alpha: f32);
+
+    #[inline(always)]
+    fn simd_relu_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::RELU)
+    }
+
+    #[inline(always)]
+    fn simd_sigmoid_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::SIGMOID)
+    }
+
+    #[inline(always)]
+    fn simd_softplus_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::SOFTPLUS)
+    }
+
+    #[inline(always)]
+    fn simd_silu_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::SILU)
+    }
+
+    #[inline(always)]
+    fn simd_gelu_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::GELU)
+    }
+
+    #[inline(always)]
+    fn simd_gelu_tanh_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::GELU_TANH)
+    }
+}
+
+/// Leaky ReLU into a caller-provided buffer of the input's size, the other
+/// activations go through `SimdUnaryInto::simd_map_into`
+pub trait SimdActivationInto: Sealed + Sized {
+    /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+    ///
+    /// Fails with `LengthMismatch`, leaving `out` untouched, instead of panicking.
+    fn try_simd_leaky_relu_into_uninit(
+        self,
+        alpha: f32,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError>;
+
+    /// Writes the results into `out`, see `try_simd_leaky_relu_into_uninit`
+    #[inline(always)]
+    fn try_simd_leaky_relu_into(self, alpha: f32, out: &mut [f32]) -> Result<(), ArithmeticsError> {
+        // Sealed, the implementations of this crate only write valid values
+        self.try_simd_leaky_relu_into_uninit(alpha, unsafe { as_uninit_mut(out) })
+            .map(|_| ())
+    }
+
+    /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+    #[inline(always)]
+    fn simd_leaky_relu_into_uninit(self, alpha: f32, out: &mut [MaybeUninit<f32>]) -> &mut [f32] {
+        self.try_simd_leaky_relu_into_uninit(alpha, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Writes the results into `out`
+    #[inline(always)]
+    fn simd_leaky_relu_into(self, alpha: f32, out: &mut [f32]) {
+        self.try_simd_leaky_relu_into(alpha, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+#[inline(always)]
+fn leaky_relu<V: SimdVec<f32>>(a: V, alpha: V) -> V {
+    a.simd_lt(V::splat(0.0)).simd_select(a.simd_mul(alpha), a)
+}
+
+// `y` with the lanes where `a` is -inf set to -0, the limit of the gated units
+// which would otherwise compute -inf * 0
+#[inline(always)]
+fn neg_inf_to_zero<V: SimdVec<f32>>(a: V, y: V) -> V {
+    V::splat(f32::NEG_INFINITY)
+        .simd_eq(a)
+        .simd_select(V::splat(-0.0), y)
+}
+
+// `a * sigmoid(k)`, as `a / (1 + exp(-k))` for positive k and `a * exp(k) / (1 + exp(k))`
+// for negative k where exp(-k) would overflow before the result underflows
+#[inline(always)]
+fn gate_fast<V: SimdVec<f32>>(a: V, k: V) -> V {
+    let e = fast_math::exp(k.simd_abs().simd_neg());
+    let num = k.simd_lt(V::splat(0.0)).simd_select(a.simd_mul(e), a);
+
+    neg_inf_to_zero(a, num.simd_div(V::splat(1.0).simd_add(e)))
+}
+
+// `a` times the gate given as the parts of `math::sigmoid_parts` or
+// `math::normal_cdf_parts`, -0 for `a = -inf` and +inf for `a = +inf`
+#[inline(always)]
+fn gate_parts<V: SimdVec<f32>>(a: V, parts: (V, V, V)) -> V {
+    let y = math::mul_parts_scaled(a, parts);
+
+    neg_inf_to_zero(a, V::splat(f32::INFINITY).simd_eq(a).simd_select(a, y))
+}
+
+// `a * sigmoid(k + k_lo)` rounded once, see `gate_parts`
+#[inline(always)]
+fn gate<V: SimdVec<f32>>(a: V, k: V, k_lo: V) -> V {
+    gate_parts(a, math::sigmoid_parts(k, k_lo))
+}
+
+// `a * sigmoid(k)` in f64, -0 for `a = -inf` like `gate`
+#[inline(always)]
+fn gate_exact(a: f64, k: f64) -> f64 {
+    if a == f64::NEG_INFINITY {
+        return -0.0;
+    }
+
+    a / (1.0 + (-k).exp())
+}
+
+// sqrt(2 / pi) and 0.044715 * sqrt(2 / pi), doubled, see `GELU_TANH_K`
+const GELU_TANH_K_F64: [f64; 2] = [1.5957691216057308, 7.135481627159294e-2];
+
+struct ReluOp;
+
+impl UnaryOp for ReluOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        let zero = V::splat(0.0);
+
+        a.simd_lt(zero).simd_select(zero, a)
+    }
+}
+
+struct SigmoidOp;
+
+impl UnaryOp for SigmoidOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        gate(V::splat(1.0), a, V::splat(0.0))
+    }
+}
+
+impl TieredOp for SigmoidOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        gate_fast(V::splat(1.0), a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        gate_exact(1.0, a)
+    }
+}
+
+struct SoftplusOp;
+
+impl UnaryOp for SoftplusOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        math::softplus(a)
+    }
+}
+
+impl TieredOp for SoftplusOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        // max(x, 0) + ln(1 + exp(-|x|))
+        let tail = fast_math::log1p(fast_math::exp(a.simd_abs().simd_neg()));
+
+        ReluOp::apply(a).simd_add(tail)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        // NaN stays NaN, unlike with f64::max
+        let relu = if a < 0.0 { 0.0 } else { a };
+
+        relu + (-a.abs()).exp().ln_1p()
+    }
+}
+
+struct SiluOp;
+
+impl UnaryOp for SiluOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        gate(a, a, V::splat(0.0))
+    }
+}
+
+impl TieredOp for SiluOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        gate_fast(a, a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        gate_exact(a, a)
+    }
+}
+
+struct GeluOp;
+
+impl UnaryOp for GeluOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        gate_parts(a, math::normal_cdf_parts(a))
+    }
+}
+
+impl TieredOp for GeluOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        let phi = fast_math::normal_cdf(a);
+
+        neg_inf_to_zero(a, a.simd_mul(phi))
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        if a == f64::NEG_INFINITY {
+            return -0.0;
+        }
+
+        a * libm::erfc(-a * std::f64::consts::FRAC_1_SQRT_2) * 0.5
+    }
+}
+
+// sqrt(2 / pi) and 0.044715 * sqrt(2 / pi), doubled
+const GELU_TANH_K: [f32; 2] = [1.5957692, 7.1354816e-2];
+
+struct GeluTanhOp;
+
+// The rounding errors of `GELU_TANH_K`
+const GELU_TANH_K_LO: [f32; 2] = [-4.534068e-8, 2.397825e-9];
+
+// The sigmoid is 0 or 1 past this magnitude, also keeps the cube finite
+const GELU_TANH_LIMIT: f32 = 1000.0;
+
+// 2 u = sqrt(2 / pi) (x + 0.044715 x^3) * 2, the argument of the sigmoid
+#[inline(always)]
+fn gelu_tanh_arg<V: SimdVec<f32>>(a: V) -> V {
+    a.simd_mul(a)
+        .simd_fma(V::splat(GELU_TANH_K[1]), V::splat(GELU_TANH_K[0]))
+        .simd_mul(a)
+}
+
+// 2 u in two parts, its rounding is magnified by |2 u| in the negative tail
+#[inline(always)]
+fn gelu_tanh_arg_parts<V: SimdVec<f32>>(a: V) -> (V, V) {
+    let limit = V::splat(GELU_TANH_LIMIT);
+    let a = a.simd_clamp(limit.simd_neg(), limit);
+
+    // K0 + K1 x^2 with the rounding errors of x^2, of the product and of the sum
+    let k1 = V::splat(GELU_TANH_K[1]);
+    let s = a.simd_mul(a);
+    let s_lo = a.simd_mul_error(a);
+    let p = s.simd_mul(k1);
+    let p_lo = s.simd_fma(
+        V::splat(GELU_TANH_K_LO[1]),
+        s_lo.simd_fma(k1, s.simd_mul_error(k1)),
+    );
+    let (t, t_lo) = two_sum(V::splat(GELU_TANH_K[0]), p);
+    let t_lo = t_lo.simd_add(p_lo).simd_add(V::splat(GELU_TANH_K_LO[0]));
+
+    (a.simd_mul(t), a.simd_fma(t_lo, a.simd_mul_error(t)))
+}
+
+impl UnaryOp for GeluTanhOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        // (1 + tanh(u)) / 2 = sigmoid(2 u)
+        let (k, k_lo) = gelu_tanh_arg_parts(a);
+
+        gate(a, k, k_lo)
+    }
+}
+
+impl TieredOp for GeluTanhOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        gate_fast(a, gelu_tanh_arg(a))
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        let k = (a * a).mul_add(GELU_TANH_K_F64[1], GELU_TANH_K_F64[0]) * a;
+
+        gate_exact(a, k)
+    }
+}
+
+struct LeakyReluKernel<'a> {
+    a: &'a [f32],
+    alpha: f32,
+}
+
+impl Kernel for LeakyReluKernel<'_> {
+    type Out = MaybeUninit<f32>;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]) {
+        let end = offset + out.len();
+
+        let alpha = V::splat(self.alpha);
+
+        unary::<V>(&self.a[offset..end], out, |a| leaky_relu(a, alpha));
+    }
+}
+
+struct LeakyReluAssignKernel {
+    alpha: f32,
+}
+
+impl Kernel for LeakyReluAssignKernel {
+    type Out = f32;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, _offset: usize, out: &mut [f32]) {
+        let alpha = V::splat(self.alpha);
+
+        unary_assign::<V>(out, |a| leaky_relu(a, alpha));
+    }
+}
+
+impl SimdActivation for &[f32] {
+    #[inline(always)]
+    fn simd_leaky_relu(self, alpha: f32) -> Self::Output {
+        collect_uninit(self.len(), |b| {
+            dispatch(&LeakyReluKernel { a: self, alpha }, b)
+        })
+    }
+}
+
+impl SimdActivation for Vec<f32> {
+    #[inline(always)]
+    fn simd_leaky_relu(mut self, alpha: f32) -> Self::Output {
+        self.simd_leaky_relu_assign(alpha);
+        self
+    }
+}
+
+impl SimdActivationAssign for [f32] {
+    #[inline(always)]
+    fn simd_leaky_relu_assign(&mut self, alpha: f32) {
+        dispatch(&LeakyReluAssignKernel { alpha }, self);
+    }
+}
+
+impl SimdActivationInto for &[f32] {
+    #[inline(always)]
+    fn try_simd_leaky_relu_into_uninit(
+        self,
+        alpha: f32,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), out.len())?;
+
+        dispatch(&LeakyReluKernel { a: self, alpha }, out);
+
+        Ok(unsafe { assume_init_mut(out) })
+    }
+}
@@ -0,0 +1,271 @@
+use std::mem::MaybeUninit;
+
+use super::accuracy::{Accuracy, CorrectlyRoundedOp, FastOp, TieredBinaryOp};
+use super::dispatch::{
+    as_uninit_mut, assume_init_mut, binary_assign_scalar, binary_assign_slices,
+    binary_scalar_slice_into, binary_slice_scalar_into, binary_slices_into, collect_uninit,
+    BinaryOp, Sealed,
+};
+use crate::error::ArithmeticsError;
+use crate::simd::fast_math;
+use crate::simd::utils::SimdVec;
+
+/// Element-wise four-quadrant arctangent of `self / rhs`, in [-pi, pi]
+///
+/// `self` holds the `y` coordinates and `rhs` the `x` ones, like `f32::atan2`,
+/// whose results it gives for zeros and infinities (within 1 ulp elsewhere). Runs
+/// with `Accuracy::active`.
+pub trait SimdAtan2<Rhs = Self>: Sized {
+    type Output;
+
+    /// Fails with `LengthMismatch` instead of panicking on operands of different sizes
+    fn try_simd_atan2(self, rhs: Rhs) -> Result<Self::Output, ArithmeticsError>;
+
+    #[inline(always)]
+    fn simd_atan2(self, rhs: Rhs) -> Self::Output {
+        self.try_simd_atan2(rhs)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// In-place element-wise four-quadrant arctangent, writes the results into `self`
+pub trait SimdAtan2Assign<Rhs> {
+    /// Fails with `LengthMismatch`, leaving `self` untouched, instead of panicking
+    fn try_simd_atan2_assign(&mut self, rhs: Rhs) -> Result<(), ArithmeticsError>;
+
+    #[inline(always)]
+    fn simd_atan2_assign(&mut self, rhs: Rhs) {
+        self.try_simd_atan2_assign(rhs)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Element-wise four-quadrant arctangent into a caller-provided buffer of the operands' size
+pub trait SimdAtan2Into<Rhs>: Sealed + Sized {
+    /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+    ///
+    /// Fails with `LengthMismatch`, leaving `out` untouched, instead of panicking.
+    fn try_simd_atan2_into_uninit(
+        self,
+        rhs: Rhs,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError>;
+
+    /// Writes the results into `out`, see `try_simd_atan2_into_uninit`
+    #[inline(always)]
+    fn try_simd_atan2_into(self, rhs: Rhs, out: &mut [f32]) -> Result<(), ArithmeticsError> {
+        // Sealed, the implementations of this crate only write valid values
+        self.try_simd_atan2_into_uninit(rhs, unsafe { as_uninit_mut(out) })
+            .map(|_| ())
+    }
+
+    /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+    #[inline(always)]
+    fn simd_atan2_into_uninit(self, rhs: Rhs, out: &mut [MaybeUninit<f32>]) -> &mut [f32] {
+        self.try_simd_atan2_into_uninit(rhs, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Writes the results into `out`
+    #[inline(always)]
+    fn simd_atan2_into(self, rhs: Rhs, out: &mut [f32]) {
+        self.try_simd_atan2_into(rhs, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Element-wise four-quadrant arctangent at `Accuracy::Default`, see `expr::Atan2`
+/// for the node running every tier
+pub struct Atan2Op;
+
+impl BinaryOp for Atan2Op {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V {
+        a.simd_atan2(b)
+    }
+}
+
+impl TieredBinaryOp for Atan2Op {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V, b: V) -> V {
+        fast_math::atan2(a, b)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64, b: f64) -> f64 {
+        a.atan2(b)
+    }
+}
+
+// The kernels of `Accuracy::active` (Process chunks in parallel)
+#[inline(always)]
+fn atan2_slices_into(a: &[f32], b: &[f32], out: &mut [MaybeUninit<f32>]) {
+    match Accuracy::active() {
+        Accuracy::Fast => binary_slices_into::<FastOp<Atan2Op>>(a, b, out),
+        Accuracy::Default => binary_slices_into::<Atan2Op>(a, b, out),
+        Accuracy::CorrectlyRounded => binary_slices_into::<CorrectlyRoundedOp<Atan2Op>>(a, b, out),
+    }
+}
+
+#[inline(always)]
+fn atan2_slice_scalar_into(a: &[f32], b: f32, out: &mut [MaybeUninit<f32>]) {
+    match Accuracy::active() {
+        Accuracy::Fast => binary_slice_scalar_into::<FastOp<Atan2Op>>(a, b, out),
+        Accuracy::Default => binary_slice_scalar_into::<Atan2Op>(a, b, out),
+        Accuracy::CorrectlyRounded => {
+            binary_slice_scalar_into::<CorrectlyRoundedOp<Atan2Op>>(a, b, out)
+        }
+    }
+}
+
+#[inline(always)]
+fn atan2_scalar_slice_into(a: f32, b: &[f32], out: &mut [MaybeUninit<f32>]) {
+    match Accuracy::active() {
+        Accuracy::Fast => binary_scalar_slice_into::<FastOp<Atan2Op>>(a, b, out),
+        Accuracy::Default => binary_scalar_slice_into::<Atan2Op>(a, b, out),
+        Accuracy::CorrectlyRounded => {
+            binary_scalar_slice_into::<CorrectlyRoundedOp<Atan2Op>>(a, b, out)
+        }
+    }
+}
+
+#[inline(always)]
+fn atan2_assign_slices(a: &mut [f32], b: &[f32]) {
+    match Accuracy::active() {
+        Accuracy::Fast => binary_assign_slices::<FastOp<Atan2Op>>(a, b),
+        Accuracy::Default => binary_assign_slices::<Atan2Op>(a, b),
+        Accuracy::CorrectlyRounded => binary_assign_slices::<CorrectlyRoundedOp<Atan2Op>>(a, b),
+    }
+}
+
+#[inline(always)]
+fn atan2_assign_scalar(a: &mut [f32], b: f32) {
+    match Accuracy::active() {
+        Accuracy::Fast => binary_assign_scalar::<FastOp<Atan2Op>>(a, b),
+        Accuracy::Default => binary_assign_scalar::<Atan2Op>(a, b),
+        Accuracy::CorrectlyRounded => binary_assign_scalar::<CorrectlyRoundedOp<Atan2Op>>(a, b),
+    }
+}
+
+impl SimdAtan2 for Vec<f32> {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_atan2(mut self, rhs: Vec<f32>) -> Result<Self::Output, ArithmeticsError> {
+        self.try_simd_atan2_assign(rhs.as_slice())?;
+
+        Ok(self)
+    }
+}
+
+impl<'rhsl> SimdAtan2<&'rhsl [f32]> for &[f32] {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_atan2(self, rhs: &'rhsl [f32]) -> Result<Self::Output, ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), rhs.len())?;
+
+        Ok(collect_uninit(self.len(), |c| {
+            atan2_slices_into(self, rhs, c)
+        }))
+    }
+}
+
+impl SimdAtan2<f32> for Vec<f32> {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_atan2(mut self, rhs: f32) -> Result<Self::Output, ArithmeticsError> {
+        self.try_simd_atan2_assign(rhs)?;
+
+        Ok(self)
+    }
+}
+
+impl SimdAtan2<f32> for &[f32] {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_atan2(self, rhs: f32) -> Result<Self::Output, ArithmeticsError> {
+        Ok(collect_uninit(self.len(), |c| {
+            atan2_slice_scalar_into(self, rhs, c)
+        }))
+    }
+}
+
+impl<'rhsl> SimdAtan2<&'rhsl [f32]> for f32 {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_atan2(self, rhs: &'rhsl [f32]) -> Result<Self::Output, ArithmeticsError> {
+        Ok(collect_uninit(rhs.len(), |c| {
+            atan2_scalar_slice_into(self, rhs, c)
+        }))
+    }
+}
+
+impl<'rhsl> SimdAtan2Assign<&'rhsl [f32]> for [f32] {
+    #[inline(always)]
+    fn try_simd_atan2_assign(&mut self, rhs: &'rhsl [f32]) -> Result<(), ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), rhs.len())?;
+
+        atan2_assign_slices(self, rhs);
+
+        Ok(())
+    }
+}
+
+impl SimdAtan2Assign<f32> for [f32] {
+    #[inline(always)]
+    fn try_simd_atan2_assign(&mut self, rhs: f32) -> Result<(), ArithmeticsError> {
+        atan2_assign_scalar(self, rhs);
+
+        Ok(())
+    }
+}
+
+impl<'rhsl> SimdAtan2Into<&'rhsl [f32]> for &[f32] {
+    #[inline(always)]
+    fn try_simd_atan2_into_uninit<'o>(
+        self,
+        rhs: &'rhsl [f32],
+        out: &'o mut [MaybeUninit<f32>],
+    ) -> Result<&'o mut [f32], ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), rhs.len())?;
+        ArithmeticsError::check_len(self.len(), out.len())?;
+
+        atan2_slices_into(self, rhs, out);
+
+        Ok(unsafe { assume_init_mut(out) })
+    }
+}
+
+impl SimdAtan2Into<f32> for &[f32] {
+    #[inline(always)]
+    fn try_simd_atan2_into_uninit(
+        self,
+        rhs: f32,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), out.len())?;
+
+        atan2_slice_scalar_into(self, rhs, out);
+
+        Ok(unsafe { assume_init_mut(out) })
+    }
+}
+
+impl<'rhsl> SimdAtan2Into<&'rhsl [f32]> for f32 {
+    #[inline(always)]
+    fn try_simd_atan2_into_uninit<'o>(
+        self,
+        rhs: &'rhsl [f32],
+        out: &'o mut [MaybeUninit<f32>],
+    ) -> Result<&'o mut [f32], ArithmeticsError> {
+        ArithmeticsError::check_len(rhs.len(), out.len())?;
+
+        atan2_scalar_slice_into(self, rhs, out);
+
+        Ok(unsafe { assume_init_mut(out) })
+    }
+}
@@ -0,0 +1,189 @@
+use std::mem::MaybeUninit;
+
+use super::dispatch::{
+    as_uninit_mut, assume_init_mut, collect_uninit, dispatch, unary, unary_assign, Kernel, Sealed,
+};
+use crate::error::ArithmeticsError;
+use crate::simd::utils::SimdVec;
+
+/// Element-wise clamp to `[lo, hi]`
+///
+/// Like `f32::clamp`, NaN elements are propagated, and the bounds must satisfy
+/// `lo <= hi` (which rules out NaN bounds).
+pub trait SimdClamp: Sized {
+    type Output;
+
+    /// Fails with `InvalidBounds` instead of panicking on bounds with `lo > hi`
+    fn try_simd_clamp(self, lo: f32, hi: f32) -> Result<Self::Output, ArithmeticsError>;
+
+    #[inline(always)]
+    fn simd_clamp(self, lo: f32, hi: f32) -> Self::Output {
+        self.try_simd_clamp(lo, hi)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// In-place element-wise clamp to `[lo, hi]`, see `SimdClamp`
+pub trait SimdClampAssign {
+    /// Fails with `InvalidBounds`, leaving `self` untouched, instead of panicking
+    fn try_simd_clamp_assign(&mut self, lo: f32, hi: f32) -> Result<(), ArithmeticsError>;
+
+    #[inline(always)]
+    fn simd_clamp_assign(&mut self, lo: f32, hi: f32) {
+        self.try_simd_clamp_assign(lo, hi)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Element-wise clamp into a caller-provided buffer of the input's size, see `SimdClamp`
+pub trait SimdClampInto: Sealed + Sized {
+    /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+    ///
+    /// Fails with `LengthMismatch` or `InvalidBounds`, leaving `out` untouched,
+    /// instead of panicking.
+    fn try_simd_clamp_into_uninit(
+        self,
+        lo: f32,
+        hi: f32,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError>;
+
+    /// Writes the results into `out`, see `try_simd_clamp_into_uninit`
+    #[inline(always)]
+    fn try_simd_clamp_into(
+        self,
+        lo: f32,
+        hi: f32,
+        out: &mut [f32],
+    ) -> Result<(), ArithmeticsError> {
+        // Sealed, the implementations of this crate only write valid values
+        self.try_simd_clamp_into_uninit(lo, hi, unsafe { as_uninit_mut(out) })
+            .map(|_| ())
+    }
+
+    /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+    #[inline(always)]
+    fn simd_clamp_into_uninit(self, lo: f32, hi: f32, out: &mut [MaybeUninit<f32>]) -> &mut [f32] {
+        self.try_simd_clamp_into_uninit(lo, hi, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Writes the results into `out`
+    #[inline(always)]
+    fn simd_clamp_into(self, lo: f32, hi: f32, out: &mut [f32]) {
+        self.try_simd_clamp_into(lo, hi, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+struct ClampKernel<'a> {
+    a: &'a [f32],
+    lo: f32,
+    hi: f32,
+}
+
+impl Kernel for ClampKernel<'_> {
+    type Out = MaybeUninit<f32>;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]) {
+        let end = offset + out.len();
+
+        let lo = V::splat(self.lo);
+        let hi = V::splat(self.hi);
+
+        unary::<V>(
+            &self.a[offset..end],
+            out,
+            #[inline(always)]
+            |a| a.simd_clamp(lo, hi),
+        );
+    }
+}
+
+struct ClampAssignKernel {
+    lo: f32,
+    hi: f32,
+}
+
+impl Kernel for ClampAssignKernel {
+    type Out = f32;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, _offset: usize, out: &mut [f32]) {
+        let lo = V::splat(self.lo);
+        let hi = V::splat(self.hi);
+
+        unary_assign::<V>(
+            out,
+            #[inline(always)]
+            |a| a.simd_clamp(lo, hi),
+        );
+    }
+}
+
+#[inline(always)]
+pub(crate) fn check_bounds(lo: f32, hi: f32) -> Result<(), ArithmeticsError> {
+    // Also rejects NaN bounds
+    if lo <= hi {
+        Ok(())
+    } else {
+        Err(ArithmeticsError::InvalidBounds { lo, hi })
+    }
+}
+
+/// Core SIMD clamp function (Processes chunks in parallel)
+#[inline(always)]
+fn clamp_slice(a: &[f32], lo: f32, hi: f32) -> Vec<f32> {
+    collect_uninit(a.len(), |b| dispatch(&ClampKernel { a, lo, hi }, b))
+}
+
+impl SimdClamp for Vec<f32> {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_clamp(mut self, lo: f32, hi: f32) -> Result<Self::Output, ArithmeticsError> {
+        self.try_simd_clamp_assign(lo, hi)?;
+
+        Ok(self)
+    }
+}
+
+impl SimdClamp for &[f32] {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_clamp(self, lo: f32, hi: f32) -> Result<Self::Output, ArithmeticsError> {
+        check_bounds(lo, hi)?;
+
+        Ok(clamp_slice(self, lo, hi))
+    }
+}
+
+impl SimdClampAssign for [f32] {
+    #[inline(always)]
+    fn try_simd_clamp_assign(&mut self, lo: f32, hi: f32) -> Result<(), ArithmeticsError> {
+        check_bounds(lo, hi)?;
+
+        dispatch(&ClampAssignKernel { lo, hi }, self);
+
+        Ok(())
+    }
+}
+
+impl SimdClampInto for &[f32] {
+    #[inline(always)]
+    fn try_simd_clamp_into_uninit(
+        self,
+        lo: f32,
+        hi: f32,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), out.len())?;
+        check_bounds(lo, hi)?;
+
+        dispatch(&ClampKernel { a: self, lo, hi }, out);
+
+        Ok(unsafe { assume_init_mut(out) })
+    }
+}
@@ -0,0 +1,777 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use rayon::prelude::*;
+
+use crate::simd::backend::Backend;
+use crate::simd::scalar::ScalarVec;
+use crate::simd::utils::SimdVec;
+
+#[cfg(target_arch = "x86_64")]
+use crate::simd::f32x16::F32x16;
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+use crate::simd::f32x4::F32x4;
+
+#[cfg(target_arch = "x86_64")]
+use crate::simd::f32x8::F32x8;
+
+/// Number of output elements handed to a single rayon task
+pub(crate) const PAR_CHUNK: usize = 1 << 14;
+
+/// Widest register of all backends, in f32 lanes
+pub(crate) const MAX_SIZE: usize = 16;
+
+/// A computation filling a range of the output, generic over the SIMD register type
+///
+/// Kernels are instantiated once per backend inside `#[target_feature]` functions,
+/// so every `SimdVec` method they call is inlined with the right instruction set.
+pub(crate) trait Kernel: Sync {
+    /// Output element, `MaybeUninit<f32>` for write-only kernels and `f32`
+    /// for in-place kernels reading their output
+    type Out: Send;
+
+    /// Fills `out`, which holds the output elements starting at `offset`
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]);
+}
+
+/// Runs `kernel` over `out` in parallel chunks on the active backend
+///
+/// Outputs of a single chunk run on the calling thread, without going through rayon.
+pub(crate) fn dispatch<K: Kernel>(kernel: &K, out: &mut [K::Out]) {
+    let backend = Backend::active();
+
+    if out.len() <= PAR_CHUNK {
+        return run_chunk(backend, kernel, 0, out);
+    }
+
+    out.par_chunks_mut(PAR_CHUNK)
+        .enumerate()
+        .for_each(|(i, out_chunk)| run_chunk(backend, kernel, PAR_CHUNK * i, out_chunk));
+}
+
+#[inline(always)]
+fn run_chunk<K: Kernel>(backend: Backend, kernel: &K, offset: usize, out: &mut [K::Out]) {
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 => unsafe { run_avx512(kernel, offset, out) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 => unsafe { run_avx2(kernel, offset, out) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse41 => unsafe { run_sse41(kernel, offset, out) },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon => kernel.run::<F32x4>(offset, out),
+        Backend::Scalar => kernel.run::<ScalarVec>(offset, out),
+        #[allow(unreachable_patterns)]
+        _ => {
+            let msg = format!("{:?} backend is not compiled for this target", backend);
+            panic!("{}", msg);
+        }
+    }
+}
+
+/// # Safety
+///
+/// The CPU must support AVX-512F.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+unsafe fn run_avx512<K: Kernel>(kernel: &K, offset: usize, out: &mut [K::Out]) {
+    kernel.run::<F32x16>(offset, out)
+}
+
+/// # Safety
+///
+/// The CPU must support AVX2 and FMA.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn run_avx2<K: Kernel>(kernel: &K, offset: usize, out: &mut [K::Out]) {
+    kernel.run::<F32x8>(offset, out)
+}
+
+/// # Safety
+///
+/// The CPU must support SSE4.1.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.1")]
+unsafe fn run_sse41<K: Kernel>(kernel: &K, offset: usize, out: &mut [K::Out]) {
+    kernel.run::<F32x4>(offset, out)
+}
+
+/// Views an initialized output buffer as a write-only one
+///
+/// # Safety
+///
+/// Only valid values may be written through the returned slice, the caller's
+/// buffer must stay initialized even if the writer bails out halfway.
+#[inline(always)]
+pub(crate) unsafe fn as_uninit_mut(out: &mut [f32]) -> &mut [MaybeUninit<f32>] {
+    &mut *(out as *mut [f32] as *mut [MaybeUninit<f32>])
+}
+
+/// Supertrait of the `*Into` traits, keeping their implementations in this crate
+///
+/// Their `try_*_into` methods hand an initialized buffer to `try_*_into_uninit`,
+/// which could otherwise be implemented downstream to write uninitialized values.
+pub trait Sealed {}
+
+impl Sealed for &[f32] {}
+
+impl Sealed for f32 {}
+
+/// # Safety
+///
+/// Every element of `out` must have been written.
+#[inline(always)]
+pub(crate) unsafe fn assume_init_mut(out: &mut [MaybeUninit<f32>]) -> &mut [f32] {
+    &mut *(out as *mut [MaybeUninit<f32>] as *mut [f32])
+}
+
+/// Allocates a `Vec` of `len` elements filled by `fill`, without zeroing it first
+///
+/// `fill` must write every element of the buffer it is given.
+#[inline(always)]
+pub(crate) fn collect_uninit(len: usize, fill: impl FnOnce(&mut [MaybeUninit<f32>])) -> Vec<f32> {
+    let mut c = Vec::with_capacity(len);
+
+    fill(&mut c.spare_capacity_mut()[..len]);
+    unsafe { c.set_len(len) }
+
+    c
+}
+
+/// A lane-wise operation on two registers, e.g. `a + b`
+pub(crate) trait BinaryOp {
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V;
+}
+
+struct BinaryKernel<'a, O> {
+    a: &'a [f32],
+    b: &'a [f32],
+    op: PhantomData<fn() -> O>,
+}
+
+impl<O: BinaryOp> Kernel for BinaryKernel<'_, O> {
+    type Out = MaybeUninit<f32>;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]) {
+        let end = offset + out.len();
+
+        // Inlined into the kernel for large operations (e.g. `atan2`), see `UnaryKernel`
+        binary::<V>(
+            &self.a[offset..end],
+            &self.b[offset..end],
+            out,
+            #[inline(always)]
+            |a, b| O::apply(a, b),
+        );
+    }
+}
+
+/// Core SIMD binary function (Processes chunks in parallel)
+#[inline(always)]
+pub(crate) fn binary_slices<O: BinaryOp>(a: &[f32], b: &[f32]) -> Vec<f32> {
+    collect_uninit(a.len(), |c| binary_slices_into::<O>(a, b, c))
+}
+
+/// Core SIMD binary function writing into `out` (Processes chunks in parallel)
+#[inline(always)]
+pub(crate) fn binary_slices_into<O: BinaryOp>(a: &[f32], b: &[f32], out: &mut [MaybeUninit<f32>]) {
+    let kernel = BinaryKernel::<O> {
+        a,
+        b,
+        op: PhantomData,
+    };
+    dispatch(&kernel, out);
+}
+
+struct BroadcastKernel<'a, O> {
+    slice: &'a [f32],
+    scalar: f32,
+    // Whether the scalar is the left operand of `O`
+    scalar_lhs: bool,
+    op: PhantomData<fn() -> O>,
+}
+
+impl<O: BinaryOp> Kernel for BroadcastKernel<'_, O> {
+    type Out = MaybeUninit<f32>;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]) {
+        let end = offset + out.len();
+        let slice = &self.slice[offset..end];
+
+        let scalar = V::splat(self.scalar);
+
+        if self.scalar_lhs {
+            unary::<V>(
+                slice,
+                out,
+                #[inline(always)]
+                |x| O::apply(scalar, x),
+            );
+        } else {
+            unary::<V>(
+                slice,
+                out,
+                #[inline(always)]
+                |x| O::apply(x, scalar),
+            );
+        }
+    }
+}
+
+/// Core SIMD binary function with a broadcast right operand (Processes chunks in parallel)
+#[inline(always)]
+pub(crate) fn binary_slice_scalar<O: BinaryOp>(a: &[f32], b: f32) -> Vec<f32> {
+    collect_uninit(a.len(), |c| broadcast_slices_into::<O>(a, b, false, c))
+}
+
+/// Core SIMD binary function with a broadcast right operand writing into `out`
+#[inline(always)]
+pub(crate) fn binary_slice_scalar_into<O: BinaryOp>(
+    a: &[f32],
+    b: f32,
+    out: &mut [MaybeUninit<f32>],
+) {
+    broadcast_slices_into::<O>(a, b, false, out)
+}
+
+/// Core SIMD binary function with a broadcast left operand (Processes chunks in parallel)
+#[inline(always)]
+pub(crate) fn binary_scalar_slice<O: BinaryOp>(a: f32, b: &[f32]) -> Vec<f32> {
+    collect_uninit(b.len(), |c| broadcast_slices_into::<O>(b, a, true, c))
+}
+
+/// Core SIMD binary function with a broadcast left operand writing into `out`
+#[inline(always)]
+pub(crate) fn binary_scalar_slice_into<O: BinaryOp>(
+    a: f32,
+    b: &[f32],
+    out: &mut [MaybeUninit<f32>],
+) {
+    broadcast_slices_into::<O>(b, a, true, out)
+}
+
+#[inline(always)]
+fn broadcast_slices_into<O: BinaryOp>(
+    slice: &[f32],
+    scalar: f32,
+    scalar_lhs: bool,
+    out: &mut [MaybeUninit<f32>],
+) {
+    let kernel = BroadcastKernel::<O> {
+        slice,
+        scalar,
+        scalar_lhs,
+        op: PhantomData,
+    };
+    dispatch(&kernel, out);
+}
+
+struct BinaryAssignKernel<'a, O> {
+    b: &'a [f32],
+    op: PhantomData<fn() -> O>,
+}
+
+impl<O: BinaryOp> Kernel for BinaryAssignKernel<'_, O> {
+    type Out = f32;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [f32]) {
+        let end = offset + out.len();
+
+        binary_assign::<V>(
+            out,
+            &self.b[offset..end],
+            #[inline(always)]
+            |a, b| O::apply(a, b),
+        );
+    }
+}
+
+/// Core SIMD in-place binary function, writes into `a` (Processes chunks in parallel)
+#[inline(always)]
+pub(crate) fn binary_assign_slices<O: BinaryOp>(a: &mut [f32], b: &[f32]) {
+    let kernel = BinaryAssignKernel::<O> { b, op: PhantomData };
+    dispatch(&kernel, a);
+}
+
+struct BroadcastAssignKernel<O> {
+    scalar: f32,
+    op: PhantomData<fn() -> O>,
+}
+
+impl<O: BinaryOp> Kernel for BroadcastAssignKernel<O> {
+    type Out = f32;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, _offset: usize, out: &mut [f32]) {
+        let scalar = V::splat(self.scalar);
+
+        unary_assign::<V>(
+            out,
+            #[inline(always)]
+            |x| O::apply(x, scalar),
+        );
+    }
+}
+
+/// Core SIMD in-place binary function with a broadcast right operand, writes into `a`
+#[inline(always)]
+pub(crate) fn binary_assign_scalar<O: BinaryOp>(a: &mut [f32], b: f32) {
+    let kernel = BroadcastAssignKernel::<O> {
+        scalar: b,
+        op: PhantomData,
+    };
+    dispatch(&kernel, a);
+}
+
+/// Declares the slice traits of a `BinaryOp`, and implements them on slices, `Vec`
+/// and f32 broadcasts through the kernels above
+///
+/// Takes the doc comments and method names of the value, `_assign` and `_into`
+/// traits, then the `BinaryOp` running them, e.g. `AddOp` for `SimdAdd`.
+macro_rules! binary_op {
+    (
+        $(#[$attr:meta])*
+        pub trait $Trait:ident { $try_op:ident, $op:ident }
+
+        $(#[$assign_attr:meta])*
+        pub trait $Assign:ident { $try_assign:ident, $assign:ident }
+
+        $(#[$into_attr:meta])*
+        pub trait $Into:ident {
+            $try_into_uninit:ident,
+            $try_into:ident,
+            $into_uninit:ident,
+            $into:ident
+        }
+
+        impl for $Op:ty;
+    ) => {
+        $(#[$attr])*
+        pub trait $Trait<Rhs = Self>: Sized {
+            type Output;
+
+            /// Fails with `LengthMismatch` instead of panicking on operands of different sizes
+            fn $try_op(self, rhs: Rhs) -> Result<Self::Output, $crate::error::ArithmeticsError>;
+
+            #[inline(always)]
+            fn $op(self, rhs: Rhs) -> Self::Output {
+                self.$try_op(rhs).unwrap_or_else(|err| panic!("{}", err))
+            }
+        }
+
+        $(#[$assign_attr])*
+        pub trait $Assign<Rhs> {
+            /// Fails with `LengthMismatch`, leaving `self` untouched, instead of panicking
+            fn $try_assign(&mut self, rhs: Rhs) -> Result<(), $crate::error::ArithmeticsError>;
+
+            #[inline(always)]
+            fn $assign(&mut self, rhs: Rhs) {
+                self.$try_assign(rhs).unwrap_or_else(|err| panic!("{}", err))
+            }
+        }
+
+        $(#[$into_attr])*
+        pub trait $Into<Rhs>: $crate::ops::dispatch::Sealed + Sized {
+            /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+            ///
+            /// Fails with `LengthMismatch`, leaving `out` untouched, instead of panicking.
+            fn $try_into_uninit(
+                self,
+                rhs: Rhs,
+                out: &mut [std::mem::MaybeUninit<f32>],
+            ) -> Result<&mut [f32], $crate::error::ArithmeticsError>;
+
+            #[doc = concat!("Writes the results into `out`, see `", stringify!($try_into_uninit), "`")]
+            #[inline(always)]
+            fn $try_into(
+                self,
+                rhs: Rhs,
+                out: &mut [f32],
+            ) -> Result<(), $crate::error::ArithmeticsError> {
+                // Sealed, the implementations of this crate only write valid values
+                self.$try_into_uninit(rhs, unsafe { $crate::ops::dispatch::as_uninit_mut(out) })
+                    .map(|_| ())
+            }
+
+            /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+            #[inline(always)]
+            fn $into_uninit(self, rhs: Rhs, out: &mut [std::mem::MaybeUninit<f32>]) -> &mut [f32] {
+                self.$try_into_uninit(rhs, out)
+                    .unwrap_or_else(|err| panic!("{}", err))
+            }
+
+            /// Writes the results into `out`
+            #[inline(always)]
+            fn $into(self, rhs: Rhs, out: &mut [f32]) {
+                self.$try_into(rhs, out)
+                    .unwrap_or_else(|err| panic!("{}", err))
+            }
+        }
+
+        impl $Trait for Vec<f32> {
+            type Output = Vec<f32>;
+
+            #[inline(always)]
+            fn $try_op(
+                mut self,
+                rhs: Vec<f32>,
+            ) -> Result<Self::Output, $crate::error::ArithmeticsError> {
+                self.$try_assign(rhs.as_slice())?;
+
+                Ok(self)
+            }
+        }
+
+        impl<'rhsl> $Trait<&'rhsl [f32]> for &[f32] {
+            type Output = Vec<f32>;
+
+            #[inline(always)]
+            fn $try_op(
+                self,
+                rhs: &'rhsl [f32],
+            ) -> Result<Self::Output, $crate::error::ArithmeticsError> {
+                $crate::error::ArithmeticsError::check_len(self.len(), rhs.len())?;
+
+                Ok($crate::ops::dispatch::binary_slices::<$Op>(self, rhs))
+            }
+        }
+
+        impl $Trait<f32> for Vec<f32> {
+            type Output = Vec<f32>;
+
+            #[inline(always)]
+            fn $try_op(mut self, rhs: f32) -> Result<Self::Output, $crate::error::ArithmeticsError> {
+                self.$try_assign(rhs)?;
+
+                Ok(self)
+            }
+        }
+
+        impl $Trait<f32> for &[f32] {
+            type Output = Vec<f32>;
+
+            #[inline(always)]
+            fn $try_op(self, rhs: f32) -> Result<Self::Output, $crate::error::ArithmeticsError> {
+                Ok($crate::ops::dispatch::binary_slice_scalar::<$Op>(self, rhs))
+            }
+        }
+
+        impl<'rhsl> $Trait<&'rhsl [f32]> for f32 {
+            type Output = Vec<f32>;
+
+            #[inline(always)]
+            fn $try_op(
+                self,
+                rhs: &'rhsl [f32],
+            ) -> Result<Self::Output, $crate::error::ArithmeticsError> {
+                Ok($crate::ops::dispatch::binary_scalar_slice::<$Op>(self, rhs))
+            }
+        }
+
+        impl<'rhsl> $Assign<&'rhsl [f32]> for [f32] {
+            #[inline(always)]
+            fn $try_assign(&mut self, rhs: &'rhsl [f32]) -> Result<(), $crate::error::ArithmeticsError> {
+                $crate::error::ArithmeticsError::check_len(self.len(), rhs.len())?;
+
+                $crate::ops::dispatch::binary_assign_slices::<$Op>(self, rhs);
+
+                Ok(())
+            }
+        }
+
+        impl $Assign<f32> for [f32] {
+            #[inline(always)]
+            fn $try_assign(&mut self, rhs: f32) -> Result<(), $crate::error::ArithmeticsError> {
+                $crate::ops::dispatch::binary_assign_scalar::<$Op>(self, rhs);
+
+                Ok(())
+            }
+        }
+
+        impl<'rhsl> $Into<&'rhsl [f32]> for &[f32] {
+            #[inline(always)]
+            fn $try_into_uninit<'o>(
+                self,
+                rhs: &'rhsl [f32],
+                out: &'o mut [std::mem::MaybeUninit<f32>],
+            ) -> Result<&'o mut [f32], $crate::error::ArithmeticsError> {
+                $crate::error::ArithmeticsError::check_len(self.len(), rhs.len())?;
+                $crate::error::ArithmeticsError::check_len(self.len(), out.len())?;
+
+                $crate::ops::dispatch::binary_slices_into::<$Op>(self, rhs, out);
+
+                Ok(unsafe { $crate::ops::dispatch::assume_init_mut(out) })
+            }
+        }
+
+        impl $Into<f32> for &[f32] {
+            #[inline(always)]
+            fn $try_into_uninit(
+                self,
+                rhs: f32,
+                out: &mut [std::mem::MaybeUninit<f32>],
+            ) -> Result<&mut [f32], $crate::error::ArithmeticsError> {
+                $crate::error::ArithmeticsError::check_len(self.len(), out.len())?;
+
+                $crate::ops::dispatch::binary_slice_scalar_into::<$Op>(self, rhs, out);
+
+                Ok(unsafe { $crate::ops::dispatch::assume_init_mut(out) })
+            }
+        }
+
+        impl<'rhsl> $Into<&'rhsl [f32]> for f32 {
+            #[inline(always)]
+            fn $try_into_uninit<'o>(
+                self,
+                rhs: &'rhsl [f32],
+                out: &'o mut [std::mem::MaybeUninit<f32>],
+            ) -> Result<&'o mut [f32], $crate::error::ArithmeticsError> {
+                $crate::error::ArithmeticsError::check_len(rhs.len(), out.len())?;
+
+                $crate::ops::dispatch::binary_scalar_slice_into::<$Op>(self, rhs, out);
+
+                Ok(unsafe { $crate::ops::dispatch::assume_init_mut(out) })
+            }
+        }
+    };
+}
+
+pub(crate) use binary_op;
+
+/// A lane-wise operation on one register, e.g. `sqrt(a)`
+pub(crate) trait UnaryOp {
+    fn apply<V: SimdVec<f32>>(a: V) -> V;
+}
+
+struct UnaryKernel<'a, O> {
+    a: &'a [f32],
+    op: PhantomData<fn() -> O>,
+}
+
+impl<O: UnaryOp> Kernel for UnaryKernel<'_, O> {
+    type Out = MaybeUninit<f32>;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]) {
+        let end = offset + out.len();
+
+        // Closures and fn items are compiled without the kernel's target features unless
+        // inlined, which large operations (e.g. `exp`) would otherwise not be
+        unary::<V>(
+            &self.a[offset..end],
+            out,
+            #[inline(always)]
+            |a| O::apply(a),
+        );
+    }
+}
+
+/// Core SIMD unary function writing into `out` (Processes chunks in parallel)
+#[inline(always)]
+pub(crate) fn unary_slice_into<O: UnaryOp>(a: &[f32], out: &mut [MaybeUninit<f32>]) {
+    let kernel = UnaryKernel::<O> { a, op: PhantomData };
+    dispatch(&kernel, out);
+}
+
+struct UnaryAssignKernel<O> {
+    op: PhantomData<fn() -> O>,
+}
+
+impl<O: UnaryOp> Kernel for UnaryAssignKernel<O> {
+    type Out = f32;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, _offset: usize, out: &mut [f32]) {
+        unary_assign::<V>(
+            out,
+            #[inline(always)]
+            |a| O::apply(a),
+        );
+    }
+}
+
+/// Core SIMD in-place unary function, writes into `a` (Processes chunks in parallel)
+#[inline(always)]
+pub(crate) fn unary_assign_slice<O: UnaryOp>(a: &mut [f32]) {
+    let kernel = UnaryAssignKernel::<O> { op: PhantomData };
+    dispatch(&kernel, a);
+}
+
+/// Applies `op` lane-wise over `a` and `b`, writing the results into `out`
+///
+/// The tail is padded like in `unary`.
+#[inline(always)]
+pub(crate) fn binary<V: SimdVec<f32>>(
+    a: &[f32],
+    b: &[f32],
+    out: &mut [MaybeUninit<f32>],
+    op: impl Fn(V, V) -> V,
+) {
+    debug_assert!(V::SIZE <= MAX_SIZE, "Register size must be <= {}", MAX_SIZE);
+
+    let mut a_chunks = a.chunks_exact(V::SIZE);
+    let mut b_chunks = b.chunks_exact(V::SIZE);
+    let mut out_chunks = out.chunks_exact_mut(V::SIZE);
+
+    for (c_chunk, (a_chunk, b_chunk)) in out_chunks
+        .by_ref()
+        .zip(a_chunks.by_ref().zip(b_chunks.by_ref()))
+    {
+        unsafe { op(V::new(a_chunk), V::new(b_chunk)).store_at(c_chunk.as_mut_ptr().cast()) }
+    }
+
+    let a_tail = a_chunks.remainder();
+    let b_tail = b_chunks.remainder();
+    let out_tail = out_chunks.into_remainder();
+
+    if !out_tail.is_empty() {
+        let mut a_buffer = [0f32; MAX_SIZE];
+        let mut b_buffer = [0f32; MAX_SIZE];
+        a_buffer[..a_tail.len()].copy_from_slice(a_tail);
+        b_buffer[..b_tail.len()].copy_from_slice(b_tail);
+
+        let c = op(V::new(&a_buffer[..V::SIZE]), V::new(&b_buffer[..V::SIZE]));
+        unsafe { c.store_at(a_buffer.as_mut_ptr()) }
+
+        for (o, c) in out_tail.iter_mut().zip(a_buffer) {
+            o.write(c);
+        }
+    }
+}
+
+/// Applies `op` lane-wise over `a`, `b` and `c`, writing the results into `out`
+#[inline(always)]
+pub(crate) fn ternary<V: SimdVec<f32>>(
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+    out: &mut [MaybeUninit<f32>],
+    op: impl Fn(V, V, V) -> V,
+) {
+    out.chunks_mut(V::SIZE)
+        .zip(a.chunks(V::SIZE))
+        .zip(b.chunks(V::SIZE).zip(c.chunks(V::SIZE)))
+        .for_each(|((d_chunk, a_chunk), (b_chunk, c_chunk))| {
+            let d = op(V::new(a_chunk), V::new(b_chunk), V::new(c_chunk));
+
+            if d_chunk.len() == V::SIZE {
+                unsafe { d.store_at(d_chunk.as_mut_ptr().cast()) }
+            } else {
+                unsafe { d.store_at_partial(d_chunk.as_mut_ptr().cast()) }
+            }
+        });
+}
+
+/// Applies `op` lane-wise over `a`, writing the results into `out`
+///
+/// The tail is padded with zeros to a full register, so `op` can freely combine
+/// its input with `SimdVec::splat` registers.
+#[inline(always)]
+pub(crate) fn unary<V: SimdVec<f32>>(a: &[f32], out: &mut [MaybeUninit<f32>], op: impl Fn(V) -> V) {
+    debug_assert!(V::SIZE <= MAX_SIZE, "Register size must be <= {}", MAX_SIZE);
+
+    let mut a_chunks = a.chunks_exact(V::SIZE);
+    let mut out_chunks = out.chunks_exact_mut(V::SIZE);
+
+    for (b_chunk, a_chunk) in out_chunks.by_ref().zip(a_chunks.by_ref()) {
+        unsafe { op(V::new(a_chunk)).store_at(b_chunk.as_mut_ptr().cast()) }
+    }
+
+    let a_tail = a_chunks.remainder();
+    let out_tail = out_chunks.into_remainder();
+
+    if !out_tail.is_empty() {
+        let mut buffer = [0f32; MAX_SIZE];
+        buffer[..a_tail.len()].copy_from_slice(a_tail);
+
+        let b = op(V::new(&buffer[..V::SIZE]));
+        unsafe { b.store_at(buffer.as_mut_ptr()) }
+
+        for (o, b) in out_tail.iter_mut().zip(buffer) {
+            o.write(b);
+        }
+    }
+}
+
+/// Applies `op` lane-wise over `out` and `b`, writing the results back into `out`
+///
+/// The tail is padded like in `unary`.
+#[inline(always)]
+pub(crate) fn binary_assign<V: SimdVec<f32>>(out: &mut [f32], b: &[f32], op: impl Fn(V, V) -> V) {
+    debug_assert!(V::SIZE <= MAX_SIZE, "Register size must be <= {}", MAX_SIZE);
+
+    let mut chunks = out.chunks_exact_mut(V::SIZE);
+    let mut b_chunks = b.chunks_exact(V::SIZE);
+
+    for (chunk, b_chunk) in chunks.by_ref().zip(b_chunks.by_ref()) {
+        unsafe { op(V::new(chunk), V::new(b_chunk)).store_at(chunk.as_mut_ptr()) }
+    }
+
+    let tail = chunks.into_remainder();
+    let b_tail = b_chunks.remainder();
+
+    if !tail.is_empty() {
+        let mut buffer = [0f32; MAX_SIZE];
+        let mut b_buffer = [0f32; MAX_SIZE];
+        buffer[..tail.len()].copy_from_slice(tail);
+        b_buffer[..b_tail.len()].copy_from_slice(b_tail);
+
+        let c = op(V::new(&buffer[..V::SIZE]), V::new(&b_buffer[..V::SIZE]));
+        unsafe { c.store_at(buffer.as_mut_ptr()) }
+
+        let len = tail.len();
+        tail.copy_from_slice(&buffer[..len]);
+    }
+}
+
+/// Applies `op` lane-wise over `out`, `b` and `c`, writing the results back into `out`
+#[inline(always)]
+pub(crate) fn ternary_assign<V: SimdVec<f32>>(
+    out: &mut [f32],
+    b: &[f32],
+    c: &[f32],
+    op: impl Fn(V, V, V) -> V,
+) {
+    out.chunks_mut(V::SIZE)
+        .zip(b.chunks(V::SIZE).zip(c.chunks(V::SIZE)))
+        .for_each(|(a_chunk, (b_chunk, c_chunk))| {
+            let d = op(V::new(a_chunk), V::new(b_chunk), V::new(c_chunk));
+
+            if a_chunk.len() == V::SIZE {
+                unsafe { d.store_at(a_chunk.as_mut_ptr()) }
+            } else {
+                unsafe { d.store_at_partial(a_chunk.as_mut_ptr()) }
+            }
+        });
+}
+
+/// Applies `op` lane-wise over `out`, writing the results back into `out`
+///
+/// The tail is padded like in `unary`.
+#[inline(always)]
+pub(crate) fn unary_assign<V: SimdVec<f32>>(out: &mut [f32], op: impl Fn(V) -> V) {
+    debug_assert!(V::SIZE <= MAX_SIZE, "Register size must be <= {}", MAX_SIZE);
+
+    let mut chunks = out.chunks_exact_mut(V::SIZE);
+
+    for chunk in chunks.by_ref() {
+        unsafe { op(V::new(chunk)).store_at(chunk.as_mut_ptr()) }
+    }
+
+    let tail = chunks.into_remainder();
+
+    if !tail.is_empty() {
+        let mut buffer = [0f32; MAX_SIZE];
+        buffer[..tail.len()].copy_from_slice(tail);
+
+        let b = op(V::new(&buffer[..V::SIZE]));
+        unsafe { b.store_at(buffer.as_mut_ptr()) }
+
+        let len = tail.len();
+        tail.copy_from_slice(&buffer[..len]);
+    }
+}
@@ -0,0 +1,30 @@
+use super::dispatch::{binary_op, BinaryOp};
+use crate::simd::utils::SimdVec;
+
+/// Element-wise division, as an `Expr` node operation
+pub struct DivOp;
+
+impl BinaryOp for DivOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V {
+        a.simd_div(b)
+    }
+}
+
+binary_op! {
+    /// Element-wise division
+    pub trait SimdDiv { try_simd_div, simd_div }
+
+    /// In-place element-wise division, writes the results into `self`
+    pub trait SimdDivAssign { try_simd_div_assign, simd_div_assign }
+
+    /// Element-wise division into a caller-provided buffer of the operands' size
+    pub trait SimdDivInto {
+        try_simd_div_into_uninit,
+        try_simd_div_into,
+        simd_div_into_uninit,
+        simd_div_into
+    }
+
+    impl for DivOp;
+}
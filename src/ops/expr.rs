@@ -0,0 +1,462 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::accuracy::{Accuracy, CorrectlyRoundedOp, FastOp};
+use super::add::AddOp;
+use super::atan2::Atan2Op;
+use super::clamp::check_bounds;
+use super::dispatch::{
+    as_uninit_mut, assume_init_mut, collect_uninit, dispatch, BinaryOp, Kernel, MAX_SIZE,
+};
+use super::div::DivOp;
+use super::max::MaxOp;
+use super::min::MinOp;
+use super::mul::MulOp;
+use super::sub::SubOp;
+use crate::error::ArithmeticsError;
+use crate::simd::utils::SimdVec;
+
+/// A node of a lazily evaluated element-wise expression
+///
+/// Nodes are built through `expr` and the operators of `Expr`, the whole tree is
+/// evaluated register by register in a single pass over the output.
+pub trait Node: Sync {
+    /// Number of elements, `None` for a broadcast scalar
+    ///
+    /// Fails if the tree is malformed, e.g. with `LengthMismatch` if two slices
+    /// have different sizes.
+    fn size(&self) -> Result<Option<usize>, ArithmeticsError>;
+
+    /// Evaluates the `len <= V::SIZE` elements starting at `offset`
+    ///
+    /// Lanes past `len` hold unspecified values.
+    fn load<V: SimdVec<f32>>(&self, offset: usize, len: usize) -> V;
+}
+
+/// A lazily evaluated element-wise expression, see `expr`
+///
+/// Combine expressions, slices and f32 scalars with `+`, `-`, `*`, `/`, `min`,
+/// `max`, `atan2`, `fma` and `clamp`, then compute the result with `eval`. Chaining
+/// `a + b * c - d` this way reads each operand once and writes the output
+/// once, instead of allocating and sweeping a `Vec` per operator.
+#[derive(Copy, Clone, Debug)]
+pub struct Expr<N>(N);
+
+/// Starts an expression over the elements of `a`, a slice or a `StridedSlice`
+#[inline(always)]
+pub fn expr<T: IntoNode>(a: T) -> Expr<T::Node> {
+    Expr(a.into_node())
+}
+
+/// Operands accepted by the `Expr` operators
+pub trait IntoNode {
+    type Node: Node;
+
+    fn into_node(self) -> Self::Node;
+}
+
+/// Leaf reading the elements of a slice
+#[derive(Copy, Clone, Debug)]
+pub struct Slice<'a>(&'a [f32]);
+
+/// Leaf broadcasting a scalar to every element
+#[derive(Copy, Clone, Debug)]
+pub struct Scalar(f32);
+
+/// Lane-wise operation on two nodes
+#[derive(Debug)]
+pub struct Binary<A, B, O> {
+    a: A,
+    b: B,
+    op: PhantomData<fn() -> O>,
+}
+
+/// Four-quadrant arctangent of two nodes, see `SimdAtan2`
+///
+/// Runs with the `Accuracy::active` of when the node was built.
+#[derive(Copy, Clone, Debug)]
+pub struct Atan2<A, B> {
+    a: A,
+    b: B,
+    accuracy: Accuracy,
+}
+
+/// Fused multiply-add of three nodes, see `SimdFma`
+#[derive(Copy, Clone, Debug)]
+pub struct Fma<A, B, C> {
+    a: A,
+    b: B,
+    c: C,
+}
+
+/// Clamp of a node to `[lo, hi]`, see `SimdClamp`
+#[derive(Copy, Clone, Debug)]
+pub struct Clamp<N> {
+    a: N,
+    lo: f32,
+    hi: f32,
+}
+
+impl<A: Copy, B: Copy, O> Clone for Binary<A, B, O> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Copy, B: Copy, O> Copy for Binary<A, B, O> {}
+
+// Size of a node made of two nodes, scalars take the size of the other side
+#[inline(always)]
+fn merge_size(
+    left: Option<usize>,
+    right: Option<usize>,
+) -> Result<Option<usize>, ArithmeticsError> {
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            ArithmeticsError::check_len(left, right)?;
+            Ok(Some(left))
+        }
+        (left, right) => Ok(left.or(right)),
+    }
+}
+
+impl Node for Slice<'_> {
+    #[inline(always)]
+    fn size(&self) -> Result<Option<usize>, ArithmeticsError> {
+        Ok(Some(self.0.len()))
+    }
+
+    #[inline(always)]
+    fn load<V: SimdVec<f32>>(&self, offset: usize, len: usize) -> V {
+        let slice = &self.0[offset..offset + len];
+
+        if len == V::SIZE {
+            V::new(slice)
+        } else {
+            // Pad the tail to a full register, so it combines with splat registers
+            let mut buffer = [0f32; MAX_SIZE];
+            buffer[..len].copy_from_slice(slice);
+
+            V::new(&buffer[..V::SIZE])
+        }
+    }
+}
+
+impl Node for Scalar {
+    #[inline(always)]
+    fn size(&self) -> Result<Option<usize>, ArithmeticsError> {
+        Ok(None)
+    }
+
+    #[inline(always)]
+    fn load<V: SimdVec<f32>>(&self, _offset: usize, _len: usize) -> V {
+        V::splat(self.0)
+    }
+}
+
+impl<A: Node, B: Node, O: BinaryOp> Node for Binary<A, B, O> {
+    #[inline(always)]
+    fn size(&self) -> Result<Option<usize>, ArithmeticsError> {
+        merge_size(self.a.size()?, self.b.size()?)
+    }
+
+    #[inline(always)]
+    fn load<V: SimdVec<f32>>(&self, offset: usize, len: usize) -> V {
+        O::apply(self.a.load(offset, len), self.b.load(offset, len))
+    }
+}
+
+impl<A: Node, B: Node> Node for Atan2<A, B> {
+    #[inline(always)]
+    fn size(&self) -> Result<Option<usize>, ArithmeticsError> {
+        merge_size(self.a.size()?, self.b.size()?)
+    }
+
+    #[inline(always)]
+    fn load<V: SimdVec<f32>>(&self, offset: usize, len: usize) -> V {
+        let (a, b) = (self.a.load(offset, len), self.b.load(offset, len));
+
+        match self.accuracy {
+            Accuracy::Fast => FastOp::<Atan2Op>::apply(a, b),
+            Accuracy::Default => Atan2Op::apply(a, b),
+            Accuracy::CorrectlyRounded => CorrectlyRoundedOp::<Atan2Op>::apply(a, b),
+        }
+    }
+}
+
+impl<A: Node, B: Node, C: Node> Node for Fma<A, B, C> {
+    #[inline(always)]
+    fn size(&self) -> Result<Option<usize>, ArithmeticsError> {
+        merge_size(merge_size(self.a.size()?, self.b.size()?)?, self.c.size()?)
+    }
+
+    #[inline(always)]
+    fn load<V: SimdVec<f32>>(&self, offset: usize, len: usize) -> V {
+        let a: V = self.a.load(offset, len);
+
+        a.simd_fma(self.b.load(offset, len), self.c.load(offset, len))
+    }
+}
+
+impl<N: Node> Node for Clamp<N> {
+    #[inline(always)]
+    fn size(&self) -> Result<Option<usize>, ArithmeticsError> {
+        check_bounds(self.lo, self.hi)?;
+
+        self.a.size()
+    }
+
+    #[inline(always)]
+    fn load<V: SimdVec<f32>>(&self, offset: usize, len: usize) -> V {
+        let a: V = self.a.load(offset, len);
+
+        a.simd_clamp(V::splat(self.lo), V::splat(self.hi))
+    }
+}
+
+impl<N: Node> IntoNode for Expr<N> {
+    type Node = N;
+
+    #[inline(always)]
+    fn into_node(self) -> N {
+        self.0
+    }
+}
+
+impl<'a> IntoNode for &'a [f32] {
+    type Node = Slice<'a>;
+
+    #[inline(always)]
+    fn into_node(self) -> Slice<'a> {
+        Slice(self)
+    }
+}
+
+impl<'a> IntoNode for &'a Vec<f32> {
+    type Node = Slice<'a>;
+
+    #[inline(always)]
+    fn into_node(self) -> Slice<'a> {
+        Slice(self.as_slice())
+    }
+}
+
+impl IntoNode for f32 {
+    type Node = Scalar;
+
+    #[inline(always)]
+    fn into_node(self) -> Scalar {
+        Scalar(self)
+    }
+}
+
+struct ExprKernel<'a, N> {
+    node: &'a N,
+}
+
+impl<N: Node> Kernel for ExprKernel<'_, N> {
+    type Out = MaybeUninit<f32>;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]) {
+        for (i, chunk) in out.chunks_mut(V::SIZE).enumerate() {
+            let len = chunk.len();
+            let c: V = self.node.load(offset + i * V::SIZE, len);
+
+            if len == V::SIZE {
+                unsafe { c.store_at(chunk.as_mut_ptr().cast()) }
+            } else {
+                let mut buffer = [0f32; MAX_SIZE];
+                unsafe { c.store_at(buffer.as_mut_ptr()) }
+
+                for (o, b) in chunk.iter_mut().zip(buffer) {
+                    o.write(b);
+                }
+            }
+        }
+    }
+}
+
+impl<N: Node> Expr<N> {
+    #[inline(always)]
+    pub(crate) fn binary<R: IntoNode, O>(self, rhs: R) -> Expr<Binary<N, R::Node, O>> {
+        Expr(Binary {
+            a: self.0,
+            b: rhs.into_node(),
+            op: PhantomData,
+        })
+    }
+
+    /// Element-wise minimum, see `SimdMin`
+    #[inline(always)]
+    pub fn min<R: IntoNode>(self, rhs: R) -> Expr<Binary<N, R::Node, MinOp>> {
+        self.binary(rhs)
+    }
+
+    /// Element-wise maximum, see `SimdMax`
+    #[inline(always)]
+    pub fn max<R: IntoNode>(self, rhs: R) -> Expr<Binary<N, R::Node, MaxOp>> {
+        self.binary(rhs)
+    }
+
+    /// Four-quadrant arctangent of `self / rhs`, see `SimdAtan2`
+    ///
+    /// Runs with `Accuracy::active` as of this call, like the slice operations.
+    #[inline(always)]
+    pub fn atan2<R: IntoNode>(self, rhs: R) -> Expr<Atan2<N, R::Node>> {
+        Expr(Atan2 {
+            a: self.0,
+            b: rhs.into_node(),
+            accuracy: Accuracy::active(),
+        })
+    }
+
+    /// Fused multiply-add `self * b + c`, see `SimdFma`
+    #[inline(always)]
+    pub fn fma<B: IntoNode, C: IntoNode>(self, b: B, c: C) -> Expr<Fma<N, B::Node, C::Node>> {
+        Expr(Fma {
+            a: self.0,
+            b: b.into_node(),
+            c: c.into_node(),
+        })
+    }
+
+    /// Element-wise clamp to `[lo, hi]`, see `SimdClamp`
+    #[inline(always)]
+    pub fn clamp(self, lo: f32, hi: f32) -> Expr<Clamp<N>> {
+        Expr(Clamp { a: self.0, lo, hi })
+    }
+
+    /// Evaluates the expression in a single pass (Processes chunks in parallel)
+    ///
+    /// Fails with `LengthMismatch` instead of panicking on slices of different sizes.
+    pub fn try_eval(self) -> Result<Vec<f32>, ArithmeticsError> {
+        let len = self.0.size()?.unwrap_or(0);
+
+        Ok(collect_uninit(len, |out| {
+            dispatch(&ExprKernel { node: &self.0 }, out)
+        }))
+    }
+
+    /// Evaluates the expression into `out`, which may be uninitialized, and returns it initialized
+    ///
+    /// Fails with `LengthMismatch`, leaving `out` untouched, instead of panicking.
+    pub fn try_eval_into_uninit(
+        self,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError> {
+        if let Some(len) = self.0.size()? {
+            ArithmeticsError::check_len(len, out.len())?;
+        }
+
+        dispatch(&ExprKernel { node: &self.0 }, out);
+
+        Ok(unsafe { assume_init_mut(out) })
+    }
+
+    /// Evaluates the expression into `out`, see `try_eval_into_uninit`
+    pub fn try_eval_into(self, out: &mut [f32]) -> Result<(), ArithmeticsError> {
+        // `try_eval_into_uninit` only writes valid values
+        self.try_eval_into_uninit(unsafe { as_uninit_mut(out) })
+            .map(|_| ())
+    }
+
+    /// Evaluates the expression in a single pass (Processes chunks in parallel)
+    pub fn eval(self) -> Vec<f32> {
+        self.try_eval().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Evaluates the expression into `out`, which may be uninitialized, and returns it initialized
+    pub fn eval_into_uninit(self, out: &mut [MaybeUninit<f32>]) -> &mut [f32] {
+        self.try_eval_into_uninit(out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Evaluates the expression into `out`
+    pub fn eval_into(self, out: &mut [f32]) {
+        self.try_eval_into(out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Implementation of Add trait for Expr, building an `a + b` node
+impl<N: Node, R: IntoNode> Add<R> for Expr<N> {
+    type Output = Expr<Binary<N, R::Node, AddOp>>;
+
+    #[inline(always)]
+    fn add(self, rhs: R) -> Self::Output {
+        self.binary(rhs)
+    }
+}
+
+/// Implementation of Sub trait for Expr, building an `a - b` node
+impl<N: Node, R: IntoNode> Sub<R> for Expr<N> {
+    type Output = Expr<Binary<N, R::Node, SubOp>>;
+
+    #[inline(always)]
+    fn sub(self, rhs: R) -> Self::Output {
+        self.binary(rhs)
+    }
+}
+
+/// Implementation of Mul trait for Expr, building an `a * b` node
+impl<N: Node, R: IntoNode> Mul<R> for Expr<N> {
+    type Output = Expr<Binary<N, R::Node, MulOp>>;
+
+    #[inline(always)]
+    fn mul(self, rhs: R) -> Self::Output {
+        self.binary(rhs)
+    }
+}
+
+/// Implementation of Div trait for Expr, building an `a / b` node
+impl<N: Node, R: IntoNode> Div<R> for Expr<N> {
+    type Output = Expr<Binary<N, R::Node, DivOp>>;
+
+    #[inline(always)]
+    fn div(self, rhs: R) -> Self::Output {
+        self.binary(rhs)
+    }
+}
+
+/// Implementation of Add trait for f32, building a `a + b` node with a broadcast left operand
+impl<N: Node> Add<Expr<N>> for f32 {
+    type Output = Expr<Binary<Scalar, N, AddOp>>;
+
+    #[inline(always)]
+    fn add(self, rhs: Expr<N>) -> Self::Output {
+        Expr(Scalar(self)).binary(rhs)
+    }
+}
+
+/// Implementation of Sub trait for f32, building a `a - b` node with a broadcast left operand
+impl<N: Node> Sub<Expr<N>> for f32 {
+    type Output = Expr<Binary<Scalar, N, SubOp>>;
+
+    #[inline(always)]
+    fn sub(self, rhs: Expr<N>) -> Self::Output {
+        Expr(Scalar(self)).binary(rhs)
+    }
+}
+
+/// Implementation of Mul trait for f32, building a `a * b` node with a broadcast left operand
+impl<N: Node> Mul<Expr<N>> for f32 {
+    type Output = Expr<Binary<Scalar, N, MulOp>>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Expr<N>) -> Self::Output {
+        Expr(Scalar(self)).binary(rhs)
+    }
+}
+
+/// Implementation of Div trait for f32, building a `a / b` node with a broadcast left operand
+impl<N: Node> Div<Expr<N>> for f32 {
+    type Output = Expr<Binary<Scalar, N, DivOp>>;
+
+    #[inline(always)]
+    fn div(self, rhs: Expr<N>) -> Self::Output {
+        Expr(Scalar(self)).binary(rhs)
+    }
+}
@@ -0,0 +1,177 @@
+use std::mem::MaybeUninit;
+
+use super::dispatch::{
+    as_uninit_mut, assume_init_mut, collect_uninit, dispatch, ternary, ternary_assign, Kernel,
+    Sealed,
+};
+use crate::error::ArithmeticsError;
+use crate::simd::utils::SimdVec;
+
+/// Fused multiply-add, computes `self * b + c` in a single pass
+///
+/// Fused (rounded once) on AVX2, AVX-512, NEON and the scalar backend,
+/// SSE has no FMA instruction and rounds the product before the addition.
+pub trait SimdFma<B = Self, C = B>: Sized {
+    type Output;
+
+    /// Fails with `LengthMismatch` instead of panicking on operands of different sizes
+    fn try_simd_fma(self, b: B, c: C) -> Result<Self::Output, ArithmeticsError>;
+
+    #[inline(always)]
+    fn simd_fma(self, b: B, c: C) -> Self::Output {
+        self.try_simd_fma(b, c)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// In-place fused multiply-add, writes `self * b + c` into `self`
+pub trait SimdFmaAssign<B, C = B> {
+    /// Fails with `LengthMismatch`, leaving `self` untouched, instead of panicking
+    fn try_simd_fma_assign(&mut self, b: B, c: C) -> Result<(), ArithmeticsError>;
+
+    #[inline(always)]
+    fn simd_fma_assign(&mut self, b: B, c: C) {
+        self.try_simd_fma_assign(b, c)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Fused multiply-add into a caller-provided buffer of the operands' size
+pub trait SimdFmaInto<B, C = B>: Sealed + Sized {
+    /// Writes `self * b + c` into `out`, which may be uninitialized, and returns it initialized
+    ///
+    /// Fails with `LengthMismatch`, leaving `out` untouched, instead of panicking.
+    fn try_simd_fma_into_uninit(
+        self,
+        b: B,
+        c: C,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError>;
+
+    /// Writes `self * b + c` into `out`, see `try_simd_fma_into_uninit`
+    #[inline(always)]
+    fn try_simd_fma_into(self, b: B, c: C, out: &mut [f32]) -> Result<(), ArithmeticsError> {
+        // Sealed, the implementations of this crate only write valid values
+        self.try_simd_fma_into_uninit(b, c, unsafe { as_uninit_mut(out) })
+            .map(|_| ())
+    }
+
+    /// Writes `self * b + c` into `out`, which may be uninitialized, and returns it initialized
+    #[inline(always)]
+    fn simd_fma_into_uninit(self, b: B, c: C, out: &mut [MaybeUninit<f32>]) -> &mut [f32] {
+        self.try_simd_fma_into_uninit(b, c, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Writes `self * b + c` into `out`
+    #[inline(always)]
+    fn simd_fma_into(self, b: B, c: C, out: &mut [f32]) {
+        self.try_simd_fma_into(b, c, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+struct FmaKernel<'a> {
+    a: &'a [f32],
+    b: &'a [f32],
+    c: &'a [f32],
+}
+
+impl Kernel for FmaKernel<'_> {
+    type Out = MaybeUninit<f32>;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]) {
+        let end = offset + out.len();
+
+        ternary::<V>(
+            &self.a[offset..end],
+            &self.b[offset..end],
+            &self.c[offset..end],
+            out,
+            #[inline(always)]
+            |a, b, c| a.simd_fma(b, c),
+        );
+    }
+}
+
+struct FmaAssignKernel<'a> {
+    b: &'a [f32],
+    c: &'a [f32],
+}
+
+impl Kernel for FmaAssignKernel<'_> {
+    type Out = f32;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [f32]) {
+        let end = offset + out.len();
+
+        ternary_assign::<V>(
+            out,
+            &self.b[offset..end],
+            &self.c[offset..end],
+            #[inline(always)]
+            |a, b, c| a.simd_fma(b, c),
+        );
+    }
+}
+
+/// Core SIMD fused multiply-add function (Processes chunks in parallel)
+#[inline(always)]
+fn fma_slices(a: &[f32], b: &[f32], c: &[f32]) -> Vec<f32> {
+    collect_uninit(a.len(), |d| dispatch(&FmaKernel { a, b, c }, d))
+}
+
+impl SimdFma for Vec<f32> {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_fma(mut self, b: Vec<f32>, c: Vec<f32>) -> Result<Self::Output, ArithmeticsError> {
+        self.try_simd_fma_assign(b.as_slice(), c.as_slice())?;
+
+        Ok(self)
+    }
+}
+
+impl<'b, 'c> SimdFma<&'b [f32], &'c [f32]> for &[f32] {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_fma(self, b: &'b [f32], c: &'c [f32]) -> Result<Self::Output, ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), b.len())?;
+        ArithmeticsError::check_len(self.len(), c.len())?;
+
+        Ok(fma_slices(self, b, c))
+    }
+}
+
+impl<'b, 'c> SimdFmaAssign<&'b [f32], &'c [f32]> for [f32] {
+    #[inline(always)]
+    fn try_simd_fma_assign(&mut self, b: &'b [f32], c: &'c [f32]) -> Result<(), ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), b.len())?;
+        ArithmeticsError::check_len(self.len(), c.len())?;
+
+        dispatch(&FmaAssignKernel { b, c }, self);
+
+        Ok(())
+    }
+}
+
+impl<'b, 'c> SimdFmaInto<&'b [f32], &'c [f32]> for &[f32] {
+    #[inline(always)]
+    fn try_simd_fma_into_uninit<'o>(
+        self,
+        b: &'b [f32],
+        c: &'c [f32],
+        out: &'o mut [MaybeUninit<f32>],
+    ) -> Result<&'o mut [f32], ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), b.len())?;
+        ArithmeticsError::check_len(self.len(), c.len())?;
+        ArithmeticsError::check_len(self.len(), out.len())?;
+
+        dispatch(&FmaKernel { a: self, b, c }, out);
+
+        Ok(unsafe { assume_init_mut(out) })
+    }
+}
@@ -0,0 +1,34 @@
+use super::dispatch::{binary_op, BinaryOp};
+use crate::simd::utils::SimdVec;
+
+/// Element-wise maximum, as an `Expr` node operation
+pub struct MaxOp;
+
+impl BinaryOp for MaxOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V {
+        a.simd_max(b)
+    }
+}
+
+binary_op! {
+    /// Element-wise maximum
+    ///
+    /// Follows IEEE 754 maxNum, like `f32::max`: when exactly one operand is NaN the
+    /// other one is returned, NaN only comes out of two NaN operands. The sign of a
+    /// zero result is unspecified when comparing `-0.0` with `+0.0`.
+    pub trait SimdMax { try_simd_max, simd_max }
+
+    /// In-place element-wise maximum, writes the results into `self`
+    pub trait SimdMaxAssign { try_simd_max_assign, simd_max_assign }
+
+    /// Element-wise maximum into a caller-provided buffer of the operands' size
+    pub trait SimdMaxInto {
+        try_simd_max_into_uninit,
+        try_simd_max_into,
+        simd_max_into_uninit,
+        simd_max_into
+    }
+
+    impl for MaxOp;
+}
@@ -0,0 +1,34 @@
+use super::dispatch::{binary_op, BinaryOp};
+use crate::simd::utils::SimdVec;
+
+/// Element-wise minimum, as an `Expr` node operation
+pub struct MinOp;
+
+impl BinaryOp for MinOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V {
+        a.simd_min(b)
+    }
+}
+
+binary_op! {
+    /// Element-wise minimum
+    ///
+    /// Follows IEEE 754 minNum, like `f32::min`: when exactly one operand is NaN the
+    /// other one is returned, NaN only comes out of two NaN operands. The sign of a
+    /// zero result is unspecified when comparing `-0.0` with `+0.0`.
+    pub trait SimdMin { try_simd_min, simd_min }
+
+    /// In-place element-wise minimum, writes the results into `self`
+    pub trait SimdMinAssign { try_simd_min_assign, simd_min_assign }
+
+    /// Element-wise minimum into a caller-provided buffer of the operands' size
+    pub trait SimdMinInto {
+        try_simd_min_into_uninit,
+        try_simd_min_into,
+        simd_min_into_uninit,
+        simd_min_into
+    }
+
+    impl for MinOp;
+}
@@ -1 +1,16 @@
+pub mod accuracy;
+pub mod activation;
 pub mod add;
+pub mod atan2;
+pub mod clamp;
+pub mod div;
+pub mod expr;
+pub mod fma;
+pub mod max;
+pub mod min;
+pub mod mul;
+pub mod strided;
+pub mod sub;
+pub mod unary;
+
+pub(crate) mod dispatch;
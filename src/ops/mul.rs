@@ -0,0 +1,30 @@
+use super::dispatch::{binary_op, BinaryOp};
+use crate::simd::utils::SimdVec;
+
+/// Element-wise multiplication, as an `Expr` node operation
+pub struct MulOp;
+
+impl BinaryOp for MulOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V {
+        a.simd_mul(b)
+    }
+}
+
+binary_op! {
+    /// Element-wise multiplication
+    pub trait SimdMul { try_simd_mul, simd_mul }
+
+    /// In-place element-wise multiplication, writes the results into `self`
+    pub trait SimdMulAssign { try_simd_mul_assign, simd_mul_assign }
+
+    /// Element-wise multiplication into a caller-provided buffer of the operands' size
+    pub trait SimdMulInto {
+        try_simd_mul_into_uninit,
+        try_simd_mul_into,
+        simd_mul_into_uninit,
+        simd_mul_into
+    }
+
+    impl for MulOp;
+}
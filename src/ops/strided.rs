@@ -0,0 +1,250 @@
+use std::mem::MaybeUninit;
+
+use super::add::{SimdAdd, SimdAddInto};
+use super::clamp::{SimdClamp, SimdClampInto};
+use super::dispatch::{Sealed, MAX_SIZE};
+use super::div::{SimdDiv, SimdDivInto};
+use super::expr::{expr, IntoNode, Node};
+use super::fma::{SimdFma, SimdFmaInto};
+use super::max::{SimdMax, SimdMaxInto};
+use super::min::{SimdMin, SimdMinInto};
+use super::mul::{SimdMul, SimdMulInto};
+use super::sub::{SimdSub, SimdSubInto};
+use crate::error::ArithmeticsError;
+use crate::simd::utils::SimdVec;
+
+/// A read-only view of every `stride`-th element of a slice
+///
+/// Selects a column of a row-major matrix (`StridedSlice::new(&m[j..], cols)`)
+/// or a channel of interleaved data without copying it. Accepted by the
+/// arithmetic traits and `expr`, registers are filled with gathers on AVX2 and
+/// AVX-512, lane by lane on the other backends.
+#[derive(Copy, Clone, Debug)]
+pub struct StridedSlice<'a> {
+    // Ends at the last element of the view
+    data: &'a [f32],
+    len: usize,
+    stride: usize,
+}
+
+impl<'a> StridedSlice<'a> {
+    /// View of `data[0]`, `data[stride]`, `data[2 * stride]`, ...
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is 0.
+    pub fn new(data: &'a [f32], stride: usize) -> Self {
+        Self::try_new(data, stride).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// View of `data[0]`, `data[stride]`, `data[2 * stride]`, ...
+    ///
+    /// Fails with `ZeroStride` instead of panicking if `stride` is 0.
+    pub fn try_new(data: &'a [f32], stride: usize) -> Result<Self, ArithmeticsError> {
+        if stride == 0 {
+            return Err(ArithmeticsError::ZeroStride);
+        }
+
+        let len = data.len().div_ceil(stride);
+        let end = if len == 0 { 0 } else { (len - 1) * stride + 1 };
+
+        Ok(Self {
+            data: &data[..end],
+            len,
+            stride,
+        })
+    }
+
+    /// Number of elements in the view
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Distance between two consecutive elements, in f32
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Element `i` of the view, `None` if out of bounds
+    pub fn get(&self, i: usize) -> Option<f32> {
+        if i < self.len {
+            Some(self.data[i * self.stride])
+        } else {
+            None
+        }
+    }
+
+    /// Copies the elements of the view into a contiguous `Vec`
+    pub fn to_vec(&self) -> Vec<f32> {
+        self.data.iter().step_by(self.stride).copied().collect()
+    }
+}
+
+impl Node for StridedSlice<'_> {
+    #[inline(always)]
+    fn size(&self) -> Result<Option<usize>, ArithmeticsError> {
+        Ok(Some(self.len))
+    }
+
+    #[inline(always)]
+    fn load<V: SimdVec<f32>>(&self, offset: usize, len: usize) -> V {
+        if len == V::SIZE {
+            let start = offset * self.stride;
+
+            if self.stride == 1 {
+                V::new(&self.data[start..start + len])
+            } else {
+                // The last lane, `start + (len - 1) * stride`, is within `data`
+                unsafe { V::load_strided(self.data[start..].as_ptr(), self.stride) }
+            }
+        } else {
+            // Pad the tail to a full register, like the contiguous slices
+            let mut buffer = [0f32; MAX_SIZE];
+
+            for (i, element) in buffer[..len].iter_mut().enumerate() {
+                *element = self.data[(offset + i) * self.stride];
+            }
+
+            V::new(&buffer[..V::SIZE])
+        }
+    }
+}
+
+impl<'a> IntoNode for StridedSlice<'a> {
+    type Node = StridedSlice<'a>;
+
+    #[inline(always)]
+    fn into_node(self) -> StridedSlice<'a> {
+        self
+    }
+}
+
+impl Sealed for StridedSlice<'_> {}
+
+/// Implements each binary trait and its `_into` counterpart on `StridedSlice` with
+/// any right operand, and on slices and f32 broadcasts with a strided right operand
+///
+/// Every operand goes through `expr`, `|a, b| ...` combines the two nodes, so adding
+/// an operation is one more line in the invocation below.
+macro_rules! strided_binary {
+    ($(
+        $Trait:ident::$try_op:ident,
+        $Into:ident::$try_into_uninit:ident,
+        |$a:ident, $b:ident| $apply:expr;
+    )*) => {
+        $(strided_binary!(@op $Trait::$try_op, $Into::$try_into_uninit, |$a, $b| $apply);)*
+    };
+    (
+        @op $Trait:ident::$try_op:ident,
+        $Into:ident::$try_into_uninit:ident,
+        |$a:ident, $b:ident| $apply:expr
+    ) => {
+        impl<R: IntoNode> $Trait<R> for StridedSlice<'_> {
+            type Output = Vec<f32>;
+
+            #[inline(always)]
+            fn $try_op(self, rhs: R) -> Result<Self::Output, ArithmeticsError> {
+                let ($a, $b) = (expr(self), rhs);
+                $apply.try_eval()
+            }
+        }
+
+        impl<R: IntoNode> $Into<R> for StridedSlice<'_> {
+            #[inline(always)]
+            fn $try_into_uninit(
+                self,
+                rhs: R,
+                out: &mut [MaybeUninit<f32>],
+            ) -> Result<&mut [f32], ArithmeticsError> {
+                let ($a, $b) = (expr(self), rhs);
+                $apply.try_eval_into_uninit(out)
+            }
+        }
+
+        strided_binary!(@rhs &[f32], $Trait::$try_op, $Into::$try_into_uninit, |$a, $b| $apply);
+        strided_binary!(@rhs f32, $Trait::$try_op, $Into::$try_into_uninit, |$a, $b| $apply);
+    };
+    (
+        @rhs $Lhs:ty,
+        $Trait:ident::$try_op:ident,
+        $Into:ident::$try_into_uninit:ident,
+        |$a:ident, $b:ident| $apply:expr
+    ) => {
+        impl<'b> $Trait<StridedSlice<'b>> for $Lhs {
+            type Output = Vec<f32>;
+
+            #[inline(always)]
+            fn $try_op(self, rhs: StridedSlice<'b>) -> Result<Self::Output, ArithmeticsError> {
+                let ($a, $b) = (expr(self), rhs);
+                $apply.try_eval()
+            }
+        }
+
+        impl<'b> $Into<StridedSlice<'b>> for $Lhs {
+            #[inline(always)]
+            fn $try_into_uninit<'o>(
+                self,
+                rhs: StridedSlice<'b>,
+                out: &'o mut [MaybeUninit<f32>],
+            ) -> Result<&'o mut [f32], ArithmeticsError> {
+                let ($a, $b) = (expr(self), rhs);
+                $apply.try_eval_into_uninit(out)
+            }
+        }
+    };
+}
+
+strided_binary! {
+    SimdAdd::try_simd_add, SimdAddInto::try_simd_add_into_uninit, |a, b| a + b;
+    SimdSub::try_simd_sub, SimdSubInto::try_simd_sub_into_uninit, |a, b| a - b;
+    SimdMul::try_simd_mul, SimdMulInto::try_simd_mul_into_uninit, |a, b| a * b;
+    SimdDiv::try_simd_div, SimdDivInto::try_simd_div_into_uninit, |a, b| a / b;
+    SimdMin::try_simd_min, SimdMinInto::try_simd_min_into_uninit, |a, b| a.min(b);
+    SimdMax::try_simd_max, SimdMaxInto::try_simd_max_into_uninit, |a, b| a.max(b);
+}
+
+impl<B: IntoNode, C: IntoNode> SimdFma<B, C> for StridedSlice<'_> {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_fma(self, b: B, c: C) -> Result<Self::Output, ArithmeticsError> {
+        expr(self).fma(b, c).try_eval()
+    }
+}
+
+impl<B: IntoNode, C: IntoNode> SimdFmaInto<B, C> for StridedSlice<'_> {
+    #[inline(always)]
+    fn try_simd_fma_into_uninit(
+        self,
+        b: B,
+        c: C,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError> {
+        expr(self).fma(b, c).try_eval_into_uninit(out)
+    }
+}
+
+impl SimdClamp for StridedSlice<'_> {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn try_simd_clamp(self, lo: f32, hi: f32) -> Result<Self::Output, ArithmeticsError> {
+        expr(self).clamp(lo, hi).try_eval()
+    }
+}
+
+impl SimdClampInto for StridedSlice<'_> {
+    #[inline(always)]
+    fn try_simd_clamp_into_uninit(
+        self,
+        lo: f32,
+        hi: f32,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError> {
+        expr(self).clamp(lo, hi).try_eval_into_uninit(out)
+    }
+}
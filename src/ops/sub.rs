@@ -0,0 +1,30 @@
+use super::dispatch::{binary_op, BinaryOp};
+use crate::simd::utils::SimdVec;
+
+/// Element-wise subtraction, as an `Expr` node operation
+pub struct SubOp;
+
+impl BinaryOp for SubOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V, b: V) -> V {
+        a.simd_sub(b)
+    }
+}
+
+binary_op! {
+    /// Element-wise subtraction
+    pub trait SimdSub { try_simd_sub, simd_sub }
+
+    /// In-place element-wise subtraction, writes the results into `self`
+    pub trait SimdSubAssign { try_simd_sub_assign, simd_sub_assign }
+
+    /// Element-wise subtraction into a caller-provided buffer of the operands' size
+    pub trait SimdSubInto {
+        try_simd_sub_into_uninit,
+        try_simd_sub_into,
+        simd_sub_into_uninit,
+        simd_sub_into
+    }
+
+    impl for SubOp;
+}
@@ -0,0 +1,1349 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use super::accuracy::{libm, Accuracy, CorrectlyRoundedOp, FastOp, TieredOp};
+use super::dispatch::{
+    as_uninit_mut, assume_init_mut, collect_uninit, dispatch, unary_assign_slice, unary_slice_into,
+    Kernel, Sealed, UnaryOp, MAX_SIZE,
+};
+use crate::error::ArithmeticsError;
+use crate::simd::fast_math;
+use crate::simd::utils::SimdVec;
+
+/// An element-wise function of one operand, e.g. `UnaryFn::SQRT`
+///
+/// Passed to `simd_map` and its variants, the named methods of `SimdUnary`
+/// and `SimdUnaryAssign` are shorthands for them. The transcendental functions
+/// run with `Accuracy::active` unless pinned with `UnaryFn::with_accuracy`, their
+/// error bounds below are the ones of `Accuracy::Default`.
+#[derive(Copy, Clone)]
+pub struct UnaryFn {
+    name: &'static str,
+    accuracy: Option<Accuracy>,
+    // Indexed by `Accuracy`, thrice the same for the functions without tiers
+    kernels: [UnaryKernels; 3],
+}
+
+// The slice kernels of a function at one accuracy
+#[derive(Copy, Clone)]
+struct UnaryKernels {
+    into: fn(&[f32], &mut [MaybeUninit<f32>]),
+    assign: fn(&mut [f32]),
+}
+
+impl UnaryFn {
+    /// Absolute value
+    pub const ABS: UnaryFn = UnaryFn::of::<AbsOp>("abs");
+    /// Negation
+    pub const NEG: UnaryFn = UnaryFn::of::<NegOp>("neg");
+    /// Square root
+    pub const SQRT: UnaryFn = UnaryFn::of::<SqrtOp>("sqrt");
+    /// Reciprocal square root, the hardware estimate refined by Newton-Raphson (within 1.5 ulp)
+    pub const RSQRT: UnaryFn = UnaryFn::of::<RsqrtOp>("rsqrt");
+    /// Reciprocal square root, the raw hardware estimate (relative error below 2^-11)
+    pub const RSQRT_APPROX: UnaryFn = UnaryFn::of::<RsqrtApproxOp>("rsqrt_approx");
+    /// Reciprocal `1 / x`
+    pub const RECIP: UnaryFn = UnaryFn::of::<RecipOp>("recip");
+    /// Rounding toward negative infinity
+    pub const FLOOR: UnaryFn = UnaryFn::of::<FloorOp>("floor");
+    /// Rounding toward positive infinity
+    pub const CEIL: UnaryFn = UnaryFn::of::<CeilOp>("ceil");
+    /// Rounding to the nearest integer, ties to even
+    pub const ROUND: UnaryFn = UnaryFn::of::<RoundOp>("round");
+    /// Rounding toward zero
+    pub const TRUNC: UnaryFn = UnaryFn::of::<TruncOp>("trunc");
+    /// Sine (within 1 ulp)
+    pub const SIN: UnaryFn = UnaryFn::tiered::<SinOp>("sin");
+    /// Cosine (within 1 ulp)
+    pub const COS: UnaryFn = UnaryFn::tiered::<CosOp>("cos");
+    /// Tangent (within 1 ulp)
+    pub const TAN: UnaryFn = UnaryFn::tiered::<TanOp>("tan");
+    /// Natural exponential (within 1 ulp)
+    pub const EXP: UnaryFn = UnaryFn::tiered::<ExpOp>("exp");
+    /// Base 2 exponential (within 1 ulp)
+    pub const EXP2: UnaryFn = UnaryFn::tiered::<Exp2Op>("exp2");
+    /// `exp(x) - 1`, accurate near zero (within 1 ulp)
+    pub const EXPM1: UnaryFn = UnaryFn::tiered::<Expm1Op>("expm1");
+    /// Natural logarithm (within 1 ulp)
+    pub const LN: UnaryFn = UnaryFn::tiered::<LnOp>("ln");
+    /// Base 2 logarithm (within 1 ulp)
+    pub const LOG2: UnaryFn = UnaryFn::tiered::<Log2Op>("log2");
+    /// Base 10 logarithm (within 1 ulp)
+    pub const LOG10: UnaryFn = UnaryFn::tiered::<Log10Op>("log10");
+    /// `ln(1 + x)`, accurate near zero (within 1 ulp)
+    pub const LOG1P: UnaryFn = UnaryFn::tiered::<Log1pOp>("log1p");
+    /// Hyperbolic tangent (within 1 ulp)
+    pub const TANH: UnaryFn = UnaryFn::tiered::<TanhOp>("tanh");
+    /// Error function (within 1 ulp)
+    pub const ERF: UnaryFn = UnaryFn::tiered::<ErfOp>("erf");
+    /// Complementary error function `1 - erf(x)` (within 1 ulp)
+    pub const ERFC: UnaryFn = UnaryFn::tiered::<ErfcOp>("erfc");
+    /// Gamma function (within 1 ulp)
+    pub const TGAMMA: UnaryFn = UnaryFn::tiered::<TgammaOp>("tgamma");
+    /// Natural logarithm of `|gamma(x)|` (within 1 ulp)
+    pub const LGAMMA: UnaryFn = UnaryFn::tiered::<LgammaOp>("lgamma");
+    /// Arcsine (within 1 ulp)
+    pub const ASIN: UnaryFn = UnaryFn::tiered::<AsinOp>("asin");
+    /// Arccosine (within 1 ulp)
+    pub const ACOS: UnaryFn = UnaryFn::tiered::<AcosOp>("acos");
+    /// Arctangent (within 1 ulp)
+    pub const ATAN: UnaryFn = UnaryFn::tiered::<AtanOp>("atan");
+    /// Hyperbolic sine (within 1 ulp)
+    pub const SINH: UnaryFn = UnaryFn::tiered::<SinhOp>("sinh");
+    /// Hyperbolic cosine (within 1 ulp)
+    pub const COSH: UnaryFn = UnaryFn::tiered::<CoshOp>("cosh");
+    /// Inverse hyperbolic sine (within 1 ulp)
+    pub const ASINH: UnaryFn = UnaryFn::tiered::<AsinhOp>("asinh");
+    /// Inverse hyperbolic cosine (within 1 ulp)
+    pub const ACOSH: UnaryFn = UnaryFn::tiered::<AcoshOp>("acosh");
+    /// Inverse hyperbolic tangent (within 1 ulp)
+    pub const ATANH: UnaryFn = UnaryFn::tiered::<AtanhOp>("atanh");
+
+    pub(super) const fn of<O: UnaryOp>(name: &'static str) -> UnaryFn {
+        let kernels = UnaryKernels::of::<O>();
+
+        UnaryFn {
+            name,
+            accuracy: None,
+            kernels: [kernels; 3],
+        }
+    }
+
+    pub(super) const fn tiered<O: TieredOp>(name: &'static str) -> UnaryFn {
+        UnaryFn {
+            name,
+            accuracy: None,
+            kernels: [
+                UnaryKernels::of::<FastOp<O>>(),
+                UnaryKernels::of::<O>(),
+                UnaryKernels::of::<CorrectlyRoundedOp<O>>(),
+            ],
+        }
+    }
+
+    /// The function run with `accuracy`, whatever `Accuracy::active`
+    ///
+    /// Has no effect on the functions without accuracy tiers, e.g. `UnaryFn::SQRT`.
+    pub const fn with_accuracy(self, accuracy: Accuracy) -> UnaryFn {
+        UnaryFn {
+            accuracy: Some(accuracy),
+            ..self
+        }
+    }
+
+    /// Name of the function, e.g. `"sqrt"`
+    pub fn name(self) -> &'static str {
+        self.name
+    }
+
+    // The kernels of the pinned accuracy, or of the active one
+    #[inline(always)]
+    fn kernels(self) -> UnaryKernels {
+        let accuracy = self.accuracy.unwrap_or_else(Accuracy::active);
+
+        self.kernels[accuracy as usize]
+    }
+}
+
+impl UnaryKernels {
+    const fn of<O: UnaryOp>() -> UnaryKernels {
+        UnaryKernels {
+            into: unary_slice_into::<O>,
+            assign: unary_assign_slice::<O>,
+        }
+    }
+}
+
+impl fmt::Debug for UnaryFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.accuracy {
+            Some(accuracy) => write!(f, "UnaryFn({}, {})", self.name, accuracy.name()),
+            None => write!(f, "UnaryFn({})", self.name),
+        }
+    }
+}
+
+/// Element-wise functions of one operand
+pub trait SimdUnary: Sized {
+    type Output;
+
+    /// Applies `f` to every element
+    fn simd_map(self, f: UnaryFn) -> Self::Output;
+
+    #[inline(always)]
+    fn simd_abs(self) -> Self::Output {
+        self.simd_map(UnaryFn::ABS)
+    }
+
+    #[inline(always)]
+    fn simd_neg(self) -> Self::Output {
+        self.simd_map(UnaryFn::NEG)
+    }
+
+    #[inline(always)]
+    fn simd_sqrt(self) -> Self::Output {
+        self.simd_map(UnaryFn::SQRT)
+    }
+
+    /// Refined by Newton-Raphson, see `simd_rsqrt_approx` for the raw estimate
+    #[inline(always)]
+    fn simd_rsqrt(self) -> Self::Output {
+        self.simd_map(UnaryFn::RSQRT)
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt_approx(self) -> Self::Output {
+        self.simd_map(UnaryFn::RSQRT_APPROX)
+    }
+
+    #[inline(always)]
+    fn simd_recip(self) -> Self::Output {
+        self.simd_map(UnaryFn::RECIP)
+    }
+
+    #[inline(always)]
+    fn simd_floor(self) -> Self::Output {
+        self.simd_map(UnaryFn::FLOOR)
+    }
+
+    #[inline(always)]
+    fn simd_ceil(self) -> Self::Output {
+        self.simd_map(UnaryFn::CEIL)
+    }
+
+    /// Ties to even, unlike `f32::round`
+    #[inline(always)]
+    fn simd_round(self) -> Self::Output {
+        self.simd_map(UnaryFn::ROUND)
+    }
+
+    #[inline(always)]
+    fn simd_trunc(self) -> Self::Output {
+        self.simd_map(UnaryFn::TRUNC)
+    }
+
+    /// See `SimdVec::simd_sin` for the accuracy and the handling of large arguments
+    #[inline(always)]
+    fn simd_sin(self) -> Self::Output {
+        self.simd_map(UnaryFn::SIN)
+    }
+
+    #[inline(always)]
+    fn simd_cos(self) -> Self::Output {
+        self.simd_map(UnaryFn::COS)
+    }
+
+    #[inline(always)]
+    fn simd_tan(self) -> Self::Output {
+        self.simd_map(UnaryFn::TAN)
+    }
+
+    /// Sine and cosine in a single pass, sharing the range reduction
+    fn simd_sincos(self) -> (Self::Output, Self::Output);
+
+    /// See `SimdVec::simd_exp` for the handling of overflow and underflow
+    #[inline(always)]
+    fn simd_exp(self) -> Self::Output {
+        self.simd_map(UnaryFn::EXP)
+    }
+
+    #[inline(always)]
+    fn simd_exp2(self) -> Self::Output {
+        self.simd_map(UnaryFn::EXP2)
+    }
+
+    #[inline(always)]
+    fn simd_expm1(self) -> Self::Output {
+        self.simd_map(UnaryFn::EXPM1)
+    }
+
+    #[inline(always)]
+    fn simd_ln(self) -> Self::Output {
+        self.simd_map(UnaryFn::LN)
+    }
+
+    #[inline(always)]
+    fn simd_log2(self) -> Self::Output {
+        self.simd_map(UnaryFn::LOG2)
+    }
+
+    #[inline(always)]
+    fn simd_log10(self) -> Self::Output {
+        self.simd_map(UnaryFn::LOG10)
+    }
+
+    #[inline(always)]
+    fn simd_log1p(self) -> Self::Output {
+        self.simd_map(UnaryFn::LOG1P)
+    }
+
+    #[inline(always)]
+    fn simd_tanh(self) -> Self::Output {
+        self.simd_map(UnaryFn::TANH)
+    }
+
+    #[inline(always)]
+    fn simd_erf(self) -> Self::Output {
+        self.simd_map(UnaryFn::ERF)
+    }
+
+    #[inline(always)]
+    fn simd_erfc(self) -> Self::Output {
+        self.simd_map(UnaryFn::ERFC)
+    }
+
+    #[inline(always)]
+    fn simd_tgamma(self) -> Self::Output {
+        self.simd_map(UnaryFn::TGAMMA)
+    }
+
+    #[inline(always)]
+    fn simd_lgamma(self) -> Self::Output {
+        self.simd_map(UnaryFn::LGAMMA)
+    }
+
+    #[inline(always)]
+    fn simd_asin(self) -> Self::Output {
+        self.simd_map(UnaryFn::ASIN)
+    }
+
+    #[inline(always)]
+    fn simd_acos(self) -> Self::Output {
+        self.simd_map(UnaryFn::ACOS)
+    }
+
+    #[inline(always)]
+    fn simd_atan(self) -> Self::Output {
+        self.simd_map(UnaryFn::ATAN)
+    }
+
+    #[inline(always)]
+    fn simd_sinh(self) -> Self::Output {
+        self.simd_map(UnaryFn::SINH)
+    }
+
+    #[inline(always)]
+    fn simd_cosh(self) -> Self::Output {
+        self.simd_map(UnaryFn::COSH)
+    }
+
+    #[inline(always)]
+    fn simd_asinh(self) -> Self::Output {
+        self.simd_map(UnaryFn::ASINH)
+    }
+
+    #[inline(always)]
+    fn simd_acosh(self) -> Self::Output {
+        self.simd_map(UnaryFn::ACOSH)
+    }
+
+    #[inline(always)]
+    fn simd_atanh(self) -> Self::Output {
+        self.simd_map(UnaryFn::ATANH)
+    }
+}
+
+/// In-place element-wise functions of one operand, see `SimdUnary`
+pub trait SimdUnaryAssign {
+    /// Applies `f` to every element, writing the results into `self`
+    fn simd_map_assign(&mut self, f: UnaryFn);
+
+    #[inline(always)]
+    fn simd_abs_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ABS)
+    }
+
+    #[inline(always)]
+    fn simd_neg_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::NEG)
+    }
+
+    #[inline(always)]
+    fn simd_sqrt_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::SQRT)
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::RSQRT)
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt_approx_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::RSQRT_APPROX)
+    }
+
+    #[inline(always)]
+    fn simd_recip_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::RECIP)
+    }
+
+    #[inline(always)]
+    fn simd_floor_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::FLOOR)
+    }
+
+    #[inline(always)]
+    fn simd_ceil_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::CEIL)
+    }
+
+    #[inline(always)]
+    fn simd_round_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ROUND)
+    }
+
+    #[inline(always)]
+    fn simd_trunc_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::TRUNC)
+    }
+
+    #[inline(always)]
+    fn simd_sin_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::SIN)
+    }
+
+    #[inline(always)]
+    fn simd_cos_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::COS)
+    }
+
+    #[inline(always)]
+    fn simd_tan_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::TAN)
+    }
+
+    #[inline(always)]
+    fn simd_exp_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::EXP)
+    }
+
+    #[inline(always)]
+    fn simd_exp2_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::EXP2)
+    }
+
+    #[inline(always)]
+    fn simd_expm1_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::EXPM1)
+    }
+
+    #[inline(always)]
+    fn simd_ln_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::LN)
+    }
+
+    #[inline(always)]
+    fn simd_log2_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::LOG2)
+    }
+
+    #[inline(always)]
+    fn simd_log10_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::LOG10)
+    }
+
+    #[inline(always)]
+    fn simd_log1p_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::LOG1P)
+    }
+
+    #[inline(always)]
+    fn simd_tanh_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::TANH)
+    }
+
+    #[inline(always)]
+    fn simd_erf_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ERF)
+    }
+
+    #[inline(always)]
+    fn simd_erfc_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ERFC)
+    }
+
+    #[inline(always)]
+    fn simd_tgamma_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::TGAMMA)
+    }
+
+    #[inline(always)]
+    fn simd_lgamma_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::LGAMMA)
+    }
+
+    #[inline(always)]
+    fn simd_asin_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ASIN)
+    }
+
+    #[inline(always)]
+    fn simd_acos_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ACOS)
+    }
+
+    #[inline(always)]
+    fn simd_atan_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ATAN)
+    }
+
+    #[inline(always)]
+    fn simd_sinh_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::SINH)
+    }
+
+    #[inline(always)]
+    fn simd_cosh_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::COSH)
+    }
+
+    #[inline(always)]
+    fn simd_asinh_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ASINH)
+    }
+
+    #[inline(always)]
+    fn simd_acosh_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ACOSH)
+    }
+
+    #[inline(always)]
+    fn simd_atanh_assign(&mut self) {
+        self.simd_map_assign(UnaryFn::ATANH)
+    }
+}
+
+/// Element-wise functions of one operand into a caller-provided buffer of the input's size
+pub trait SimdUnaryInto: Sealed + Sized {
+    /// Writes `f` of every element into `out`, which may be uninitialized, and returns it initialized
+    ///
+    /// Fails with `LengthMismatch`, leaving `out` untouched, instead of panicking.
+    fn try_simd_map_into_uninit(
+        self,
+        f: UnaryFn,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError>;
+
+    /// Writes the results into `out`, see `try_simd_map_into_uninit`
+    #[inline(always)]
+    fn try_simd_map_into(self, f: UnaryFn, out: &mut [f32]) -> Result<(), ArithmeticsError> {
+        // Sealed, the implementations of this crate only write valid values
+        self.try_simd_map_into_uninit(f, unsafe { as_uninit_mut(out) })
+            .map(|_| ())
+    }
+
+    /// Writes the results into `out`, which may be uninitialized, and returns it initialized
+    #[inline(always)]
+    fn simd_map_into_uninit(self, f: UnaryFn, out: &mut [MaybeUninit<f32>]) -> &mut [f32] {
+        self.try_simd_map_into_uninit(f, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Writes the results into `out`
+    #[inline(always)]
+    fn simd_map_into(self, f: UnaryFn, out: &mut [f32]) {
+        self.try_simd_map_into(f, out)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Writes the sines into `sin` and the cosines into `cos`, which may be
+    /// uninitialized, and returns them initialized
+    ///
+    /// Fails with `LengthMismatch`, leaving both outputs untouched, instead of panicking.
+    fn try_simd_sincos_into_uninit<'o>(
+        self,
+        sin: &'o mut [MaybeUninit<f32>],
+        cos: &'o mut [MaybeUninit<f32>],
+    ) -> Result<(&'o mut [f32], &'o mut [f32]), ArithmeticsError>;
+
+    /// Writes the sines into `sin` and the cosines into `cos`, see `try_simd_sincos_into_uninit`
+    #[inline(always)]
+    fn try_simd_sincos_into(
+        self,
+        sin: &mut [f32],
+        cos: &mut [f32],
+    ) -> Result<(), ArithmeticsError> {
+        // Sealed, the implementations of this crate only write valid values
+        self.try_simd_sincos_into_uninit(unsafe { as_uninit_mut(sin) }, unsafe {
+            as_uninit_mut(cos)
+        })
+        .map(|_| ())
+    }
+
+    /// Writes the sines into `sin` and the cosines into `cos`, which may be
+    /// uninitialized, and returns them initialized
+    #[inline(always)]
+    fn simd_sincos_into_uninit<'o>(
+        self,
+        sin: &'o mut [MaybeUninit<f32>],
+        cos: &'o mut [MaybeUninit<f32>],
+    ) -> (&'o mut [f32], &'o mut [f32]) {
+        self.try_simd_sincos_into_uninit(sin, cos)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Writes the sines into `sin` and the cosines into `cos`
+    #[inline(always)]
+    fn simd_sincos_into(self, sin: &mut [f32], cos: &mut [f32]) {
+        self.try_simd_sincos_into(sin, cos)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+struct AbsOp;
+
+impl UnaryOp for AbsOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_abs()
+    }
+}
+
+struct NegOp;
+
+impl UnaryOp for NegOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_neg()
+    }
+}
+
+struct SqrtOp;
+
+impl UnaryOp for SqrtOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_sqrt()
+    }
+}
+
+struct RsqrtOp;
+
+impl UnaryOp for RsqrtOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_rsqrt()
+    }
+}
+
+struct RsqrtApproxOp;
+
+impl UnaryOp for RsqrtApproxOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_rsqrt_approx()
+    }
+}
+
+struct RecipOp;
+
+impl UnaryOp for RecipOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_recip()
+    }
+}
+
+struct FloorOp;
+
+impl UnaryOp for FloorOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_floor()
+    }
+}
+
+struct CeilOp;
+
+impl UnaryOp for CeilOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_ceil()
+    }
+}
+
+struct RoundOp;
+
+impl UnaryOp for RoundOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_round()
+    }
+}
+
+struct TruncOp;
+
+impl UnaryOp for TruncOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_trunc()
+    }
+}
+
+struct SinOp;
+
+impl UnaryOp for SinOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_sin()
+    }
+}
+
+impl TieredOp for SinOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::sin(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::sin(a)
+    }
+}
+
+struct CosOp;
+
+impl UnaryOp for CosOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_cos()
+    }
+}
+
+impl TieredOp for CosOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::cos(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::cos(a)
+    }
+}
+
+struct TanOp;
+
+impl UnaryOp for TanOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_tan()
+    }
+}
+
+impl TieredOp for TanOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::tan(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::tan(a)
+    }
+}
+
+struct ExpOp;
+
+impl UnaryOp for ExpOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_exp()
+    }
+}
+
+impl TieredOp for ExpOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::exp(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::exp(a)
+    }
+}
+
+struct Exp2Op;
+
+impl UnaryOp for Exp2Op {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_exp2()
+    }
+}
+
+impl TieredOp for Exp2Op {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::exp2(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::exp2(a)
+    }
+}
+
+struct Expm1Op;
+
+impl UnaryOp for Expm1Op {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_expm1()
+    }
+}
+
+impl TieredOp for Expm1Op {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::expm1(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::exp_m1(a)
+    }
+}
+
+struct LnOp;
+
+impl UnaryOp for LnOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_ln()
+    }
+}
+
+impl TieredOp for LnOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::ln(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::ln(a)
+    }
+}
+
+struct Log2Op;
+
+impl UnaryOp for Log2Op {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_log2()
+    }
+}
+
+impl TieredOp for Log2Op {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::log2(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::log2(a)
+    }
+}
+
+struct Log10Op;
+
+impl UnaryOp for Log10Op {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_log10()
+    }
+}
+
+impl TieredOp for Log10Op {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::log10(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::log10(a)
+    }
+}
+
+struct Log1pOp;
+
+impl UnaryOp for Log1pOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_log1p()
+    }
+}
+
+impl TieredOp for Log1pOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::log1p(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::ln_1p(a)
+    }
+}
+
+struct TanhOp;
+
+impl UnaryOp for TanhOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_tanh()
+    }
+}
+
+impl TieredOp for TanhOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::tanh(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::tanh(a)
+    }
+}
+
+struct ErfOp;
+
+impl UnaryOp for ErfOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_erf()
+    }
+}
+
+impl TieredOp for ErfOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::erf(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        libm::erf(a)
+    }
+}
+
+struct ErfcOp;
+
+impl UnaryOp for ErfcOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_erfc()
+    }
+}
+
+impl TieredOp for ErfcOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::erfc(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        libm::erfc(a)
+    }
+}
+
+struct TgammaOp;
+
+impl UnaryOp for TgammaOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_tgamma()
+    }
+}
+
+impl TieredOp for TgammaOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::tgamma(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        libm::tgamma(a)
+    }
+}
+
+struct LgammaOp;
+
+impl UnaryOp for LgammaOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_lgamma()
+    }
+}
+
+impl TieredOp for LgammaOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::lgamma(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        libm::lgamma(a)
+    }
+}
+
+struct AsinOp;
+
+impl UnaryOp for AsinOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_asin()
+    }
+}
+
+impl TieredOp for AsinOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::asin(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::asin(a)
+    }
+}
+
+struct AcosOp;
+
+impl UnaryOp for AcosOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_acos()
+    }
+}
+
+impl TieredOp for AcosOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::acos(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::acos(a)
+    }
+}
+
+struct AtanOp;
+
+impl UnaryOp for AtanOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_atan()
+    }
+}
+
+impl TieredOp for AtanOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::atan(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::atan(a)
+    }
+}
+
+struct SinhOp;
+
+impl UnaryOp for SinhOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_sinh()
+    }
+}
+
+impl TieredOp for SinhOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::sinh(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::sinh(a)
+    }
+}
+
+struct CoshOp;
+
+impl UnaryOp for CoshOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_cosh()
+    }
+}
+
+impl TieredOp for CoshOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::cosh(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::cosh(a)
+    }
+}
+
+struct AsinhOp;
+
+impl UnaryOp for AsinhOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_asinh()
+    }
+}
+
+impl TieredOp for AsinhOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::asinh(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::asinh(a)
+    }
+}
+
+struct AcoshOp;
+
+impl UnaryOp for AcoshOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_acosh()
+    }
+}
+
+impl TieredOp for AcoshOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::acosh(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::acosh(a)
+    }
+}
+
+struct AtanhOp;
+
+impl UnaryOp for AtanhOp {
+    #[inline(always)]
+    fn apply<V: SimdVec<f32>>(a: V) -> V {
+        a.simd_atanh()
+    }
+}
+
+impl TieredOp for AtanhOp {
+    #[inline(always)]
+    fn apply_fast<V: SimdVec<f32>>(a: V) -> V {
+        fast_math::atanh(a)
+    }
+
+    #[inline(always)]
+    fn apply_exact(a: f64) -> f64 {
+        f64::atanh(a)
+    }
+}
+
+// `dispatch` splits a single output, the kernel fills the sines handed to it and
+// the cosines of the same range through `cos`
+struct SinCosKernel<'a> {
+    a: &'a [f32],
+    cos: *mut MaybeUninit<f32>,
+    accuracy: Accuracy,
+}
+
+// Chunks cover disjoint ranges, so no two threads write the same cosine
+unsafe impl Sync for SinCosKernel<'_> {}
+
+impl Kernel for SinCosKernel<'_> {
+    type Out = MaybeUninit<f32>;
+
+    #[inline(always)]
+    fn run<V: SimdVec<f32>>(&self, offset: usize, out: &mut [Self::Out]) {
+        let end = offset + out.len();
+        let cos = unsafe { std::slice::from_raw_parts_mut(self.cos.add(offset), out.len()) };
+
+        let a = &self.a[offset..end];
+
+        match self.accuracy {
+            Accuracy::Fast => sincos::<V>(
+                a,
+                out,
+                cos,
+                #[inline(always)]
+                |a| fast_math::sincos(a),
+            ),
+            Accuracy::Default => sincos::<V>(
+                a,
+                out,
+                cos,
+                #[inline(always)]
+                |a| a.simd_sincos(),
+            ),
+            Accuracy::CorrectlyRounded => sincos::<V>(a, out, cos, sincos_exact),
+        }
+    }
+}
+
+// Sine and cosine of every lane in f64, see `CorrectlyRoundedOp`
+#[inline(always)]
+fn sincos_exact<V: SimdVec<f32>>(a: V) -> (V, V) {
+    let mut sin = [0f32; MAX_SIZE];
+    let mut cos = [0f32; MAX_SIZE];
+    unsafe { a.store_at(sin.as_mut_ptr()) }
+
+    for (s, c) in sin[..V::SIZE].iter_mut().zip(&mut cos) {
+        let (y, x) = (*s as f64).sin_cos();
+        (*s, *c) = (y as f32, x as f32);
+    }
+
+    (V::new(&sin[..V::SIZE]), V::new(&cos[..V::SIZE]))
+}
+
+// Writes sin and cos of `a` register by register, padding the tail like `unary`
+#[inline(always)]
+fn sincos<V: SimdVec<f32>>(
+    a: &[f32],
+    sin: &mut [MaybeUninit<f32>],
+    cos: &mut [MaybeUninit<f32>],
+    op: impl Fn(V) -> (V, V),
+) {
+    let mut a_chunks = a.chunks_exact(V::SIZE);
+    let mut sin_chunks = sin.chunks_exact_mut(V::SIZE);
+    let mut cos_chunks = cos.chunks_exact_mut(V::SIZE);
+
+    for ((s_chunk, c_chunk), a_chunk) in sin_chunks
+        .by_ref()
+        .zip(cos_chunks.by_ref())
+        .zip(a_chunks.by_ref())
+    {
+        let (s, c) = op(V::new(a_chunk));
+
+        unsafe {
+            s.store_at(s_chunk.as_mut_ptr().cast());
+            c.store_at(c_chunk.as_mut_ptr().cast());
+        }
+    }
+
+    let a_tail = a_chunks.remainder();
+    let sin_tail = sin_chunks.into_remainder();
+    let cos_tail = cos_chunks.into_remainder();
+
+    if !sin_tail.is_empty() {
+        let mut s_buffer = [0f32; MAX_SIZE];
+        let mut c_buffer = [0f32; MAX_SIZE];
+        s_buffer[..a_tail.len()].copy_from_slice(a_tail);
+
+        let (s, c) = op(V::new(&s_buffer[..V::SIZE]));
+        unsafe {
+            s.store_at(s_buffer.as_mut_ptr());
+            c.store_at(c_buffer.as_mut_ptr());
+        }
+
+        for (o, s) in sin_tail.iter_mut().zip(s_buffer) {
+            o.write(s);
+        }
+        for (o, c) in cos_tail.iter_mut().zip(c_buffer) {
+            o.write(c);
+        }
+    }
+}
+
+// Same-length `a`, `sin` and `cos` (Processes chunks in parallel)
+#[inline(always)]
+fn sincos_slices_into(a: &[f32], sin: &mut [MaybeUninit<f32>], cos: &mut [MaybeUninit<f32>]) {
+    let kernel = SinCosKernel {
+        a,
+        cos: cos.as_mut_ptr(),
+        accuracy: Accuracy::active(),
+    };
+    dispatch(&kernel, sin);
+}
+
+impl SimdUnary for &[f32] {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn simd_map(self, f: UnaryFn) -> Self::Output {
+        collect_uninit(self.len(), |c| (f.kernels().into)(self, c))
+    }
+
+    #[inline(always)]
+    fn simd_sincos(self) -> (Self::Output, Self::Output) {
+        let mut cos = Vec::with_capacity(self.len());
+
+        let sin = collect_uninit(self.len(), |s| {
+            sincos_slices_into(self, s, &mut cos.spare_capacity_mut()[..self.len()])
+        });
+        unsafe { cos.set_len(self.len()) }
+
+        (sin, cos)
+    }
+}
+
+impl SimdUnary for Vec<f32> {
+    type Output = Vec<f32>;
+
+    #[inline(always)]
+    fn simd_map(mut self, f: UnaryFn) -> Self::Output {
+        self.simd_map_assign(f);
+        self
+    }
+
+    #[inline(always)]
+    fn simd_sincos(self) -> (Self::Output, Self::Output) {
+        self.as_slice().simd_sincos()
+    }
+}
+
+impl SimdUnaryAssign for [f32] {
+    #[inline(always)]
+    fn simd_map_assign(&mut self, f: UnaryFn) {
+        (f.kernels().assign)(self)
+    }
+}
+
+impl SimdUnaryInto for &[f32] {
+    #[inline(always)]
+    fn try_simd_map_into_uninit(
+        self,
+        f: UnaryFn,
+        out: &mut [MaybeUninit<f32>],
+    ) -> Result<&mut [f32], ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), out.len())?;
+        (f.kernels().into)(self, out);
+        Ok(unsafe { assume_init_mut(out) })
+    }
+
+    #[inline(always)]
+    fn try_simd_sincos_into_uninit<'o>(
+        self,
+        sin: &'o mut [MaybeUninit<f32>],
+        cos: &'o mut [MaybeUninit<f32>],
+    ) -> Result<(&'o mut [f32], &'o mut [f32]), ArithmeticsError> {
+        ArithmeticsError::check_len(self.len(), sin.len())?;
+        ArithmeticsError::check_len(self.len(), cos.len())?;
+        sincos_slices_into(self, sin, cos);
+        Ok(unsafe { (assume_init_mut(sin), assume_init_mut(cos)) })
+    }
+}
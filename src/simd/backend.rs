@@ -0,0 +1,212 @@
+use std::env::VarError;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+use super::features::CpuFeatures;
+use crate::error::ArithmeticsError;
+
+/// Environment variable forcing the backend picked by `Backend::active`
+///
+/// Accepts the names returned by `Backend::name` (case-insensitive).
+pub const BACKEND_ENV: &str = "ARITHMETICS_BACKEND";
+
+/// SIMD instruction sets the slice operations can be dispatched to
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// Portable lane-wise loops, through `ScalarVec`
+    ///
+    /// Picked when the CPU has no supported SIMD extension, and the only
+    /// backend compiled on targets the build script reports as `baseline`.
+    Scalar,
+    /// 128-bit SSE4.1 registers, through `F32x4`
+    Sse41,
+    /// 256-bit AVX2 registers with FMA, through `F32x8`
+    Avx2,
+    /// 512-bit AVX-512F registers, through `F32x16`
+    Avx512,
+    /// 128-bit NEON registers, through `F32x4`
+    Neon,
+}
+
+// Sentinel stored in `ACTIVE` until the first detection has run
+const UNDETECTED: u8 = u8::MAX;
+
+// Backend picked by the first call to `Backend::active`, or by `Backend::set_active`
+static ACTIVE: AtomicU8 = AtomicU8::new(UNDETECTED);
+
+// Why the first detection ignored `ARITHMETICS_BACKEND`, see `Backend::env_error`
+static ENV_ERROR: OnceLock<ArithmeticsError> = OnceLock::new();
+
+impl Backend {
+    /// Every backend, from the widest to the narrowest
+    pub const ALL: [Backend; 5] = [
+        Backend::Avx512,
+        Backend::Avx2,
+        Backend::Sse41,
+        Backend::Neon,
+        Backend::Scalar,
+    ];
+
+    /// Backend used by the slice operations.
+    ///
+    /// On the first call, the backend named by `ARITHMETICS_BACKEND` is used if
+    /// the CPU supports it, otherwise CPU features are detected and the reason
+    /// the variable was ignored is kept in `Backend::env_error`, nothing is
+    /// printed. The result is cached for the lifetime of the process, see
+    /// `Backend::set_active` to change it afterwards.
+    ///
+    /// Applications honouring the variable should check `Backend::env_error`
+    /// after the first call, or `Backend::from_env` beforehand, to warn about it.
+    #[inline(always)]
+    pub fn active() -> Backend {
+        match ACTIVE.load(Ordering::Relaxed) {
+            UNDETECTED => {
+                let backend = match Self::from_env() {
+                    Ok(backend) => backend.unwrap_or_else(Self::detect),
+                    Err(err) => {
+                        let _ = ENV_ERROR.set(err);
+                        Self::detect()
+                    }
+                };
+
+                // A concurrent `set_active` or detection may have won the race
+                match ACTIVE.compare_exchange(
+                    UNDETECTED,
+                    backend as u8,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => backend,
+                    Err(value) => Self::from_u8(value),
+                }
+            }
+            value => Self::from_u8(value),
+        }
+    }
+
+    /// Why the first call to `Backend::active` ignored `ARITHMETICS_BACKEND`,
+    /// `None` if the variable was unset, valid, or never read
+    pub fn env_error() -> Option<ArithmeticsError> {
+        ENV_ERROR.get().copied()
+    }
+
+    /// Forces the backend used by the slice operations from now on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend is not compiled for this target or not supported
+    /// by the CPU, running its kernels would trigger illegal instructions.
+    pub fn set_active(backend: Backend) {
+        Self::try_set_active(backend).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Forces the backend used by the slice operations from now on, see `Backend::set_active`
+    ///
+    /// Fails with `UnsupportedBackend`, leaving the active backend unchanged,
+    /// instead of panicking.
+    pub fn try_set_active(backend: Backend) -> Result<(), ArithmeticsError> {
+        if !backend.is_supported() {
+            return Err(ArithmeticsError::UnsupportedBackend(backend));
+        }
+
+        ACTIVE.store(backend as u8, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    /// Detects the widest backend supported by the CPU running this process
+    pub fn detect() -> Backend {
+        Self::ALL
+            .into_iter()
+            .find(|backend| backend.is_supported())
+            .unwrap_or(Backend::Scalar)
+    }
+
+    /// Whether the backend is compiled for this target and supported by the CPU
+    pub fn is_supported(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx512 => CpuFeatures::get().avx512f,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => {
+                let features = CpuFeatures::get();
+                features.avx2 && features.fma
+            }
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse41 => CpuFeatures::get().sse41,
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => CpuFeatures::get().neon,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    /// Name of the backend, as accepted by `ARITHMETICS_BACKEND`
+    pub fn name(self) -> &'static str {
+        match self {
+            Backend::Scalar => "scalar",
+            Backend::Sse41 => "sse4.1",
+            Backend::Avx2 => "avx2",
+            Backend::Avx512 => "avx512",
+            Backend::Neon => "neon",
+        }
+    }
+
+    /// Number of f32 lanes processed per register
+    pub fn lanes(self) -> usize {
+        match self {
+            Backend::Scalar => super::scalar::SIZE,
+            Backend::Sse41 | Backend::Neon => 4,
+            Backend::Avx2 => 8,
+            Backend::Avx512 => 16,
+        }
+    }
+
+    /// Parses a backend name, see `Backend::name`
+    pub fn from_name(name: &str) -> Option<Backend> {
+        let name = name.trim().to_lowercase();
+
+        match name.as_str() {
+            "scalar" | "baseline" => Some(Backend::Scalar),
+            "sse4.1" | "sse41" | "sse" => Some(Backend::Sse41),
+            "avx2" => Some(Backend::Avx2),
+            "avx512" | "avx512f" => Some(Backend::Avx512),
+            "neon" => Some(Backend::Neon),
+            _ => None,
+        }
+    }
+
+    /// Backend requested through `ARITHMETICS_BACKEND`, `None` when unset
+    ///
+    /// Fails with `InvalidEnv` if the value is not a backend name, or with
+    /// `UnsupportedBackend` if the backend cannot run on this target or CPU.
+    pub fn from_env() -> Result<Option<Backend>, ArithmeticsError> {
+        let value = match std::env::var(BACKEND_ENV) {
+            Ok(value) => value,
+            Err(VarError::NotPresent) => return Ok(None),
+            Err(VarError::NotUnicode(_)) => return Err(ArithmeticsError::InvalidEnv(BACKEND_ENV)),
+        };
+
+        match Self::from_name(&value) {
+            Some(backend) if backend.is_supported() => Ok(Some(backend)),
+            Some(backend) => Err(ArithmeticsError::UnsupportedBackend(backend)),
+            None => Err(ArithmeticsError::InvalidEnv(BACKEND_ENV)),
+        }
+    }
+
+    fn from_u8(value: u8) -> Backend {
+        match value {
+            0 => Backend::Scalar,
+            1 => Backend::Sse41,
+            2 => Backend::Avx2,
+            3 => Backend::Avx512,
+            4 => Backend::Neon,
+            _ => {
+                let msg = format!("Invalid backend {}", value);
+                panic!("{}", msg);
+            }
+        }
+    }
+}
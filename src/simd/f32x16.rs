@@ -0,0 +1,816 @@
+use std::arch::x86_64::*;
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::math;
+use super::utils::SimdVec;
+
+pub const SIZE: usize = 16;
+
+/// A SIMD vector of 16 32-bit floating point values, backed by AVX-512F
+#[derive(Copy, Clone, Debug)]
+pub struct F32x16 {
+    size: usize,
+
+    elements: __m512,
+}
+
+impl SimdVec<f32> for F32x16 {
+    const SIZE: usize = SIZE;
+
+    #[inline(always)]
+    fn new(slice: &[f32]) -> Self {
+        if slice.len() == SIZE {
+            unsafe { Self::load(slice.as_ptr(), slice.len()) }
+        } else if slice.len() < SIZE {
+            unsafe { Self::load_partial(slice.as_ptr(), slice.len()) }
+        } else {
+            let msg = format!("F32x16 size must not exceed {}", SIZE);
+            panic!("{}", msg);
+        }
+    }
+
+    #[inline(always)]
+    fn splat(value: f32) -> Self {
+        Self {
+            elements: unsafe { _mm512_set1_ps(value) },
+            size: SIZE,
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn load(ptr: *const f32, size: usize) -> Self {
+        assert!(size == SIZE, "Size must be == {}", SIZE);
+
+        Self {
+            elements: unsafe { _mm512_loadu_ps(ptr) },
+            size: SIZE,
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn load_partial(ptr: *const f32, size: usize) -> Self {
+        assert!(size < SIZE, "Size must be < {}", SIZE);
+
+        let mask: __mmask16 = (1 << size) - 1;
+
+        Self {
+            elements: unsafe { _mm512_maskz_loadu_ps(mask, ptr) },
+            size,
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn load_strided(ptr: *const f32, stride: usize) -> Self {
+        if stride * (SIZE - 1) <= i32::MAX as usize {
+            let index = _mm512_mullo_epi32(
+                _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+                _mm512_set1_epi32(stride as i32),
+            );
+
+            Self {
+                elements: unsafe { _mm512_i32gather_ps::<4>(index, ptr) },
+                size: SIZE,
+            }
+        } else {
+            // Offsets overflow the 32-bit gather indices
+            let mut elements = [0f32; SIZE];
+
+            for (i, element) in elements.iter_mut().enumerate() {
+                *element = *ptr.add(i * stride);
+            }
+
+            Self::load(elements.as_ptr(), SIZE)
+        }
+    }
+
+    #[inline(always)]
+    fn to_vec(self) -> Vec<f32> {
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
+
+        if self.size == SIZE {
+            self.store()
+        } else {
+            self.store_partial()
+        }
+    }
+
+    #[inline(always)]
+    fn store(&self) -> Vec<f32> {
+        assert!(self.size == SIZE, "Size must be == {}", SIZE);
+
+        let mut vec = vec![0f32; SIZE];
+
+        unsafe {
+            _mm512_storeu_ps(vec.as_mut_ptr(), self.elements);
+        }
+
+        vec
+    }
+
+    #[inline(always)]
+    fn store_partial(&self) -> Vec<f32> {
+        assert!(self.size < SIZE, "Size must be < {}", SIZE);
+
+        let mask: __mmask16 = (1 << self.size) - 1;
+
+        let mut vec = vec![0f32; self.size];
+
+        unsafe {
+            _mm512_mask_storeu_ps(vec.as_mut_ptr(), mask, self.elements);
+        }
+
+        vec
+    }
+
+    #[inline(always)]
+    unsafe fn store_at(&self, ptr: *mut f32) {
+        assert!(self.size == SIZE, "Size must be == {}", SIZE);
+
+        _mm512_storeu_ps(ptr, self.elements);
+    }
+
+    #[inline(always)]
+    unsafe fn store_at_partial(&self, ptr: *mut f32) {
+        assert!(self.size < SIZE, "Size must be < {}", SIZE);
+
+        let mask: __mmask16 = (1 << self.size) - 1;
+
+        _mm512_mask_storeu_ps(ptr, mask, self.elements);
+    }
+
+    #[inline(always)]
+    fn simd_mask_add(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            let mask: __mmask16 = (1 << self.size) - 1;
+
+            // Add a+b
+            let elements = _mm512_maskz_add_ps(mask, self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_add(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // Add a+b
+            let elements = _mm512_add_ps(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sub(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // Sub a-b
+            let elements = _mm512_sub_ps(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mul(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // Mul a*b
+            let elements = _mm512_mul_ps(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_div(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // Div a/b
+            let elements = _mm512_div_ps(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_min(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // vminps returns rhs when either lane is NaN, take self where rhs is NaN
+            let rhs_nan = _mm512_cmp_ps_mask(rhs.elements, rhs.elements, _CMP_UNORD_Q);
+            let elements = _mm512_mask_blend_ps(
+                rhs_nan,
+                _mm512_min_ps(self.elements, rhs.elements),
+                self.elements,
+            );
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_max(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // vmaxps returns rhs when either lane is NaN, take self where rhs is NaN
+            let rhs_nan = _mm512_cmp_ps_mask(rhs.elements, rhs.elements, _CMP_UNORD_Q);
+            let elements = _mm512_mask_blend_ps(
+                rhs_nan,
+                _mm512_max_ps(self.elements, rhs.elements),
+                self.elements,
+            );
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_clamp(&self, lo: Self, hi: Self) -> Self {
+        assert!(
+            self.size == lo.size && self.size == hi.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // With self as second operand, vminps/vmaxps propagate its NaN lanes
+            let elements = _mm512_max_ps(lo.elements, _mm512_min_ps(hi.elements, self.elements));
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_fma(&self, b: Self, c: Self) -> Self {
+        assert!(
+            self.size == b.size && self.size == c.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // a*b+c
+            let elements = _mm512_fmadd_ps(self.elements, b.elements, c.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mul_error(&self, b: Self) -> Self {
+        assert!(
+            self.size == b.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            let p = _mm512_mul_ps(self.elements, b.elements);
+            let elements = _mm512_fmsub_ps(self.elements, b.elements, p);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_abs(&self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_abs_ps(self.elements),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_neg(&self) -> Self {
+        unsafe {
+            // xorps needs AVX512DQ, flip the sign bit through the integer unit
+            Self {
+                elements: _mm512_castsi512_ps(_mm512_xor_si512(
+                    _mm512_castps_si512(self.elements),
+                    _mm512_set1_epi32(i32::MIN),
+                )),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sqrt(&self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_sqrt_ps(self.elements),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt(&self) -> Self {
+        unsafe {
+            // One Newton-Raphson step y + 0.5 * y * (1 - x * y * y) on top of the 14 bit
+            // estimate, its residual computed exactly with FMA. The estimate is kept for 0
+            // and inf where the step would produce NaN
+            let x = self.elements;
+            let y = _mm512_rsqrt14_ps(x);
+
+            let xy = _mm512_mul_ps(x, y);
+            let xy_err = _mm512_fmsub_ps(x, y, xy);
+            let residual = _mm512_fnmadd_ps(xy, y, _mm512_set1_ps(1.0));
+            let residual = _mm512_fnmadd_ps(xy_err, y, residual);
+            let half = _mm512_mul_ps(_mm512_set1_ps(0.5), y);
+            let refined = _mm512_fmadd_ps(half, residual, y);
+
+            let exact = _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(x, _mm512_setzero_ps())
+                | _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(x, _mm512_set1_ps(f32::INFINITY));
+
+            Self {
+                elements: _mm512_mask_blend_ps(exact, refined, y),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt_approx(&self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_rsqrt14_ps(self.elements),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_recip(&self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_div_ps(_mm512_set1_ps(1.0), self.elements),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_floor(&self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_roundscale_ps::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(
+                    self.elements,
+                ),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_ceil(&self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_roundscale_ps::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(
+                    self.elements,
+                ),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_round(&self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_roundscale_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+                    self.elements,
+                ),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_trunc(&self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_roundscale_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(
+                    self.elements,
+                ),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_eq(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            Self {
+                elements: from_mask(_mm512_cmp_ps_mask::<_CMP_EQ_OQ>(
+                    self.elements,
+                    rhs.elements,
+                )),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_lt(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            Self {
+                elements: from_mask(_mm512_cmp_ps_mask::<_CMP_LT_OQ>(
+                    self.elements,
+                    rhs.elements,
+                )),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_le(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            Self {
+                elements: from_mask(_mm512_cmp_ps_mask::<_CMP_LE_OQ>(
+                    self.elements,
+                    rhs.elements,
+                )),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_select(&self, a: Self, b: Self) -> Self {
+        unsafe {
+            Self {
+                elements: _mm512_mask_blend_ps(to_mask(self.elements), b.elements, a.elements),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_and(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            Self {
+                elements: _mm512_castsi512_ps(_mm512_and_si512(
+                    _mm512_castps_si512(self.elements),
+                    _mm512_castps_si512(rhs.elements),
+                )),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_xor(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            Self {
+                elements: _mm512_castsi512_ps(_mm512_xor_si512(
+                    _mm512_castps_si512(self.elements),
+                    _mm512_castps_si512(rhs.elements),
+                )),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_any(&self) -> bool {
+        unsafe { to_mask(self.elements) != 0 }
+    }
+
+    #[inline(always)]
+    fn simd_exp2i(&self) -> Self {
+        unsafe {
+            let n = _mm512_add_epi32(_mm512_cvtps_epi32(self.elements), _mm512_set1_epi32(127));
+
+            Self {
+                elements: _mm512_castsi512_ps(_mm512_slli_epi32::<23>(n)),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_exponent(&self) -> Self {
+        unsafe {
+            let bits = _mm512_srli_epi32::<23>(_mm512_castps_si512(self.elements));
+            let n = _mm512_sub_epi32(
+                _mm512_and_si512(bits, _mm512_set1_epi32(0xff)),
+                _mm512_set1_epi32(127),
+            );
+
+            Self {
+                elements: _mm512_cvtepi32_ps(n),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mantissa(&self) -> Self {
+        unsafe {
+            let bits = _mm512_and_si512(
+                _mm512_castps_si512(self.elements),
+                _mm512_set1_epi32(0x007f_ffff),
+            );
+
+            Self {
+                elements: _mm512_castsi512_ps(_mm512_or_si512(
+                    bits,
+                    _mm512_set1_epi32(0x3f80_0000),
+                )),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sin(&self) -> Self {
+        math::sin(*self)
+    }
+
+    #[inline(always)]
+    fn simd_cos(&self) -> Self {
+        math::cos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tan(&self) -> Self {
+        math::tan(*self)
+    }
+
+    #[inline(always)]
+    fn simd_sincos(&self) -> (Self, Self) {
+        math::sincos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_exp(&self) -> Self {
+        math::exp(*self)
+    }
+
+    #[inline(always)]
+    fn simd_exp2(&self) -> Self {
+        math::exp2(*self)
+    }
+
+    #[inline(always)]
+    fn simd_expm1(&self) -> Self {
+        math::expm1(*self)
+    }
+
+    #[inline(always)]
+    fn simd_ln(&self) -> Self {
+        math::ln(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log2(&self) -> Self {
+        math::log2(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log10(&self) -> Self {
+        math::log10(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log1p(&self) -> Self {
+        math::log1p(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tanh(&self) -> Self {
+        math::tanh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_erf(&self) -> Self {
+        math::erf(*self)
+    }
+
+    #[inline(always)]
+    fn simd_erfc(&self) -> Self {
+        math::erfc(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tgamma(&self) -> Self {
+        math::tgamma(*self)
+    }
+
+    #[inline(always)]
+    fn simd_lgamma(&self) -> Self {
+        math::lgamma(*self)
+    }
+
+    #[inline(always)]
+    fn simd_asin(&self) -> Self {
+        math::asin(*self)
+    }
+
+    #[inline(always)]
+    fn simd_acos(&self) -> Self {
+        math::acos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atan(&self) -> Self {
+        math::atan(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atan2(&self, x: Self) -> Self {
+        math::atan2(*self, x)
+    }
+
+    #[inline(always)]
+    fn simd_sinh(&self) -> Self {
+        math::sinh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_cosh(&self) -> Self {
+        math::cosh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_asinh(&self) -> Self {
+        math::asinh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_acosh(&self) -> Self {
+        math::acosh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atanh(&self) -> Self {
+        math::atanh(*self)
+    }
+}
+
+// Vector mask with all bits set in the lanes of `mask`, AVX512DQ has movm for this
+#[inline(always)]
+unsafe fn from_mask(mask: __mmask16) -> __m512 {
+    _mm512_castsi512_ps(_mm512_maskz_set1_epi32(mask, -1))
+}
+
+// Lanes of a vector mask with any bit set
+#[inline(always)]
+unsafe fn to_mask(mask: __m512) -> __mmask16 {
+    let bits = _mm512_castps_si512(mask);
+    _mm512_test_epi32_mask(bits, bits)
+}
+
+/// Implementation of Add trait for F32x16 using custom SIMD types
+impl Add for F32x16 {
+    type Output = F32x16;
+
+    fn add(self, rhs: F32x16) -> Self::Output {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            SIZE
+        );
+
+        if self.size == SIZE {
+            self.simd_add(rhs)
+        } else if self.size < SIZE {
+            self.simd_mask_add(rhs)
+        } else {
+            let msg = format!("F32x16 size must not exceed {}", SIZE);
+            panic!("{}", msg);
+        }
+    }
+}
+
+/// Implementation of Sub trait for F32x16 using custom SIMD types
+impl Sub for F32x16 {
+    type Output = F32x16;
+
+    #[inline(always)]
+    fn sub(self, rhs: F32x16) -> Self::Output {
+        self.simd_sub(rhs)
+    }
+}
+
+/// Implementation of Mul trait for F32x16 using custom SIMD types
+impl Mul for F32x16 {
+    type Output = F32x16;
+
+    #[inline(always)]
+    fn mul(self, rhs: F32x16) -> Self::Output {
+        self.simd_mul(rhs)
+    }
+}
+
+/// Implementation of Div trait for F32x16 using custom SIMD types
+impl Div for F32x16 {
+    type Output = F32x16;
+
+    #[inline(always)]
+    fn div(self, rhs: F32x16) -> Self::Output {
+        self.simd_div(rhs)
+    }
+}
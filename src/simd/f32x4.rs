@@ -7,8 +7,9 @@ use std::arch::aarch64::*;
 #[cfg(target_arch = "arm")]
 use std::arch::arm::*;
 
-use std::ops::Add;
+use std::ops::{Add, Div, Mul, Sub};
 
+use super::math;
 use super::utils::SimdVec;
 
 pub const SIZE: usize = 4;
@@ -33,6 +34,8 @@ pub struct F32x4 {
 }
 
 impl SimdVec<f32> for F32x4 {
+    const SIZE: usize = SIZE;
+
     #[inline(always)]
     fn new(slice: &[f32]) -> Self {
         match slice.len().cmp(&SIZE) {
@@ -62,8 +65,7 @@ impl SimdVec<f32> for F32x4 {
 
     #[inline(always)]
     unsafe fn load(ptr: *const f32, size: usize) -> Self {
-        let msg = format!("Size must be == {}", SIZE);
-        assert!(size == SIZE, "{}", msg);
+        assert!(size == SIZE, "Size must be == {}", SIZE);
 
         #[cfg(target_arch = "x86_64")]
         let loaded = Self {
@@ -82,8 +84,7 @@ impl SimdVec<f32> for F32x4 {
 
     #[inline(always)]
     unsafe fn load_partial(ptr: *const f32, size: usize) -> Self {
-        let msg = format!("Size must be < {}", SIZE);
-        assert!(size < SIZE, "{}", msg);
+        assert!(size < SIZE, "Size must be < {}", SIZE);
 
         #[cfg(target_arch = "x86_64")]
         let elements = match size {
@@ -121,10 +122,22 @@ impl SimdVec<f32> for F32x4 {
         Self { elements, size }
     }
 
+    #[inline(always)]
+    unsafe fn load_strided(ptr: *const f32, stride: usize) -> Self {
+        // No gather instruction in SSE nor NEON
+        let elements = [
+            *ptr,
+            *ptr.add(stride),
+            *ptr.add(2 * stride),
+            *ptr.add(3 * stride),
+        ];
+
+        Self::load(elements.as_ptr(), SIZE)
+    }
+
     #[inline(always)]
     fn to_vec(self) -> Vec<f32> {
-        let msg = format!("Size must be <= {}", SIZE);
-        assert!(self.size <= SIZE, "{}", msg);
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
 
         if self.size == SIZE {
             self.store()
@@ -135,9 +148,7 @@ impl SimdVec<f32> for F32x4 {
 
     #[inline(always)]
     fn store(&self) -> Vec<f32> {
-        let msg = format!("Size must be <= {}", SIZE);
-
-        assert!(self.size <= SIZE, "{}", msg);
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
 
         let mut vec = vec![0f32; SIZE];
 
@@ -156,14 +167,10 @@ impl SimdVec<f32> for F32x4 {
 
     #[inline(always)]
     unsafe fn store_at(&self, ptr: *mut f32) {
-        let msg = format!("Size must be <= {}", SIZE);
-
-        assert!(self.size <= SIZE, "{}", msg);
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
 
         #[cfg(target_arch = "x86_64")]
         unsafe {
-            println!("size {}", self.size);
-
             _mm_storeu_ps(ptr, self.elements);
         }
 
@@ -175,11 +182,9 @@ impl SimdVec<f32> for F32x4 {
 
     #[inline(always)]
     unsafe fn store_at_partial(&self, ptr: *mut f32) {
-        let msg = format!("Size must be <= {}", SIZE);
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
 
-        assert!(self.size <= SIZE, "{}", msg);
-
-        #[cfg(all(target_arch = "x86_64", sse))]
+        #[cfg(target_arch = "x86_64")]
         match self.size {
             3 => {
                 // Store first two floats at once using _mm_store_sd
@@ -205,31 +210,6 @@ impl SimdVec<f32> for F32x4 {
             }
         }
 
-        #[cfg(all(target_arch = "x86_64", avx2))]
-        match self.size {
-            3 => {
-                // Store first 2 floats (indices 0 and 1)
-                let mask = _mm_setr_epi32(-1, -1, 0, 0); // mask to store only first 2 floats
-                _mm_maskstore_ps(ptr, mask, self.elements);
-
-                // Extract 3rd float (index 2)
-                let shuffled = _mm_shuffle_ps(self.elements, self.elements, 0b10_10_10_10); // broadcast index 2
-                let third = _mm_cvtss_f32(shuffled); // convert to f32
-                *ptr.add(2) = third;
-            }
-            2 => {
-                let mask = _mm_setr_epi32(-1, -1, 0, 0); // store only first 2 floats
-                _mm_maskstore_ps(ptr, mask, self.elements);
-            }
-            1 => {
-                _mm_store_ss(ptr, self.elements); // store only lowest float
-            }
-            _ => {
-                let msg = "WTF is happening here";
-                panic!("{}", msg);
-            }
-        }
-
         #[cfg(target_arch = "aarch64")]
         match self.size {
             3 => {
@@ -272,8 +252,11 @@ impl SimdVec<f32> for F32x4 {
 
     #[inline(always)]
     fn simd_add(&self, rhs: Self) -> Self {
-        let msg = format!("Operands must have the same size {}", self.size);
-        assert!(self.size == rhs.size, "{}", msg);
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
 
         unsafe {
             // Add a+b
@@ -291,8 +274,782 @@ impl SimdVec<f32> for F32x4 {
     }
 
     #[inline(always)]
-    fn simd_sin(&self) {
-        todo!()
+    fn simd_sub(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // Sub a-b
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_sub_ps(self.elements, rhs.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vsubq_f32(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mul(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // Mul a*b
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_mul_ps(self.elements, rhs.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vmulq_f32(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_div(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // Div a/b
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_div_ps(self.elements, rhs.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vdivq_f32(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_min(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // minps returns rhs when either lane is NaN, take self where rhs is NaN
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_blendv_ps(
+                _mm_min_ps(self.elements, rhs.elements),
+                self.elements,
+                _mm_cmpunord_ps(rhs.elements, rhs.elements),
+            );
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vminnmq_f32(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_max(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // maxps returns rhs when either lane is NaN, take self where rhs is NaN
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_blendv_ps(
+                _mm_max_ps(self.elements, rhs.elements),
+                self.elements,
+                _mm_cmpunord_ps(rhs.elements, rhs.elements),
+            );
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vmaxnmq_f32(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_clamp(&self, lo: Self, hi: Self) -> Self {
+        assert!(
+            self.size == lo.size && self.size == hi.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // With self as second operand, minps/maxps propagate its NaN lanes
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_max_ps(lo.elements, _mm_min_ps(hi.elements, self.elements));
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vmaxq_f32(lo.elements, vminq_f32(hi.elements, self.elements));
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_fma(&self, b: Self, c: Self) -> Self {
+        assert!(
+            self.size == b.size && self.size == c.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // SSE has no fused multiply-add, a*b+c is rounded twice
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_add_ps(_mm_mul_ps(self.elements, b.elements), c.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vfmaq_f32(c.elements, self.elements, b.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mul_error(&self, b: Self) -> Self {
+        assert!(
+            self.size == b.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            // The product of two f32 is exact in f64, and so is its difference with the
+            // rounded one, computed two lanes at a time
+            #[cfg(target_arch = "x86_64")]
+            let elements = {
+                let p = _mm_mul_ps(self.elements, b.elements);
+                let error = |a: __m128, b: __m128, p: __m128| {
+                    let exact = _mm_mul_pd(_mm_cvtps_pd(a), _mm_cvtps_pd(b));
+                    _mm_cvtpd_ps(_mm_sub_pd(exact, _mm_cvtps_pd(p)))
+                };
+                let low = error(self.elements, b.elements, p);
+                let high = error(
+                    _mm_movehl_ps(self.elements, self.elements),
+                    _mm_movehl_ps(b.elements, b.elements),
+                    _mm_movehl_ps(p, p),
+                );
+
+                _mm_movelh_ps(low, high)
+            };
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = {
+                let p = vmulq_f32(self.elements, b.elements);
+                vfmaq_f32(vnegq_f32(p), self.elements, b.elements)
+            };
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_abs(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_andnot_ps(_mm_set1_ps(-0.0), self.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vabsq_f32(self.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_neg(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_xor_ps(self.elements, _mm_set1_ps(-0.0));
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vnegq_f32(self.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sqrt(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_sqrt_ps(self.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vsqrtq_f32(self.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt(&self) -> Self {
+        unsafe {
+            // A Newton-Raphson step y * (1.5 - 0.5 * x * y * y) on top of the estimate, then
+            // a second one y + 0.5 * y * (1 - x * y * y) correcting the rounding of the first.
+            // The estimate is kept for 0 and inf where the steps would produce NaN. rsqrtps
+            // flushes subnormals to zero, they are scaled by 2^24 and their result by 2^12
+            #[cfg(target_arch = "x86_64")]
+            let elements = {
+                let tiny = _mm_cmplt_ps(self.elements, _mm_set1_ps(f32::MIN_POSITIVE));
+                let x = _mm_blendv_ps(
+                    self.elements,
+                    _mm_mul_ps(self.elements, _mm_set1_ps(16777216.0)),
+                    tiny,
+                );
+                let scale = _mm_blendv_ps(_mm_set1_ps(1.0), _mm_set1_ps(4096.0), tiny);
+                let y = _mm_rsqrt_ps(x);
+
+                let xyy = _mm_mul_ps(_mm_mul_ps(x, y), y);
+                let step = _mm_sub_ps(_mm_set1_ps(1.5), _mm_mul_ps(_mm_set1_ps(0.5), xyy));
+                let y1 = _mm_mul_ps(y, step);
+
+                let xyy = _mm_mul_ps(_mm_mul_ps(x, y1), y1);
+                let half = _mm_mul_ps(_mm_set1_ps(0.5), y1);
+                let refined = _mm_add_ps(y1, _mm_mul_ps(half, _mm_sub_ps(_mm_set1_ps(1.0), xyy)));
+
+                let exact = _mm_or_ps(
+                    _mm_cmpeq_ps(x, _mm_setzero_ps()),
+                    _mm_cmpeq_ps(x, _mm_set1_ps(f32::INFINITY)),
+                );
+                _mm_mul_ps(_mm_blendv_ps(refined, y, exact), scale)
+            };
+
+            // vrsqrtsq computes (3 - a * b) / 2, a step from the 8 bit estimate, then a
+            // second one y + 0.5 * y * (1 - x * y * y) whose residual is computed exactly
+            #[cfg(target_arch = "aarch64")]
+            let elements = {
+                let x = self.elements;
+                let estimate = vrsqrteq_f32(x);
+                let y1 = vmulq_f32(estimate, vrsqrtsq_f32(vmulq_f32(x, estimate), estimate));
+
+                let xy = vmulq_f32(x, y1);
+                let xy_err = vfmaq_f32(vnegq_f32(xy), x, y1);
+                let residual = vfmsq_f32(vdupq_n_f32(1.0), xy, y1);
+                let residual = vfmsq_f32(residual, xy_err, y1);
+                let half = vmulq_f32(vdupq_n_f32(0.5), y1);
+                let y = vfmaq_f32(y1, half, residual);
+
+                let exact = vorrq_u32(
+                    vceqq_f32(x, vdupq_n_f32(0.0)),
+                    vceqq_f32(x, vdupq_n_f32(f32::INFINITY)),
+                );
+                vbslq_f32(exact, estimate, y)
+            };
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt_approx(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_rsqrt_ps(self.elements);
+
+            // One step from the 8 bit estimate, keeping it for 0 and inf
+            #[cfg(target_arch = "aarch64")]
+            let elements = {
+                let x = self.elements;
+                let estimate = vrsqrteq_f32(x);
+                let y = vmulq_f32(estimate, vrsqrtsq_f32(vmulq_f32(x, estimate), estimate));
+
+                let exact = vorrq_u32(
+                    vceqq_f32(x, vdupq_n_f32(0.0)),
+                    vceqq_f32(x, vdupq_n_f32(f32::INFINITY)),
+                );
+                vbslq_f32(exact, estimate, y)
+            };
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_recip(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_div_ps(_mm_set1_ps(1.0), self.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vdivq_f32(vdupq_n_f32(1.0), self.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_floor(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements =
+                _mm_round_ps::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(self.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vrndmq_f32(self.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_ceil(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements =
+                _mm_round_ps::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(self.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vrndpq_f32(self.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_round(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements =
+                _mm_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(self.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vrndnq_f32(self.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_trunc(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements =
+                _mm_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vrndq_f32(self.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_eq(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_cmpeq_ps(self.elements, rhs.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vreinterpretq_f32_u32(vceqq_f32(self.elements, rhs.elements));
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_lt(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_cmplt_ps(self.elements, rhs.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vreinterpretq_f32_u32(vcltq_f32(self.elements, rhs.elements));
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_le(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_cmple_ps(self.elements, rhs.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vreinterpretq_f32_u32(vcleq_f32(self.elements, rhs.elements));
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_select(&self, a: Self, b: Self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_blendv_ps(b.elements, a.elements, self.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vbslq_f32(vreinterpretq_u32_f32(self.elements), a.elements, b.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_and(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_and_ps(self.elements, rhs.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vreinterpretq_f32_u32(vandq_u32(
+                vreinterpretq_u32_f32(self.elements),
+                vreinterpretq_u32_f32(rhs.elements),
+            ));
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_xor(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = _mm_xor_ps(self.elements, rhs.elements);
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = vreinterpretq_f32_u32(veorq_u32(
+                vreinterpretq_u32_f32(self.elements),
+                vreinterpretq_u32_f32(rhs.elements),
+            ));
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_any(&self) -> bool {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let any = _mm_movemask_ps(self.elements) != 0;
+
+            #[cfg(target_arch = "aarch64")]
+            let any = vmaxvq_u32(vreinterpretq_u32_f32(self.elements)) != 0;
+
+            any
+        }
+    }
+
+    #[inline(always)]
+    fn simd_exp2i(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = {
+                let n = _mm_add_epi32(_mm_cvtps_epi32(self.elements), _mm_set1_epi32(127));
+                _mm_castsi128_ps(_mm_slli_epi32::<23>(n))
+            };
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = {
+                let n = vaddq_s32(vcvtnq_s32_f32(self.elements), vdupq_n_s32(127));
+                vreinterpretq_f32_s32(vshlq_n_s32::<23>(n))
+            };
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_exponent(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = {
+                let bits = _mm_srli_epi32::<23>(_mm_castps_si128(self.elements));
+                let n = _mm_sub_epi32(
+                    _mm_and_si128(bits, _mm_set1_epi32(0xff)),
+                    _mm_set1_epi32(127),
+                );
+                _mm_cvtepi32_ps(n)
+            };
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = {
+                let bits = vshrq_n_u32::<23>(vreinterpretq_u32_f32(self.elements));
+                let n = vsubq_s32(
+                    vreinterpretq_s32_u32(vandq_u32(bits, vdupq_n_u32(0xff))),
+                    vdupq_n_s32(127),
+                );
+                vcvtq_f32_s32(n)
+            };
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mantissa(&self) -> Self {
+        unsafe {
+            #[cfg(target_arch = "x86_64")]
+            let elements = {
+                let bits =
+                    _mm_and_si128(_mm_castps_si128(self.elements), _mm_set1_epi32(0x007f_ffff));
+                _mm_castsi128_ps(_mm_or_si128(bits, _mm_set1_epi32(0x3f80_0000)))
+            };
+
+            #[cfg(target_arch = "aarch64")]
+            let elements = {
+                let bits = vandq_u32(
+                    vreinterpretq_u32_f32(self.elements),
+                    vdupq_n_u32(0x007f_ffff),
+                );
+                vreinterpretq_f32_u32(vorrq_u32(bits, vdupq_n_u32(0x3f80_0000)))
+            };
+
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sin(&self) -> Self {
+        math::sin(*self)
+    }
+
+    #[inline(always)]
+    fn simd_cos(&self) -> Self {
+        math::cos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tan(&self) -> Self {
+        math::tan(*self)
+    }
+
+    #[inline(always)]
+    fn simd_sincos(&self) -> (Self, Self) {
+        math::sincos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_exp(&self) -> Self {
+        math::exp(*self)
+    }
+
+    #[inline(always)]
+    fn simd_exp2(&self) -> Self {
+        math::exp2(*self)
+    }
+
+    #[inline(always)]
+    fn simd_expm1(&self) -> Self {
+        math::expm1(*self)
+    }
+
+    #[inline(always)]
+    fn simd_ln(&self) -> Self {
+        math::ln(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log2(&self) -> Self {
+        math::log2(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log10(&self) -> Self {
+        math::log10(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log1p(&self) -> Self {
+        math::log1p(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tanh(&self) -> Self {
+        math::tanh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_erf(&self) -> Self {
+        math::erf(*self)
+    }
+
+    #[inline(always)]
+    fn simd_erfc(&self) -> Self {
+        math::erfc(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tgamma(&self) -> Self {
+        math::tgamma(*self)
+    }
+
+    #[inline(always)]
+    fn simd_lgamma(&self) -> Self {
+        math::lgamma(*self)
+    }
+
+    #[inline(always)]
+    fn simd_asin(&self) -> Self {
+        math::asin(*self)
+    }
+
+    #[inline(always)]
+    fn simd_acos(&self) -> Self {
+        math::acos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atan(&self) -> Self {
+        math::atan(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atan2(&self, x: Self) -> Self {
+        math::atan2(*self, x)
+    }
+
+    #[inline(always)]
+    fn simd_sinh(&self) -> Self {
+        math::sinh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_cosh(&self) -> Self {
+        math::cosh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_asinh(&self) -> Self {
+        math::asinh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_acosh(&self) -> Self {
+        math::acosh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atanh(&self) -> Self {
+        math::atanh(*self)
     }
 }
 
@@ -302,9 +1059,11 @@ impl Add for F32x4 {
 
     #[inline(always)]
     fn add(self, rhs: F32x4) -> Self::Output {
-        let msg = format!("Operands must have the same size {}", SIZE);
-
-        assert!(self.size == rhs.size, "{}", msg);
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            SIZE
+        );
 
         match self.size.cmp(&SIZE) {
             std::cmp::Ordering::Less => self.simd_mask_add(rhs),
@@ -316,3 +1075,33 @@ impl Add for F32x4 {
         }
     }
 }
+
+/// Implementation of Sub trait for F32x4 using custom SIMD types
+impl Sub for F32x4 {
+    type Output = F32x4;
+
+    #[inline(always)]
+    fn sub(self, rhs: F32x4) -> Self::Output {
+        self.simd_sub(rhs)
+    }
+}
+
+/// Implementation of Mul trait for F32x4 using custom SIMD types
+impl Mul for F32x4 {
+    type Output = F32x4;
+
+    #[inline(always)]
+    fn mul(self, rhs: F32x4) -> Self::Output {
+        self.simd_mul(rhs)
+    }
+}
+
+/// Implementation of Div trait for F32x4 using custom SIMD types
+impl Div for F32x4 {
+    type Output = F32x4;
+
+    #[inline(always)]
+    fn div(self, rhs: F32x4) -> Self::Output {
+        self.simd_div(rhs)
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(not(target_arch = "x86_64"))]
 use super::f32x4::{self, F32x4};
 
+use super::math;
 use super::utils::SimdVec;
 
 #[cfg(target_arch = "x86_64")]
@@ -12,7 +13,7 @@ use std::arch::aarch64::*;
 #[cfg(target_arch = "arm")]
 use std::arch::arm::*;
 
-use std::ops::Add;
+use std::ops::{Add, Div, Mul, Sub};
 
 pub const SIZE: usize = 8;
 
@@ -30,6 +31,9 @@ pub struct F32x8 {
 }
 
 impl SimdVec<f32> for F32x8 {
+    const SIZE: usize = SIZE;
+
+    #[inline(always)]
     fn new(slice: &[f32]) -> Self {
         match slice.len().cmp(&SIZE) {
             std::cmp::Ordering::Less => unsafe { Self::load_partial(slice.as_ptr(), slice.len()) },
@@ -39,6 +43,7 @@ impl SimdVec<f32> for F32x8 {
         }
     }
 
+    #[inline(always)]
     fn splat(value: f32) -> Self {
         #[cfg(target_arch = "x86_64")]
         let splat = Self {
@@ -58,8 +63,7 @@ impl SimdVec<f32> for F32x8 {
 
     #[inline(always)]
     unsafe fn load(ptr: *const f32, size: usize) -> Self {
-        let msg = format!("Size must be == {}", SIZE);
-        assert!(size == SIZE, "{}", msg);
+        assert!(size == SIZE, "Size must be == {}", SIZE);
 
         #[cfg(target_arch = "x86_64")]
         let loaded = Self {
@@ -79,8 +83,7 @@ impl SimdVec<f32> for F32x8 {
 
     #[inline(always)]
     unsafe fn load_partial(ptr: *const f32, size: usize) -> Self {
-        let msg = format!("Size must be < {}", SIZE);
-        assert!(size < SIZE, "{}", msg);
+        assert!(size < SIZE, "Size must be < {}", SIZE);
         #[cfg(target_arch = "x86_64")]
         let elements = match size {
             1 => unsafe { _mm256_set_ps(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, *ptr.add(0)) },
@@ -179,10 +182,44 @@ impl SimdVec<f32> for F32x8 {
         loaded
     }
 
+    #[inline(always)]
+    unsafe fn load_strided(ptr: *const f32, stride: usize) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        let loaded = if stride * (SIZE - 1) <= i32::MAX as usize {
+            // Gather, requires AVX2
+            let index = _mm256_mullo_epi32(
+                _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7),
+                _mm256_set1_epi32(stride as i32),
+            );
+
+            Self {
+                elements: unsafe { _mm256_i32gather_ps::<4>(ptr, index) },
+                size: SIZE,
+            }
+        } else {
+            // Offsets overflow the 32-bit gather indices
+            let mut elements = [0f32; SIZE];
+
+            for (i, element) in elements.iter_mut().enumerate() {
+                *element = *ptr.add(i * stride);
+            }
+
+            Self::load(elements.as_ptr(), SIZE)
+        };
+
+        #[cfg(not(target_arch = "x86_64"))]
+        let loaded = Self {
+            size: SIZE,
+            low: F32x4::load_strided(ptr, stride),
+            high: F32x4::load_strided(unsafe { ptr.add(4 * stride) }, stride),
+        };
+
+        loaded
+    }
+
     #[inline(always)]
     fn to_vec(self) -> Vec<f32> {
-        let msg = format!("Size must be <= {}", SIZE);
-        assert!(self.size <= SIZE, "{}", msg);
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
 
         if self.size == SIZE {
             self.store()
@@ -191,10 +228,9 @@ impl SimdVec<f32> for F32x8 {
         }
     }
 
+    #[inline(always)]
     fn store(&self) -> Vec<f32> {
-        let msg = format!("Size must be <= {}", SIZE);
-
-        assert!(self.size <= SIZE, "{}", msg);
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
 
         #[cfg(target_arch = "x86_64")]
         unsafe {
@@ -217,6 +253,7 @@ impl SimdVec<f32> for F32x8 {
         }
     }
 
+    #[inline(always)]
     fn store_partial(&self) -> Vec<f32> {
         match self.size {
             1..=7 => self.store().into_iter().take(self.size).collect(),
@@ -227,13 +264,18 @@ impl SimdVec<f32> for F32x8 {
         }
     }
 
+    #[inline(always)]
     fn simd_mask_add(&self, rhs: Self) -> Self {
         self.simd_add(rhs)
     }
 
+    #[inline(always)]
     fn simd_add(&self, rhs: Self) -> Self {
-        let msg = format!("Operands must have the same size {}", self.size);
-        assert!(self.size == rhs.size, "{}", msg);
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
 
         #[cfg(target_arch = "x86_64")]
         unsafe {
@@ -256,165 +298,989 @@ impl SimdVec<f32> for F32x8 {
         }
     }
 
-    fn simd_sin(&self) {
-        todo!()
-    }
-
-    unsafe fn store_at(&self, ptr: *mut f32) {
-        let msg = format!("Size must be <= {}", SIZE);
-
-        assert!(self.size <= SIZE, "{}", msg);
+    #[inline(always)]
+    fn simd_sub(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
 
         #[cfg(target_arch = "x86_64")]
         unsafe {
-            _mm256_storeu_ps(ptr, self.elements);
+            // Sub a-b
+            let elements = _mm256_sub_ps(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
+            }
         }
 
-        #[cfg(target_arch = "aarch64")]
-        unsafe {
-            vst1q_f32(ptr, self.low.elements);
-            vst1q_f32(ptr.add(f32x4::SIZE), self.high.elements);
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low - rhs.low,
+                high: self.high - rhs.high,
+                size: self.size,
+            }
         }
     }
 
-    unsafe fn store_at_partial(&self, ptr: *mut f32) {
-        #[cfg(all(sse, target_arch = "x86_64"))]
-        match self.size {
-            4..=7 => {
-                // Store lower 4 floats with a single instruction
-                _mm_storeu_ps(ptr, _mm256_castps256_ps128(self.elements));
+    #[inline(always)]
+    fn simd_mul(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
 
-                // Extract upper lane once
-                let upper = _mm256_extractf128_ps(self.elements, 1);
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            // Mul a*b
+            let elements = _mm256_mul_ps(self.elements, rhs.elements);
 
-                // Use a more efficient approach for remaining elements
-                match self.size {
-                    7 => {
-                        // Store the next 3 elements with one instruction
-                        _mm_storel_pi((ptr.add(4)) as *mut __m64, upper);
-                        let seventh = _mm_extract_ps(upper, 2) as u32;
-                        *(ptr.add(6)) = core::mem::transmute(seventh);
-                    }
-                    6 => {
-                        // Store the next 2 elements with one instruction
-                        _mm_storel_pi((ptr.add(4)) as *mut __m64, upper);
-                    }
-                    5 => {
-                        // Store just one more element
-                        _mm_store_ss(ptr.add(4), upper);
-                    }
-                    _ => {} // Size 4 already fully handled
-                }
-            }
-            3 => {
-                let lower = _mm256_castps256_ps128(self.elements);
-                _mm_storel_pi(ptr as *mut __m64, lower);
-                let third = _mm_extract_ps(lower, 2) as u32;
-                *(ptr.add(2)) = core::mem::transmute(third);
-            }
-            2 => {
-                let lower = _mm256_castps256_ps128(self.elements);
-                _mm_storel_pi(ptr as *mut __m64, lower);
+            Self {
+                elements,
+                size: self.size,
             }
-            1 => {
-                let lower = _mm256_castps256_ps128(self.elements);
-                _mm_store_ss(ptr, lower);
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low * rhs.low,
+                high: self.high * rhs.high,
+                size: self.size,
             }
-            _ => {
-                let msg = "WTF is happening here";
-                panic!("{}", msg);
+        }
+    }
+
+    #[inline(always)]
+    fn simd_div(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            // Div a/b
+            let elements = _mm256_div_ps(self.elements, rhs.elements);
+
+            Self {
+                elements,
+                size: self.size,
             }
         }
 
-        #[cfg(all(avx2, target_arch = "x86_64"))]
-        match self.size {
-            4..=7 => {
-                let lower = _mm256_castps256_ps128(self.elements);
-                _mm_storeu_ps(ptr, lower); // Store first 4 floats
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low / rhs.low,
+                high: self.high / rhs.high,
+                size: self.size,
+            }
+        }
+    }
 
-                let upper = _mm256_extractf128_ps(self.elements, 1); // Extract upper lane
+    #[inline(always)]
+    fn simd_min(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
 
-                match self.size {
-                    7 => {
-                        // Store 5th float
-                        _mm_store_ss(ptr.add(4), upper);
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            // vminps returns rhs when either lane is NaN, take self where rhs is NaN
+            let elements = _mm256_blendv_ps(
+                _mm256_min_ps(self.elements, rhs.elements),
+                self.elements,
+                _mm256_cmp_ps(rhs.elements, rhs.elements, _CMP_UNORD_Q),
+            );
 
-                        // Store 6th float
-                        let sixth = _mm_shuffle_ps(upper, upper, 0b01_01_01_01); // move upper[1] to all lanes
-                        _mm_store_ss(ptr.add(5), sixth);
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
 
-                        // Store 7th float
-                        let seventh = _mm_shuffle_ps(upper, upper, 0b10_10_10_10); // move upper[2] to all lanes
-                        _mm_store_ss(ptr.add(6), seventh);
-                    }
-                    6 => {
-                        _mm_store_ss(ptr.add(4), upper);
-                        let sixth = _mm_shuffle_ps(upper, upper, 0b01_01_01_01);
-                        _mm_store_ss(ptr.add(5), sixth);
-                    }
-                    5 => {
-                        _mm_store_ss(ptr.add(4), upper);
-                    }
-                    _ => {}
-                }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_min(rhs.low),
+                high: self.high.simd_min(rhs.high),
+                size: self.size,
             }
-            3 => {
-                let lower = _mm256_castps256_ps128(self.elements);
-                // Store first float
-                _mm_store_ss(ptr, lower);
-                // Store second float
-                let second = _mm_shuffle_ps(lower, lower, 0b01_01_01_01);
-                _mm_store_ss(ptr.add(1), second);
-                // Store third float
-                let third = _mm_shuffle_ps(lower, lower, 0b10_10_10_10);
-                _mm_store_ss(ptr.add(2), third);
+        }
+    }
+
+    #[inline(always)]
+    fn simd_max(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            // vmaxps returns rhs when either lane is NaN, take self where rhs is NaN
+            let elements = _mm256_blendv_ps(
+                _mm256_max_ps(self.elements, rhs.elements),
+                self.elements,
+                _mm256_cmp_ps(rhs.elements, rhs.elements, _CMP_UNORD_Q),
+            );
+
+            Self {
+                elements,
+                size: self.size,
             }
-            2 => {
-                let lower = _mm256_castps256_ps128(self.elements);
-                _mm_store_ss(ptr, lower);
-                let second = _mm_shuffle_ps(lower, lower, 0b01_01_01_01);
-                _mm_store_ss(ptr.add(1), second);
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_max(rhs.low),
+                high: self.high.simd_max(rhs.high),
+                size: self.size,
             }
-            1 => {
-                let lower = _mm256_castps256_ps128(self.elements);
-                _mm_store_ss(ptr, lower);
+        }
+    }
+
+    #[inline(always)]
+    fn simd_clamp(&self, lo: Self, hi: Self) -> Self {
+        assert!(
+            self.size == lo.size && self.size == hi.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            // With self as second operand, vminps/vmaxps propagate its NaN lanes
+            let elements = _mm256_max_ps(lo.elements, _mm256_min_ps(hi.elements, self.elements));
+
+            Self {
+                elements,
+                size: self.size,
             }
-            _ => {
-                panic!("Unexpected size: {}", self.size);
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_clamp(lo.low, hi.low),
+                high: self.high.simd_clamp(lo.high, hi.high),
+                size: self.size,
             }
         }
-        #[cfg(target_arch = "aarch64")]
-        match self.size {
-            5..=7 => {
-                self.low.store_at(ptr);
-                self.high.store_at_partial(ptr.add(4));
+    }
+
+    #[inline(always)]
+    fn simd_fma(&self, b: Self, c: Self) -> Self {
+        assert!(
+            self.size == b.size && self.size == c.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            // a*b+c, requires FMA along with AVX2
+            let elements = _mm256_fmadd_ps(self.elements, b.elements, c.elements);
+
+            Self {
+                elements,
+                size: self.size,
             }
-            4 => self.low.store_at(ptr),
-            1..=3 => self.low.store_at_partial(ptr),
-            _ => {
-                let msg = "WTF is happening here";
-                panic!("{}", msg);
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_fma(b.low, c.low),
+                high: self.high.simd_fma(b.high, c.high),
+                size: self.size,
             }
         }
     }
-}
 
-/// Implementation of Add trait for F32x8 using custom SIMD types
-impl Add for F32x8 {
-    type Output = F32x8;
+    #[inline(always)]
+    fn simd_mul_error(&self, b: Self) -> Self {
+        assert!(
+            self.size == b.size,
+            "Operands must have the same size {}",
+            self.size
+        );
 
-    fn add(self, rhs: F32x8) -> Self::Output {
-        let msg = format!("Operands must have the same size {}", SIZE);
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            let p = _mm256_mul_ps(self.elements, b.elements);
+            let elements = _mm256_fmsub_ps(self.elements, b.elements, p);
 
-        assert!(self.size == rhs.size, "{}", msg);
+            Self {
+                elements,
+                size: self.size,
+            }
+        }
 
-        match self.size.cmp(&SIZE) {
-            std::cmp::Ordering::Less => self.simd_mask_add(rhs),
-            std::cmp::Ordering::Equal => self.simd_add(rhs),
-            std::cmp::Ordering::Greater => {
-                let msg = format!("F32x16 size must not exceed {}", SIZE);
-                panic!("{}", msg);
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_mul_error(b.low),
+                high: self.high.simd_mul_error(b.high),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_abs(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_andnot_ps(_mm256_set1_ps(-0.0), self.elements),
+                size: self.size,
             }
         }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_abs(),
+                high: self.high.simd_abs(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_neg(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_xor_ps(self.elements, _mm256_set1_ps(-0.0)),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_neg(),
+                high: self.high.simd_neg(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sqrt(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_sqrt_ps(self.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_sqrt(),
+                high: self.high.simd_sqrt(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            // A Newton-Raphson step y * (1.5 - 0.5 * x * y * y) on top of the estimate, then
+            // a second one y + 0.5 * y * (1 - x * y * y) whose residual is computed exactly
+            // with FMA. The estimate is kept for 0 and inf where the steps would produce NaN.
+            // vrsqrtps flushes subnormals to zero, they are scaled by 2^24 and their result by 2^12
+            let tiny =
+                _mm256_cmp_ps::<_CMP_LT_OQ>(self.elements, _mm256_set1_ps(f32::MIN_POSITIVE));
+            let x = _mm256_blendv_ps(
+                self.elements,
+                _mm256_mul_ps(self.elements, _mm256_set1_ps(16777216.0)),
+                tiny,
+            );
+            let scale = _mm256_blendv_ps(_mm256_set1_ps(1.0), _mm256_set1_ps(4096.0), tiny);
+            let y = _mm256_rsqrt_ps(x);
+
+            let xyy = _mm256_mul_ps(_mm256_mul_ps(x, y), y);
+            let step = _mm256_fnmadd_ps(_mm256_set1_ps(0.5), xyy, _mm256_set1_ps(1.5));
+            let y1 = _mm256_mul_ps(y, step);
+
+            let xy = _mm256_mul_ps(x, y1);
+            let xy_err = _mm256_fmsub_ps(x, y1, xy);
+            let residual = _mm256_fnmadd_ps(xy, y1, _mm256_set1_ps(1.0));
+            let residual = _mm256_fnmadd_ps(xy_err, y1, residual);
+            let half = _mm256_mul_ps(_mm256_set1_ps(0.5), y1);
+            let refined = _mm256_fmadd_ps(half, residual, y1);
+
+            let exact = _mm256_or_ps(
+                _mm256_cmp_ps::<_CMP_EQ_OQ>(x, _mm256_setzero_ps()),
+                _mm256_cmp_ps::<_CMP_EQ_OQ>(x, _mm256_set1_ps(f32::INFINITY)),
+            );
+
+            Self {
+                elements: _mm256_mul_ps(_mm256_blendv_ps(refined, y, exact), scale),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_rsqrt(),
+                high: self.high.simd_rsqrt(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt_approx(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_rsqrt_ps(self.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_rsqrt_approx(),
+                high: self.high.simd_rsqrt_approx(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_recip(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_div_ps(_mm256_set1_ps(1.0), self.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_recip(),
+                high: self.high.simd_recip(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_floor(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_round_ps::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(
+                    self.elements,
+                ),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_floor(),
+                high: self.high.simd_floor(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_ceil(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_round_ps::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(
+                    self.elements,
+                ),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_ceil(),
+                high: self.high.simd_ceil(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_round(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+                    self.elements,
+                ),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_round(),
+                high: self.high.simd_round(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_trunc(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(
+                    self.elements,
+                ),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_trunc(),
+                high: self.high.simd_trunc(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_eq(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_cmp_ps::<_CMP_EQ_OQ>(self.elements, rhs.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_eq(rhs.low),
+                high: self.high.simd_eq(rhs.high),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_lt(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_cmp_ps::<_CMP_LT_OQ>(self.elements, rhs.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_lt(rhs.low),
+                high: self.high.simd_lt(rhs.high),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_le(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_cmp_ps::<_CMP_LE_OQ>(self.elements, rhs.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_le(rhs.low),
+                high: self.high.simd_le(rhs.high),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_select(&self, a: Self, b: Self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_blendv_ps(b.elements, a.elements, self.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_select(a.low, b.low),
+                high: self.high.simd_select(a.high, b.high),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_and(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_and_ps(self.elements, rhs.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_and(rhs.low),
+                high: self.high.simd_and(rhs.high),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_xor(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            Self {
+                elements: _mm256_xor_ps(self.elements, rhs.elements),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_xor(rhs.low),
+                high: self.high.simd_xor(rhs.high),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_any(&self) -> bool {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            _mm256_movemask_ps(self.elements) != 0
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            self.low.simd_any() || self.high.simd_any()
+        }
+    }
+
+    #[inline(always)]
+    fn simd_exp2i(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            let n = _mm256_add_epi32(_mm256_cvtps_epi32(self.elements), _mm256_set1_epi32(127));
+
+            Self {
+                elements: _mm256_castsi256_ps(_mm256_slli_epi32::<23>(n)),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_exp2i(),
+                high: self.high.simd_exp2i(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_exponent(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            let bits = _mm256_srli_epi32::<23>(_mm256_castps_si256(self.elements));
+            let n = _mm256_sub_epi32(
+                _mm256_and_si256(bits, _mm256_set1_epi32(0xff)),
+                _mm256_set1_epi32(127),
+            );
+
+            Self {
+                elements: _mm256_cvtepi32_ps(n),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_exponent(),
+                high: self.high.simd_exponent(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mantissa(&self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            let bits = _mm256_and_si256(
+                _mm256_castps_si256(self.elements),
+                _mm256_set1_epi32(0x007f_ffff),
+            );
+
+            Self {
+                elements: _mm256_castsi256_ps(_mm256_or_si256(
+                    bits,
+                    _mm256_set1_epi32(0x3f80_0000),
+                )),
+                size: self.size,
+            }
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self {
+                low: self.low.simd_mantissa(),
+                high: self.high.simd_mantissa(),
+                size: self.size,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sin(&self) -> Self {
+        math::sin(*self)
+    }
+
+    #[inline(always)]
+    fn simd_cos(&self) -> Self {
+        math::cos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tan(&self) -> Self {
+        math::tan(*self)
+    }
+
+    #[inline(always)]
+    fn simd_sincos(&self) -> (Self, Self) {
+        math::sincos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_exp(&self) -> Self {
+        math::exp(*self)
+    }
+
+    #[inline(always)]
+    fn simd_exp2(&self) -> Self {
+        math::exp2(*self)
+    }
+
+    #[inline(always)]
+    fn simd_expm1(&self) -> Self {
+        math::expm1(*self)
+    }
+
+    #[inline(always)]
+    fn simd_ln(&self) -> Self {
+        math::ln(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log2(&self) -> Self {
+        math::log2(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log10(&self) -> Self {
+        math::log10(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log1p(&self) -> Self {
+        math::log1p(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tanh(&self) -> Self {
+        math::tanh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_erf(&self) -> Self {
+        math::erf(*self)
+    }
+
+    #[inline(always)]
+    fn simd_erfc(&self) -> Self {
+        math::erfc(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tgamma(&self) -> Self {
+        math::tgamma(*self)
+    }
+
+    #[inline(always)]
+    fn simd_lgamma(&self) -> Self {
+        math::lgamma(*self)
+    }
+
+    #[inline(always)]
+    fn simd_asin(&self) -> Self {
+        math::asin(*self)
+    }
+
+    #[inline(always)]
+    fn simd_acos(&self) -> Self {
+        math::acos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atan(&self) -> Self {
+        math::atan(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atan2(&self, x: Self) -> Self {
+        math::atan2(*self, x)
+    }
+
+    #[inline(always)]
+    fn simd_sinh(&self) -> Self {
+        math::sinh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_cosh(&self) -> Self {
+        math::cosh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_asinh(&self) -> Self {
+        math::asinh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_acosh(&self) -> Self {
+        math::acosh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atanh(&self) -> Self {
+        math::atanh(*self)
+    }
+
+    #[inline(always)]
+    unsafe fn store_at(&self, ptr: *mut f32) {
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            _mm256_storeu_ps(ptr, self.elements);
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        unsafe {
+            vst1q_f32(ptr, self.low.elements);
+            vst1q_f32(ptr.add(f32x4::SIZE), self.high.elements);
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn store_at_partial(&self, ptr: *mut f32) {
+        #[cfg(target_arch = "x86_64")]
+        match self.size {
+            4..=7 => {
+                let lower = _mm256_castps256_ps128(self.elements);
+                _mm_storeu_ps(ptr, lower); // Store first 4 floats
+
+                let upper = _mm256_extractf128_ps(self.elements, 1); // Extract upper lane
+
+                match self.size {
+                    7 => {
+                        // Store 5th float
+                        _mm_store_ss(ptr.add(4), upper);
+
+                        // Store 6th float
+                        let sixth = _mm_shuffle_ps(upper, upper, 0b01_01_01_01); // move upper[1] to all lanes
+                        _mm_store_ss(ptr.add(5), sixth);
+
+                        // Store 7th float
+                        let seventh = _mm_shuffle_ps(upper, upper, 0b10_10_10_10); // move upper[2] to all lanes
+                        _mm_store_ss(ptr.add(6), seventh);
+                    }
+                    6 => {
+                        _mm_store_ss(ptr.add(4), upper);
+                        let sixth = _mm_shuffle_ps(upper, upper, 0b01_01_01_01);
+                        _mm_store_ss(ptr.add(5), sixth);
+                    }
+                    5 => {
+                        _mm_store_ss(ptr.add(4), upper);
+                    }
+                    _ => {}
+                }
+            }
+            3 => {
+                let lower = _mm256_castps256_ps128(self.elements);
+                // Store first float
+                _mm_store_ss(ptr, lower);
+                // Store second float
+                let second = _mm_shuffle_ps(lower, lower, 0b01_01_01_01);
+                _mm_store_ss(ptr.add(1), second);
+                // Store third float
+                let third = _mm_shuffle_ps(lower, lower, 0b10_10_10_10);
+                _mm_store_ss(ptr.add(2), third);
+            }
+            2 => {
+                let lower = _mm256_castps256_ps128(self.elements);
+                _mm_store_ss(ptr, lower);
+                let second = _mm_shuffle_ps(lower, lower, 0b01_01_01_01);
+                _mm_store_ss(ptr.add(1), second);
+            }
+            1 => {
+                let lower = _mm256_castps256_ps128(self.elements);
+                _mm_store_ss(ptr, lower);
+            }
+            _ => {
+                panic!("Unexpected size: {}", self.size);
+            }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        match self.size {
+            5..=7 => {
+                self.low.store_at(ptr);
+                self.high.store_at_partial(ptr.add(4));
+            }
+            4 => self.low.store_at(ptr),
+            1..=3 => self.low.store_at_partial(ptr),
+            _ => {
+                let msg = "WTF is happening here";
+                panic!("{}", msg);
+            }
+        }
+    }
+}
+
+/// Implementation of Add trait for F32x8 using custom SIMD types
+impl Add for F32x8 {
+    type Output = F32x8;
+
+    #[inline(always)]
+    fn add(self, rhs: F32x8) -> Self::Output {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            SIZE
+        );
+
+        match self.size.cmp(&SIZE) {
+            std::cmp::Ordering::Less => self.simd_mask_add(rhs),
+            std::cmp::Ordering::Equal => self.simd_add(rhs),
+            std::cmp::Ordering::Greater => {
+                let msg = format!("F32x16 size must not exceed {}", SIZE);
+                panic!("{}", msg);
+            }
+        }
+    }
+}
+
+/// Implementation of Sub trait for F32x8 using custom SIMD types
+impl Sub for F32x8 {
+    type Output = F32x8;
+
+    #[inline(always)]
+    fn sub(self, rhs: F32x8) -> Self::Output {
+        self.simd_sub(rhs)
+    }
+}
+
+/// Implementation of Mul trait for F32x8 using custom SIMD types
+impl Mul for F32x8 {
+    type Output = F32x8;
+
+    #[inline(always)]
+    fn mul(self, rhs: F32x8) -> Self::Output {
+        self.simd_mul(rhs)
+    }
+}
+
+/// Implementation of Div trait for F32x8 using custom SIMD types
+impl Div for F32x8 {
+    type Output = F32x8;
+
+    #[inline(always)]
+    fn div(self, rhs: F32x8) -> Self::Output {
+        self.simd_div(rhs)
     }
 }
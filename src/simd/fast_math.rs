@@ -0,0 +1,562 @@
+//! Cheaper variants of the functions of `math`, for `Accuracy::Fast`
+//!
+//! They keep the reductions and special values of `math` with shorter polynomials,
+//! fitted for a relative error below 2^-15, leaving room for the rounding of the
+//! reductions below 1e-4.
+
+use super::math::{self, Polynomials};
+use super::utils::SimdVec;
+
+// pi / 2 - PIO2[0] - PIO2[1], for a reduction in three steps instead of five
+const PIO2_LO: f32 = 7.54979e-8;
+
+// Polynomials of sin and cos on [-pi / 4, pi / 4], see `math::sincos_reduced`
+const SIN_P: [f32; 2] = [8.211856e-3, -1.6665731e-1];
+const COS_P: [f32; 2] = [-1.3736814e-3, 4.1665494e-2];
+
+// Polynomial of (exp(r) - 1 - r) / r^2 on [-ln 2 / 2, ln 2 / 2]
+const EXPM1_P: [f32; 4] = [8.3572e-3, 4.1833803e-2, 1.6666631e-1, 4.999975e-1];
+
+// Polynomial of (ln(1 + f) - f + f^2 / 2) / f^3 on [sqrt(1 / 2) - 1, sqrt(2) - 1]
+const LOG_P: [f32; 5] = [
+    1.2354828e-1,
+    -1.8178457e-1,
+    2.0249808e-1,
+    -2.4962643e-1,
+    3.3330503e-1,
+];
+
+// Polynomial of (tanh(x) / x - 1) / x^2 in x^2 on [0, 0.625]
+const TANH_P: [f32; 3] = [-4.3117654e-2, 1.3182051e-1, -3.333008e-1];
+
+// Polynomial of erf(x) / x - 1 in x^2 on [0, 1]
+const ERF_P: [f32; 5] = [
+    3.5048246e-3,
+    -2.5469659e-2,
+    1.123557e-1,
+    -3.7606704e-1,
+    1.2837799e-1,
+];
+
+// Polynomial of erfc(x) * exp(x^2) * (1 + 2 x) in t = (x - 2) / (x + 2) on [0, 10.06]
+const ERFC_P: [f32; 7] = [
+    5.5433204e-3,
+    -7.418819e-3,
+    -5.2399263e-2,
+    1.6394927e-1,
+    -1.6634518e-1,
+    -9.275487e-2,
+    1.2769868,
+];
+
+// Polynomial of (atan(x) / x - 1) / x^2 in x^2 on [0, 1]
+const ATAN_P: [f32; 5] = [
+    -1.8023219e-2,
+    6.834406e-2,
+    -1.3017781e-1,
+    1.985442e-1,
+    -3.333047e-1,
+];
+
+// Polynomial of (asin(x) / x - 1) / x^2 in x^2 on [0, 1 / 2]
+const ASIN_P: [f32; 4] = [7.493814e-2, 2.7976174e-2, 7.678772e-2, 1.6663837e-1];
+
+// Polynomial of (sinh(x) / x - 1) / x^2 in x^2 on [0, 1]
+const SINH_P: [f32; 2] = [8.534176e-3, 1.6664152e-1];
+
+// Like `math::sincos_reduced`, the last three parts of pi / 2 merged into one, which
+// keeps the reduction accurate while `|x| <= 8192`, the f64 fallback of `math` takes
+// over past it
+#[inline(always)]
+fn sincos_reduced<V: SimdVec<f32>>(ax: V) -> (V, V, math::Quadrant<V>) {
+    let j = ax
+        .simd_mul(V::splat(std::f32::consts::FRAC_2_PI))
+        .simd_round();
+
+    let minus_j = j.simd_neg();
+    let r = minus_j.simd_fma(V::splat(math::PIO2[0]), ax);
+    let r = minus_j.simd_fma(V::splat(math::PIO2[1]), r);
+    let r = minus_j.simd_fma(V::splat(PIO2_LO), r);
+
+    let r2 = r.simd_mul(r);
+
+    let p = r2.simd_fma(V::splat(SIN_P[0]), V::splat(SIN_P[1]));
+    let sin = r.simd_mul(r2).simd_fma(p, r);
+
+    let q = r2.simd_fma(V::splat(COS_P[0]), V::splat(COS_P[1]));
+    let cos = r2
+        .simd_mul(r2)
+        .simd_fma(q, r2.simd_fma(V::splat(-0.5), V::splat(1.0)));
+
+    (sin, cos, math::quadrant(j))
+}
+
+#[inline(always)]
+pub(crate) fn sin<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    math::trig_large(x, ax, math::sin_of(x, sincos_reduced(ax)), f64::sin)
+}
+
+#[inline(always)]
+pub(crate) fn cos<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    math::trig_large(x, ax, math::cos_of(sincos_reduced(ax)), f64::cos)
+}
+
+#[inline(always)]
+pub(crate) fn sincos<V: SimdVec<f32>>(x: V) -> (V, V) {
+    let ax = x.simd_abs();
+    let reduced = sincos_reduced(ax);
+
+    (
+        math::trig_large(x, ax, math::sin_of(x, reduced), f64::sin),
+        math::trig_large(x, ax, math::cos_of(reduced), f64::cos),
+    )
+}
+
+#[inline(always)]
+pub(crate) fn tan<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    math::trig_large(x, ax, math::tan_of(x, sincos_reduced(ax)), f64::tan)
+}
+
+/// The polynomials of the `Accuracy::Fast` kernels
+pub(crate) struct Fast;
+
+impl Polynomials for Fast {
+    #[inline(always)]
+    fn expm1<V: SimdVec<f32>>(r: V) -> V {
+        let p = r.simd_fma(V::splat(EXPM1_P[0]), V::splat(EXPM1_P[1]));
+        let p = r.simd_fma(p, V::splat(EXPM1_P[2]));
+        let p = r.simd_fma(p, V::splat(EXPM1_P[3]));
+
+        r.simd_mul(r).simd_fma(p, r)
+    }
+
+    #[inline(always)]
+    fn log<V: SimdVec<f32>>(f: V) -> V {
+        let p = f.simd_fma(V::splat(LOG_P[0]), V::splat(LOG_P[1]));
+        let p = f.simd_fma(p, V::splat(LOG_P[2]));
+        let p = f.simd_fma(p, V::splat(LOG_P[3]));
+
+        f.simd_fma(p, V::splat(LOG_P[4]))
+    }
+
+    #[inline(always)]
+    fn tanh<V: SimdVec<f32>>(s: V) -> V {
+        let p = s.simd_fma(V::splat(TANH_P[0]), V::splat(TANH_P[1]));
+
+        s.simd_fma(p, V::splat(TANH_P[2]))
+    }
+
+    #[inline(always)]
+    fn erf<V: SimdVec<f32>>(s: V) -> V {
+        let p = s.simd_fma(V::splat(ERF_P[0]), V::splat(ERF_P[1]));
+        let p = s.simd_fma(p, V::splat(ERF_P[2]));
+        let p = s.simd_fma(p, V::splat(ERF_P[3]));
+
+        s.simd_fma(p, V::splat(ERF_P[4]))
+    }
+
+    #[inline(always)]
+    fn erfc<V: SimdVec<f32>>(t: V) -> V {
+        let p = t.simd_fma(V::splat(ERFC_P[0]), V::splat(ERFC_P[1]));
+        let p = t.simd_fma(p, V::splat(ERFC_P[2]));
+        let p = t.simd_fma(p, V::splat(ERFC_P[3]));
+        let p = t.simd_fma(p, V::splat(ERFC_P[4]));
+        let p = t.simd_fma(p, V::splat(ERFC_P[5]));
+
+        t.simd_fma(p, V::splat(ERFC_P[6]))
+    }
+
+    #[inline(always)]
+    fn atan<V: SimdVec<f32>>(a: V) -> V {
+        let s = a.simd_mul(a);
+        let p = s.simd_fma(V::splat(ATAN_P[0]), V::splat(ATAN_P[1]));
+        let p = s.simd_fma(p, V::splat(ATAN_P[2]));
+        let p = s.simd_fma(p, V::splat(ATAN_P[3]));
+        let p = s.simd_fma(p, V::splat(ATAN_P[4]));
+
+        a.simd_mul(s).simd_fma(p, a)
+    }
+
+    #[inline(always)]
+    fn asin<V: SimdVec<f32>>(s: V) -> V {
+        let p = s.simd_fma(V::splat(ASIN_P[0]), V::splat(ASIN_P[1]));
+        let p = s.simd_fma(p, V::splat(ASIN_P[2]));
+
+        s.simd_fma(p, V::splat(ASIN_P[3]))
+    }
+
+    #[inline(always)]
+    fn sinh<V: SimdVec<f32>>(s: V) -> V {
+        s.simd_fma(V::splat(SINH_P[0]), V::splat(SINH_P[1]))
+    }
+}
+
+#[inline(always)]
+pub(crate) fn exp<V: SimdVec<f32>>(x: V) -> V {
+    math::exp_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn exp2<V: SimdVec<f32>>(x: V) -> V {
+    math::exp2_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn expm1<V: SimdVec<f32>>(x: V) -> V {
+    math::expm1_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn ln<V: SimdVec<f32>>(x: V) -> V {
+    math::ln_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn log2<V: SimdVec<f32>>(x: V) -> V {
+    math::log2_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn log10<V: SimdVec<f32>>(x: V) -> V {
+    math::log10_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn log1p<V: SimdVec<f32>>(x: V) -> V {
+    math::log1p_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn tanh<V: SimdVec<f32>>(x: V) -> V {
+    math::tanh_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn erf<V: SimdVec<f32>>(x: V) -> V {
+    math::erf_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn erfc<V: SimdVec<f32>>(x: V) -> V {
+    math::erfc_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn normal_cdf<V: SimdVec<f32>>(x: V) -> V {
+    math::normal_cdf_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn atan<V: SimdVec<f32>>(x: V) -> V {
+    math::atan_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn atan2<V: SimdVec<f32>>(y: V, x: V) -> V {
+    math::atan2_with::<Fast, V>(y, x)
+}
+
+#[inline(always)]
+pub(crate) fn asin<V: SimdVec<f32>>(x: V) -> V {
+    math::asin_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn acos<V: SimdVec<f32>>(x: V) -> V {
+    math::acos_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn sinh<V: SimdVec<f32>>(x: V) -> V {
+    math::sinh_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn cosh<V: SimdVec<f32>>(x: V) -> V {
+    math::cosh_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn asinh<V: SimdVec<f32>>(x: V) -> V {
+    math::asinh_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn acosh<V: SimdVec<f32>>(x: V) -> V {
+    math::acosh_with::<Fast, V>(x)
+}
+
+#[inline(always)]
+pub(crate) fn atanh<V: SimdVec<f32>>(x: V) -> V {
+    math::atanh_with::<Fast, V>(x)
+}
+
+// Polynomial of (sin(pi r) / r - pi) / r^2 in r^2 on [0, 1 / 2]
+const SINPI_P: [f32; 5] = [-7.084489e-3, 8.208372e-2, -5.99259e-1, 2.550164, -5.1677127];
+
+// sin(pi * x) for finite x, exact zeros at the integers
+#[inline(always)]
+fn sinpi<V: SimdVec<f32>>(x: V) -> V {
+    // x - n is exact
+    let n = x.simd_round();
+    let r = x.simd_sub(n);
+    let s = r.simd_mul(r);
+
+    let p = s.simd_fma(V::splat(SINPI_P[0]), V::splat(SINPI_P[1]));
+    let p = s.simd_fma(p, V::splat(SINPI_P[2]));
+    let p = s.simd_fma(p, V::splat(SINPI_P[3]));
+    let p = s.simd_fma(p, V::splat(SINPI_P[4]));
+    let y = r.simd_fma(
+        V::splat(math::PI_HI),
+        r.simd_mul(s.simd_fma(p, V::splat(math::PI_LO))),
+    );
+
+    // sin(pi (r + n)) = (-1)^n sin(pi r)
+    let half = n.simd_mul(V::splat(0.5));
+    let even = half.simd_floor().simd_eq(half);
+
+    even.simd_select(y, y.simd_neg())
+}
+
+// Polynomial of (gamma(1 + z) - 1) / z on [0, 1]
+const GAMMA_P: [f32; 11] = [
+    -1.5883496e-2,
+    1.0322309e-1,
+    -3.1052664e-1,
+    5.8838874e-1,
+    -8.1856394e-1,
+    9.400375e-1,
+    -9.716181e-1,
+    9.8050827e-1,
+    -9.074039e-1,
+    9.8905414e-1,
+    -5.772157e-1,
+];
+
+/// Gamma function, within 3.5 ulp for positive lanes and 6 ulp for negative ones
+///
+/// Without a fused multiply-add the product of the shifted arguments is not
+/// compensated, the error grows to 13 ulp on SSE for large `|x|`.
+#[inline(always)]
+pub(crate) fn tgamma<V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+
+    // gamma(x) = pi / (sin(pi x) gamma(1 - x)) for negative x, with gamma(1 - x)
+    // = y gamma(y) on y = -x, exact unlike 1 - x whose rounding gamma would amplify
+    let neg = x.simd_lt(V::splat(0.0));
+    let y = neg.simd_select(x.simd_neg(), x);
+    let limit = V::splat(math::GAMMA_LIMIT);
+    let y = limit.simd_lt(y).simd_select(limit, y);
+
+    // y = z + 1 + n with z in [0, 1), gamma(y) = gamma(1 + z) (z + 1) ... (z + n)
+    let floor = y.simd_floor();
+    let z = y.simd_sub(floor);
+    let n = floor.simd_sub(one);
+
+    let g = z.simd_fma(V::splat(GAMMA_P[0]), V::splat(GAMMA_P[1]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[2]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[3]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[4]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[5]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[6]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[7]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[8]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[9]));
+    let g = z.simd_fma(g, V::splat(GAMMA_P[10]));
+    let g = z.simd_fma(g, one);
+
+    // The products of the factors up to GAMMA_SPLIT and past it, each with the sum of
+    // its rounding errors carried along, which is exact with a fused multiply-add
+    let (mut p, mut c) = (one, V::splat(0.0));
+    let (mut q, mut d) = (one, V::splat(0.0));
+    let mut k = 1.0;
+
+    loop {
+        let active = V::splat(k).simd_le(n);
+
+        if !active.simd_any() {
+            break;
+        }
+
+        let a = active.simd_select(z.simd_add(V::splat(k)), one);
+
+        if k <= math::GAMMA_SPLIT {
+            let pa = p.simd_mul(a);
+            c = c.simd_fma(a, p.simd_fma(a, pa.simd_neg()));
+            p = pa;
+        } else {
+            let qa = q.simd_mul(a);
+            d = d.simd_fma(a, q.simd_fma(a, qa.simd_neg()));
+            q = qa;
+        }
+
+        k += 1.0;
+    }
+
+    let gp = g.simd_mul(p.simd_add(c));
+    let q = q.simd_add(d);
+
+    // gamma(y) = gamma(1 + y) / y below 1
+    let pos = y.simd_lt(one).simd_select(g.simd_div(y), gp.simd_mul(q));
+
+    if !neg.simd_any() {
+        return pos;
+    }
+
+    // y gamma(y) is gamma(1 + y) below 1
+    let s = sinpi(x);
+    let t = y.simd_lt(one).simd_select(g, y.simd_mul(gp));
+    let reflected = V::splat(std::f32::consts::PI)
+        .simd_div(s.simd_mul(t))
+        .simd_div(q);
+
+    // Poles at the negative integers
+    let reflected = s
+        .simd_eq(V::splat(0.0))
+        .simd_select(V::splat(f32::NAN), reflected);
+
+    neg.simd_select(reflected, pos)
+}
+
+// Polynomial of lgamma(1 + z) / z on [-1 / 2, 1 / 2]
+const LGAMMA1_P: [f32; 13] = [
+    -1.6729733e-1,
+    1.7973927e-1,
+    -5.8302138e-2,
+    6.530931e-2,
+    -1.1657937e-1,
+    1.3109551e-1,
+    -1.4365292e-1,
+    1.6913451e-1,
+    -2.073982e-1,
+    2.7059427e-1,
+    -4.0068552e-1,
+    8.224669e-1,
+    -5.772157e-1,
+];
+
+// Polynomial of lgamma(2 + z) / z on [-1 / 2, 1]
+const LGAMMA2_P: [f32; 10] = [
+    4.3576623e-5,
+    -2.0758525e-4,
+    5.3586316e-4,
+    -1.2100958e-3,
+    2.88788e-3,
+    -7.3820753e-3,
+    2.0580756e-2,
+    -6.735251e-2,
+    3.2246703e-1,
+    4.2278433e-1,
+];
+
+// 2^-24, below which lgamma(-y) rounds to -ln(y)
+const LGAMMA_TINY: f32 = 5.9604645e-8;
+
+// ln(2 pi) / 2 - 1 / 2 and the Stirling series of lgamma past 8, in 1 / x^2
+const LGAMMA_C: f32 = 4.1893853e-1;
+const STIRLING_P: [f32; 3] = [7.936508e-4, -2.7777778e-3, 8.3333336e-2];
+
+/// Logarithm of the absolute value of the gamma function, within 3 ulp for positive
+/// lanes
+///
+/// The error of the reflection is absolute near the zeros of lgamma below -2.
+#[inline(always)]
+pub(crate) fn lgamma<V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+
+    // lgamma(x) = ln(pi / |sin(pi x)|) - lgamma(1 - x) for negative x, with
+    // lgamma(1 - x) = lgamma(y) + ln(y) on y = -x, exact unlike 1 - x
+    let neg = x.simd_lt(V::splat(0.0));
+    let y = neg.simd_select(x.simd_neg(), x);
+
+    let below1 = y.simd_lt(V::splat(0.5));
+    let below2 = y.simd_lt(V::splat(1.5));
+    let below8 = y.simd_lt(V::splat(8.0));
+
+    // [3, 8) is brought down to [2, 3) with lgamma(w + 1) = lgamma(w) + ln(w)
+    let mut w = y;
+    let mut p = one;
+
+    for _ in 0..5 {
+        let active = below8.simd_and(V::splat(3.0).simd_le(w));
+        w = active.simd_select(w.simd_sub(one), w);
+        p = active.simd_select(p.simd_mul(w), p);
+    }
+
+    // A single logarithm, of y below 0.5 and past 8, of the product in between
+    let l = below8.simd_select(below1.simd_select(y, p), y).simd_ln();
+
+    // Below 1.5, z * P(z) around the root at 1, minus ln(y) below 0.5 where
+    // lgamma(y) = lgamma(1 + y) - ln(y)
+    let z = below1.simd_select(y, y.simd_sub(one));
+    let r = z.simd_fma(V::splat(LGAMMA1_P[0]), V::splat(LGAMMA1_P[1]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[2]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[3]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[4]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[5]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[6]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[7]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[8]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[9]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[10]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[11]));
+    let r = z.simd_fma(r, V::splat(LGAMMA1_P[12]));
+    let zr = z.simd_mul(r);
+    let r1 = zr.simd_add(below1.simd_select(l.simd_neg(), V::splat(0.0)));
+
+    // Up to 8, z * P(z) around the root at 2 plus the logarithm of the product
+    let z = w.simd_sub(V::splat(2.0));
+    let r = z.simd_fma(V::splat(LGAMMA2_P[0]), V::splat(LGAMMA2_P[1]));
+    let r = z.simd_fma(r, V::splat(LGAMMA2_P[2]));
+    let r = z.simd_fma(r, V::splat(LGAMMA2_P[3]));
+    let r = z.simd_fma(r, V::splat(LGAMMA2_P[4]));
+    let r = z.simd_fma(r, V::splat(LGAMMA2_P[5]));
+    let r = z.simd_fma(r, V::splat(LGAMMA2_P[6]));
+    let r = z.simd_fma(r, V::splat(LGAMMA2_P[7]));
+    let r = z.simd_fma(r, V::splat(LGAMMA2_P[8]));
+    let r = z.simd_fma(r, V::splat(LGAMMA2_P[9]));
+    let r2 = z.simd_fma(r, l);
+
+    // Past 8, Stirling's (y - 1 / 2) (ln(y) - 1) + ln(2 pi) / 2 - 1 / 2 + S(1 / y^2) / y
+    let v = one.simd_div(y);
+    let v2 = v.simd_mul(v);
+    let s = v2.simd_fma(V::splat(STIRLING_P[0]), V::splat(STIRLING_P[1]));
+    let s = v2.simd_fma(s, V::splat(STIRLING_P[2]));
+    let r3 = y
+        .simd_sub(V::splat(0.5))
+        .simd_fma(l.simd_sub(one), s.simd_fma(v, V::splat(LGAMMA_C)));
+
+    let pos = below2.simd_select(r1, below8.simd_select(r2, r3));
+
+    if !neg.simd_any() {
+        return pos;
+    }
+
+    // lgamma(1 + y) is at hand below 0.5, elsewhere y is folded into the logarithm,
+    // which stays finite as y is no longer tiny
+    let s = sinpi(x).simd_abs();
+    let d = below1.simd_select(s, s.simd_mul(y));
+    let reflected = V::splat(std::f32::consts::PI)
+        .simd_div(d)
+        .simd_ln()
+        .simd_sub(below1.simd_select(zr, pos));
+
+    // lgamma(x) = -ln(y) - euler_gamma x + O(x^2) is -ln(y) to f32 precision for tiny
+    // y, where pi / |sin(pi x)| would overflow
+    let reflected = y
+        .simd_lt(V::splat(LGAMMA_TINY))
+        .simd_select(l.simd_neg(), reflected);
+
+    // Poles at the negative integers, where pi / 0 may meet an infinite lgamma(1 - x)
+    let inf = V::splat(f32::INFINITY);
+    let reflected = s.simd_eq(V::splat(0.0)).simd_select(inf, reflected);
+    let reflected = inf.simd_neg().simd_eq(x).simd_select(inf, reflected);
+
+    neg.simd_select(reflected, pos)
+}
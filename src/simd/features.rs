@@ -0,0 +1,110 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+/// CPU features the kernels can take advantage of, detected at runtime
+///
+/// Features enabled at compile time (`-C target-cpu`, `-C target-feature`)
+/// are always reported, see `CpuFeatures::compiled`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CpuFeatures {
+    pub sse41: bool,
+    pub avx2: bool,
+    pub fma: bool,
+    pub f16c: bool,
+    pub bmi2: bool,
+    pub avx512f: bool,
+    pub avx512bw: bool,
+    pub avx512vl: bool,
+    pub avx512dq: bool,
+    pub neon: bool,
+    pub fp16: bool,
+    pub dotprod: bool,
+}
+
+// Features of the CPU running this process, detected once
+static DETECTED: OnceLock<CpuFeatures> = OnceLock::new();
+
+impl CpuFeatures {
+    /// Features of the CPU running this process
+    ///
+    /// Detection runs on the first call only, the result is cached.
+    #[inline(always)]
+    pub fn get() -> &'static CpuFeatures {
+        DETECTED.get_or_init(Self::detect)
+    }
+
+    /// Features guaranteed by the target at build time, from the `cpu_feature = "..."`
+    /// cfgs emitted by the build script
+    pub const fn compiled() -> CpuFeatures {
+        CpuFeatures {
+            sse41: cfg!(cpu_feature = "sse4.1"),
+            avx2: cfg!(cpu_feature = "avx2"),
+            fma: cfg!(cpu_feature = "fma"),
+            f16c: cfg!(cpu_feature = "f16c"),
+            bmi2: cfg!(cpu_feature = "bmi2"),
+            avx512f: cfg!(cpu_feature = "avx512f"),
+            avx512bw: cfg!(cpu_feature = "avx512bw"),
+            avx512vl: cfg!(cpu_feature = "avx512vl"),
+            avx512dq: cfg!(cpu_feature = "avx512dq"),
+            neon: cfg!(cpu_feature = "neon"),
+            fp16: cfg!(cpu_feature = "fp16"),
+            dotprod: cfg!(cpu_feature = "dotprod"),
+        }
+    }
+
+    /// Queries the CPU for every feature, bypassing the cache
+    pub fn detect() -> CpuFeatures {
+        #[allow(unused_mut)]
+        let mut features = CpuFeatures::compiled();
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            features.sse41 |= is_x86_feature_detected!("sse4.1");
+            features.avx2 |= is_x86_feature_detected!("avx2");
+            features.fma |= is_x86_feature_detected!("fma");
+            features.f16c |= is_x86_feature_detected!("f16c");
+            features.bmi2 |= is_x86_feature_detected!("bmi2");
+            features.avx512f |= is_x86_feature_detected!("avx512f");
+            features.avx512bw |= is_x86_feature_detected!("avx512bw");
+            features.avx512vl |= is_x86_feature_detected!("avx512vl");
+            features.avx512dq |= is_x86_feature_detected!("avx512dq");
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            features.neon |= std::arch::is_aarch64_feature_detected!("neon");
+            features.fp16 |= std::arch::is_aarch64_feature_detected!("fp16");
+            features.dotprod |= std::arch::is_aarch64_feature_detected!("dotprod");
+        }
+
+        features
+    }
+
+    /// Names of the detected features, as spelled by `target_feature`
+    pub fn names(&self) -> Vec<&'static str> {
+        [
+            (self.sse41, "sse4.1"),
+            (self.avx2, "avx2"),
+            (self.fma, "fma"),
+            (self.f16c, "f16c"),
+            (self.bmi2, "bmi2"),
+            (self.avx512f, "avx512f"),
+            (self.avx512bw, "avx512bw"),
+            (self.avx512vl, "avx512vl"),
+            (self.avx512dq, "avx512dq"),
+            (self.neon, "neon"),
+            (self.fp16, "fp16"),
+            (self.dotprod, "dotprod"),
+        ]
+        .into_iter()
+        .filter(|(detected, _)| *detected)
+        .map(|(_, name)| name)
+        .collect()
+    }
+}
+
+impl fmt::Display for CpuFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.names().join(","))
+    }
+}
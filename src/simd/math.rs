@@ -0,0 +1,2251 @@
+//! Transcendental functions written once against `SimdVec`, shared by every backend
+
+use super::utils::SimdVec;
+use crate::ops::dispatch::MAX_SIZE;
+
+/// The polynomials the `*_with` functions are built on, implemented by `fast_math::Fast`
+///
+/// Static functions rather than closures, which do not always inline into the
+/// `target_feature` kernels.
+pub(crate) trait Polynomials {
+    /// expm1(r) on [-ln 2 / 2, ln 2 / 2]
+    fn expm1<V: SimdVec<f32>>(r: V) -> V;
+
+    /// (ln(1 + f) - f + f^2 / 2) / f^3 on [sqrt(1 / 2) - 1, sqrt(2) - 1]
+    fn log<V: SimdVec<f32>>(f: V) -> V;
+
+    /// (tanh(x) / x - 1) / x^2 in s = x^2 on [0, 0.625^2]
+    fn tanh<V: SimdVec<f32>>(s: V) -> V;
+
+    /// erf(x) / x - 1 in s = x^2 on [0, 1]
+    fn erf<V: SimdVec<f32>>(s: V) -> V;
+
+    /// erfc(x) * exp(x^2) * (1 + 2 x) in t = (x - 2) / (x + 2) for x in [1 / 2, 10.5]
+    fn erfc<V: SimdVec<f32>>(t: V) -> V;
+
+    /// atan(a) on [0, 1]
+    fn atan<V: SimdVec<f32>>(a: V) -> V;
+
+    /// (asin(x) / x - 1) / x^2 in s = x^2 on [0, 1 / 2]
+    fn asin<V: SimdVec<f32>>(s: V) -> V;
+
+    /// (sinh(x) / x - 1) / x^2 in s = x^2 on [0, 1]
+    fn sinh<V: SimdVec<f32>>(s: V) -> V;
+}
+
+// Above this magnitude the Cody-Waite reduction loses accuracy, such lanes are
+// computed in f64 instead, one by one
+const TRIG_LIMIT: f32 = 8192.0;
+
+// pi / 2 split in five parts, the first four with 11 significant bits for `j * part`
+// to be exact while `j < 2^13`, even without a fused multiply-add
+pub(crate) const PIO2: [f32; 5] = [
+    1.5703125,
+    4.837513e-4,
+    7.5495336e-8,
+    2.563283e-12,
+    6.123234e-17,
+];
+
+// Polynomial of (sin(r) / r - 1) / r^2 in r^2 on [0, pi / 4], lowest degree first
+const SIN_P: [f32; 4] = [-1.6666667e-1, 8.333332e-3, -1.9840087e-4, 2.7249926e-6];
+
+// Minimax polynomial of cos on [-pi / 4, pi / 4], from Cephes
+const COS_P: [f32; 3] = [4.1666646e-2, -1.3887316e-3, 2.4433157e-5];
+
+// Lane masks of the quadrant `j mod 4` the argument was reduced from
+#[derive(Copy, Clone)]
+pub(crate) struct Quadrant<V> {
+    // Bit 0, sin and cos swap roles
+    odd: V,
+    // Bit 1, sin changes sign
+    high: V,
+    // Bit 0 xor bit 1, cos changes sign
+    cos_neg: V,
+}
+
+// The quadrant of the integer-valued `j`
+#[inline(always)]
+pub(crate) fn quadrant<V: SimdVec<f32>>(j: V) -> Quadrant<V> {
+    // The bits of j, exact since j < 2^24
+    let half = j.simd_mul(V::splat(0.5)).simd_floor();
+    let bit0 = half.simd_fma(V::splat(-2.0), j);
+    let bit1 = half
+        .simd_mul(V::splat(0.5))
+        .simd_floor()
+        .simd_fma(V::splat(-2.0), half);
+
+    let one = V::splat(1.0);
+
+    Quadrant {
+        odd: bit0.simd_eq(one),
+        high: bit1.simd_eq(one),
+        cos_neg: bit0.simd_add(bit1).simd_eq(one),
+    }
+}
+
+// `a + b` and its rounding error, whatever the magnitudes of `a` and `b`
+#[inline(always)]
+pub(crate) fn two_sum<V: SimdVec<f32>>(a: V, b: V) -> (V, V) {
+    let s = a.simd_add(b);
+    let bb = s.simd_sub(a);
+    let e = a.simd_sub(s.simd_sub(bb)).simd_add(b.simd_sub(bb));
+
+    (s, e)
+}
+
+// `a + b` and its rounding error, for `|a| >= |b|` or `a = 0`
+#[inline(always)]
+fn fast_two_sum<V: SimdVec<f32>>(a: V, b: V) -> (V, V) {
+    let s = a.simd_add(b);
+
+    (s, b.simd_sub(s.simd_sub(a)))
+}
+
+// sin(r) and cos(r) of the reduced argument, each as an unevaluated sum of a head
+// and a tail smaller than one ulp of it
+#[derive(Copy, Clone)]
+struct SinCos<V> {
+    sin: (V, V),
+    cos: (V, V),
+}
+
+// Reduces `ax >= 0` to `r = ax - j * pi / 2` in [-pi / 4, pi / 4], kept as a head
+// and a tail, returns sin(r), cos(r) and the quadrant of `j`
+#[inline(always)]
+fn sincos_parts<V: SimdVec<f32>>(ax: V) -> (SinCos<V>, Quadrant<V>) {
+    let j = ax
+        .simd_mul(V::splat(std::f32::consts::FRAC_2_PI))
+        .simd_round();
+
+    // Each `j * part` is exact, the first difference too, the rounding errors of
+    // the next ones are gathered in the tail
+    let minus_j = j.simd_neg();
+    let r = minus_j.simd_fma(V::splat(PIO2[0]), ax);
+    let (r, e1) = two_sum(r, minus_j.simd_mul(V::splat(PIO2[1])));
+    let (r, e2) = two_sum(r, minus_j.simd_mul(V::splat(PIO2[2])));
+    let (r, e3) = two_sum(r, minus_j.simd_mul(V::splat(PIO2[3])));
+    let tail = minus_j.simd_fma(V::splat(PIO2[4]), e1.simd_add(e2).simd_add(e3));
+    let (r, t) = fast_two_sum(r, tail);
+
+    let r2 = r.simd_mul(r);
+    let r2_lo = r.simd_mul_error(r);
+
+    // r + (t + r^3 * P(r^2)), t cos(r) - t is below the rounding, as are the
+    // rounding errors of r^3 past the first term of P, which is kept apart
+    let p = r2.simd_fma(V::splat(SIN_P[3]), V::splat(SIN_P[2]));
+    let p = r2.simd_fma(p, V::splat(SIN_P[1]));
+    let r3 = r.simd_mul(r2);
+    let r3_lo = r.simd_fma(r2_lo, r.simd_mul_error(r2));
+    let sin_tail = r3.simd_fma(
+        V::splat(SIN_P[0]),
+        r3.simd_mul(r2)
+            .simd_fma(p, r3_lo.simd_fma(V::splat(SIN_P[0]), t)),
+    );
+    let sin = fast_two_sum(r, sin_tail);
+
+    // 1 - h + r^4 * Q(r^2) with h = (r + t)^2 / 2, whose rounding from 1 - h is
+    // recovered as (1 - w) - h is exact
+    let q = r2.simd_fma(V::splat(COS_P[2]), V::splat(COS_P[1]));
+    let q = r2.simd_fma(q, V::splat(COS_P[0]));
+    let h = r2.simd_mul(V::splat(0.5));
+    let w = V::splat(1.0).simd_sub(h);
+    let h_lo = r.simd_fma(t, r2_lo.simd_mul(V::splat(0.5)));
+    let cos_tail = V::splat(1.0)
+        .simd_sub(w)
+        .simd_sub(h)
+        .simd_add(r2.simd_mul(r2).simd_fma(q, h_lo.simd_neg()));
+    let cos = fast_two_sum(w, cos_tail);
+
+    (SinCos { sin, cos }, quadrant(j))
+}
+
+// sin(r), cos(r) rounded once, and the quadrant of `j`, see `sincos_parts`
+#[inline(always)]
+fn sincos_reduced<V: SimdVec<f32>>(ax: V) -> (V, V, Quadrant<V>) {
+    let (parts, quadrant) = sincos_parts(ax);
+
+    (parts.sin.0, parts.cos.0, quadrant)
+}
+
+// (a + a_lo) / (b + b_lo) in two parts, the quotient of the heads and its
+// correction by the remainder
+#[inline(always)]
+fn div_pair<V: SimdVec<f32>>((a, a_lo): (V, V), (b, b_lo): (V, V)) -> (V, V) {
+    let q = a.simd_div(b);
+
+    // a - q b is exact, q being within a few ulp of a / b
+    let p = q.simd_mul(b);
+    let rem = a
+        .simd_sub(p)
+        .simd_sub(q.simd_mul_error(b))
+        .simd_add(q.simd_fma(b_lo.simd_neg(), a_lo));
+
+    (q, rem.simd_div(b))
+}
+
+// (a + a_lo) / (b + b_lo) rounded once, see `div_pair`
+#[inline(always)]
+fn div_parts<V: SimdVec<f32>>(a: (V, V), b: (V, V)) -> V {
+    let (q, q_lo) = div_pair(a, b);
+
+    q.simd_add(q_lo)
+}
+
+// sqrt(a + a_lo) in two parts for a >= 0, the tail is 0 where a is 0
+#[inline(always)]
+fn sqrt_parts<V: SimdVec<f32>>(a: V, a_lo: V) -> (V, V) {
+    let r = a.simd_sqrt();
+
+    // sqrt(a + a_lo) - r = (a - r^2 + a_lo) / (2 r), a - r * r is exact
+    let c = a
+        .simd_sub(r.simd_mul(r))
+        .simd_sub(r.simd_mul_error(r))
+        .simd_add(a_lo)
+        .simd_div(r.simd_add(r));
+    let zero = V::splat(0.0);
+
+    (r, zero.simd_lt(r).simd_select(c, zero))
+}
+
+// Negates the lanes of `y` where `x` has its sign bit set, odd functions of |x|
+#[inline(always)]
+pub(crate) fn xor_sign<V: SimdVec<f32>>(y: V, x: V) -> V {
+    y.simd_xor(x.simd_and(V::splat(-0.0)))
+}
+
+// Replaces the lanes of `y` selected by `lanes` by `f` of `x` computed in f64
+#[inline(always)]
+fn f64_lanes<V: SimdVec<f32>>(x: V, lanes: V, y: V, f: fn(f64) -> f64) -> V {
+    if !lanes.simd_any() {
+        return y;
+    }
+
+    let mut values = [0f32; MAX_SIZE];
+    unsafe { x.store_at(values.as_mut_ptr()) }
+
+    for value in &mut values[..V::SIZE] {
+        *value = f(*value as f64) as f32;
+    }
+
+    lanes.simd_select(V::new(&values[..V::SIZE]), y)
+}
+
+// Replaces the lanes with `|x| > TRIG_LIMIT` by `f` computed in f64
+#[inline(always)]
+pub(crate) fn trig_large<V: SimdVec<f32>>(x: V, ax: V, fast: V, f: fn(f64) -> f64) -> V {
+    f64_lanes(x, V::splat(TRIG_LIMIT).simd_lt(ax), fast, f)
+}
+
+/// Sine of `x` given sin(r), cos(r) and the quadrant of the reduction of |x|
+#[inline(always)]
+pub(crate) fn sin_of<V: SimdVec<f32>>(x: V, (s, c, quadrant): (V, V, Quadrant<V>)) -> V {
+    let y = quadrant.odd.simd_select(c, s);
+    let y = quadrant.high.simd_select(y.simd_neg(), y);
+
+    xor_sign(y, x)
+}
+
+/// Cosine given sin(r), cos(r) and the quadrant of the reduction of |x|
+#[inline(always)]
+pub(crate) fn cos_of<V: SimdVec<f32>>((s, c, quadrant): (V, V, Quadrant<V>)) -> V {
+    let y = quadrant.odd.simd_select(s, c);
+
+    quadrant.cos_neg.simd_select(y.simd_neg(), y)
+}
+
+/// Tangent of `x` given sin(r), cos(r) and the quadrant of the reduction of |x|
+#[inline(always)]
+pub(crate) fn tan_of<V: SimdVec<f32>>(x: V, (s, c, quadrant): (V, V, Quadrant<V>)) -> V {
+    // tan(r + pi / 2) = -cos(r) / sin(r)
+    let y = quadrant
+        .odd
+        .simd_select(c, s)
+        .simd_div(quadrant.odd.simd_select(s, c));
+    let y = quadrant.odd.simd_select(y.simd_neg(), y);
+
+    xor_sign(y, x)
+}
+
+/// Sine, see `SimdVec::simd_sin`
+#[inline(always)]
+pub(crate) fn sin<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    trig_large(x, ax, sin_of(x, sincos_reduced(ax)), f64::sin)
+}
+
+/// Cosine, see `SimdVec::simd_cos`
+#[inline(always)]
+pub(crate) fn cos<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    trig_large(x, ax, cos_of(sincos_reduced(ax)), f64::cos)
+}
+
+/// Sine and cosine from a single range reduction, see `SimdVec::simd_sincos`
+#[inline(always)]
+pub(crate) fn sincos<V: SimdVec<f32>>(x: V) -> (V, V) {
+    let ax = x.simd_abs();
+    let (s, c, quadrant) = sincos_reduced(ax);
+
+    let sin = sin_of(x, (s, c, quadrant));
+    let cos = cos_of((s, c, quadrant));
+
+    (
+        trig_large(x, ax, sin, f64::sin),
+        trig_large(x, ax, cos, f64::cos),
+    )
+}
+
+/// Tangent, see `SimdVec::simd_tan`
+#[inline(always)]
+pub(crate) fn tan<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    let (parts, quadrant) = sincos_parts(ax);
+
+    // tan(r + pi / 2) = -cos(r) / sin(r)
+    let sin = (
+        quadrant.odd.simd_select(parts.cos.0, parts.sin.0),
+        quadrant.odd.simd_select(parts.cos.1, parts.sin.1),
+    );
+    let cos = (
+        quadrant.odd.simd_select(parts.sin.0, parts.cos.0),
+        quadrant.odd.simd_select(parts.sin.1, parts.cos.1),
+    );
+    let y = div_parts(sin, cos);
+    let y = quadrant.odd.simd_select(y.simd_neg(), y);
+
+    trig_large(x, ax, xor_sign(y, x), f64::tan)
+}
+
+// ln 2 split in two parts, the first with 9 significant bits for `n * part` to be
+// exact while `|n| < 2^8`
+const LN2_HI: f32 = 0.6933594;
+const LN2_LO: f32 = -2.1219444e-4;
+
+// Minimax polynomial of (exp(r) - 1 - r) / r^2 on [-ln 2 / 2, ln 2 / 2], from Cephes
+const EXPM1_P: [f32; 7] = [
+    0.5,
+    1.6666667e-1,
+    4.1666668e-2,
+    8.333298e-3,
+    1.3888854e-3,
+    1.9899274e-4,
+    2.4859579e-5,
+];
+
+// Arguments are clamped to these bounds, past which the results round to 0 (-1 for
+// expm1) or overflow anyway
+const EXP_MIN: f32 = -104.0;
+const EXP_MAX: f32 = 89.0;
+const EXP2_MIN: f32 = -151.0;
+const EXP2_MAX: f32 = 129.0;
+const EXPM1_MIN: f32 = -30.0;
+
+// (exp(r) - 1 - r) / r^2 for |r| <= ln 2 / 2
+#[inline(always)]
+fn expm1_poly<V: SimdVec<f32>>(r: V) -> V {
+    let p = r.simd_fma(V::splat(EXPM1_P[6]), V::splat(EXPM1_P[5]));
+    let p = r.simd_fma(p, V::splat(EXPM1_P[4]));
+    let p = r.simd_fma(p, V::splat(EXPM1_P[3]));
+    let p = r.simd_fma(p, V::splat(EXPM1_P[2]));
+    let p = r.simd_fma(p, V::splat(EXPM1_P[1]));
+
+    r.simd_fma(p, V::splat(EXPM1_P[0]))
+}
+
+// Splits `x` into `n * ln 2 + r` with integer-valued `n` and `|r| <= ln 2 / 2`
+#[inline(always)]
+fn exp_reduce<V: SimdVec<f32>>(x: V) -> (V, V) {
+    let n = x.simd_mul(V::splat(std::f32::consts::LOG2_E)).simd_round();
+
+    let minus_n = n.simd_neg();
+    let r = minus_n.simd_fma(V::splat(LN2_HI), x);
+    let r = minus_n.simd_fma(V::splat(LN2_LO), r);
+
+    (n, r)
+}
+
+// Like `exp_reduce`, with `x = n * ln 2 + r + r_lo` where `r_lo` is the rounding
+// error of `r`
+#[inline(always)]
+fn exp_reduce_parts<V: SimdVec<f32>>(x: V) -> (V, V, V) {
+    let n = x.simd_mul(V::splat(std::f32::consts::LOG2_E)).simd_round();
+
+    let minus_n = n.simd_neg();
+    let r = minus_n.simd_fma(V::splat(LN2_HI), x);
+    let (r, e) = two_sum(r, minus_n.simd_mul(V::splat(LN2_LO)));
+
+    (n, r, e.simd_add(minus_n.simd_mul_error(V::splat(LN2_LO))))
+}
+
+// exp(r + r_lo) for |r| <= ln 2 / 2 and |r_lo| below an ulp of r, as a head and a
+// tail below an ulp of it
+#[inline(always)]
+fn exp_reduced_parts<V: SimdVec<f32>>(r: V, r_lo: V) -> (V, V) {
+    // 1 + r + (r^2 * P(r) + r_lo * (1 + r)), the rounding of 1 + r kept apart
+    let (hi, lo) = fast_two_sum(V::splat(1.0), r);
+
+    fast_two_sum(hi, expm1_tail(r, r_lo, lo))
+}
+
+// expm1(r + r_lo) - r + c for |r| <= ln 2 / 2 and a small `c`, rounded once
+#[inline(always)]
+fn expm1_tail<V: SimdVec<f32>>(r: V, r_lo: V, c: V) -> V {
+    let p = expm1_poly(r);
+    let c = r
+        .simd_mul_error(r)
+        .simd_fma(p, r_lo.simd_fma(r, r_lo.simd_add(c)));
+
+    r.simd_mul(r).simd_fma(p, c)
+}
+
+// `y * 2^n` for integer-valued `n` in [-252, 254], in two exact steps so that results
+// which overflow or turn subnormal are rounded once
+#[inline(always)]
+fn scale<V: SimdVec<f32>>(y: V, n: V) -> V {
+    let half = n.simd_mul(V::splat(0.5)).simd_floor();
+
+    y.simd_mul(half.simd_exp2i())
+        .simd_mul(n.simd_sub(half).simd_exp2i())
+}
+
+// exp(x) in two parts for `x` in [-87, 88], the tail below an ulp of the head
+#[inline(always)]
+fn exp_parts<V: SimdVec<f32>>(x: V) -> (V, V) {
+    let (n, r, r_lo) = exp_reduce_parts(x);
+    let (hi, lo) = exp_reduced_parts(r, r_lo);
+    let t = n.simd_exp2i();
+
+    (hi.simd_mul(t), lo.simd_mul(t))
+}
+
+/// Natural exponential, see `SimdVec::simd_exp`
+#[inline(always)]
+pub(crate) fn exp<V: SimdVec<f32>>(x: V) -> V {
+    let x = x.simd_clamp(V::splat(EXP_MIN), V::splat(EXP_MAX));
+    let (n, r, r_lo) = exp_reduce_parts(x);
+    let (hi, lo) = exp_reduced_parts(r, r_lo);
+
+    scale(hi.simd_add(lo), n)
+}
+
+/// Natural exponential with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn exp_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let x = x.simd_clamp(V::splat(EXP_MIN), V::splat(EXP_MAX));
+    let (n, r) = exp_reduce(x);
+
+    scale(P::expm1(r).simd_add(V::splat(1.0)), n)
+}
+
+// The rounding error of `std::f32::consts::LN_2`
+const LN_2_LO: f32 = -1.9046543e-9;
+
+/// Base 2 exponential, see `SimdVec::simd_exp2`
+#[inline(always)]
+pub(crate) fn exp2<V: SimdVec<f32>>(x: V) -> V {
+    let x = x.simd_clamp(V::splat(EXP2_MIN), V::splat(EXP2_MAX));
+
+    // x - n is exact, so is the rounding error of its product by ln 2
+    let n = x.simd_round();
+    let f = x.simd_sub(n);
+    let ln2 = V::splat(std::f32::consts::LN_2);
+    let r = f.simd_mul(ln2);
+    let r_lo = f.simd_fma(V::splat(LN_2_LO), f.simd_mul_error(ln2));
+    let (hi, lo) = exp_reduced_parts(r, r_lo);
+
+    scale(hi.simd_add(lo), n)
+}
+
+/// Base 2 exponential with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn exp2_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let x = x.simd_clamp(V::splat(EXP2_MIN), V::splat(EXP2_MAX));
+
+    // x - n is exact
+    let n = x.simd_round();
+    let r = x.simd_sub(n).simd_mul(V::splat(std::f32::consts::LN_2));
+
+    scale(P::expm1(r).simd_add(V::splat(1.0)), n)
+}
+
+/// `exp(x) - 1`, accurate near zero, see `SimdVec::simd_expm1`
+#[inline(always)]
+pub(crate) fn expm1<V: SimdVec<f32>>(x: V) -> V {
+    let x = x.simd_clamp(V::splat(EXPM1_MIN), V::splat(EXP_MAX));
+    let (n, r, r_lo) = exp_reduce_parts(x);
+
+    // Without reduction r + (r^2 * P(r) + r_lo * (1 + r))
+    let e = r.simd_add(expm1_tail(r, r_lo, V::splat(0.0)));
+
+    // 2^n * (hi + lo) - 1 = 2 * ((t * hi - 1 / 2) + t * lo) with t = 2^(n - 1), which
+    // stays finite for n = 128, t * hi and t * lo are exact
+    let (hi, lo) = exp_reduced_parts(r, r_lo);
+    let t = n.simd_sub(V::splat(1.0)).simd_exp2i();
+    let (y, y_lo) = two_sum(t.simd_mul(hi), V::splat(-0.5));
+    let y = y.simd_add(t.simd_fma(lo, y_lo)).simd_mul(V::splat(2.0));
+
+    // r + r^2 * P(r) turns -0 into +0
+    let zero = x.simd_eq(V::splat(0.0));
+    let unreduced = n.simd_eq(V::splat(0.0));
+
+    zero.simd_select(x, unreduced.simd_select(e, y))
+}
+
+/// `exp(x) - 1` with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn expm1_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let x = x.simd_clamp(V::splat(EXPM1_MIN), V::splat(EXP_MAX));
+    let (n, r) = exp_reduce(x);
+
+    let e = P::expm1(r);
+
+    // 2^n * (1 + e) - 1 = 2 * (t * e + t - 1 / 2) with t = 2^(n - 1), which stays
+    // finite for n = 128
+    let t = n.simd_sub(V::splat(1.0)).simd_exp2i();
+    let y = t
+        .simd_fma(e, t.simd_sub(V::splat(0.5)))
+        .simd_mul(V::splat(2.0));
+
+    // r + r^2 * P(r) turns -0 into +0
+    let zero = x.simd_eq(V::splat(0.0));
+    let unreduced = n.simd_eq(V::splat(0.0));
+
+    zero.simd_select(x, unreduced.simd_select(e, y))
+}
+
+// Minimax polynomial of (ln(1 + f) - f + f^2 / 2) / f^3 on [sqrt(1 / 2) - 1,
+// sqrt(2) - 1], from Cephes
+const LOG_P: [f32; 9] = [
+    3.333333e-1,
+    -2.4999994e-1,
+    2.0000714e-1,
+    -1.6668058e-1,
+    1.4249323e-1,
+    -1.2420141e-1,
+    1.16769987e-1,
+    -1.151461e-1,
+    7.0376836e-2,
+];
+
+// log2(e) - 1, added to the terms that are already exact in base e
+const LOG2_EA: f32 = 4.4269504e-1;
+
+// Rounding errors of the f32 log2(e) and log10(e)
+const LOG2_E_TAIL: f32 = 1.925963e-8;
+const LOG10_E_TAIL: f32 = -1.010305e-8;
+
+// log10(2) and log10(e) split in two parts, the first with 9 significant bits
+const LOG10_2_HI: f32 = 3.0078125e-1;
+const LOG10_2_LO: f32 = 2.4874566e-4;
+const LOG10_E_HI: f32 = 4.3359375e-1;
+const LOG10_E_LO: f32 = 7.007319e-4;
+
+// (ln(1 + f) - f + f^2 / 2) / f^3 on [sqrt(1 / 2) - 1, sqrt(2) - 1]
+#[inline(always)]
+fn log_poly<V: SimdVec<f32>>(f: V) -> V {
+    let p = f.simd_fma(V::splat(LOG_P[8]), V::splat(LOG_P[7]));
+    let p = f.simd_fma(p, V::splat(LOG_P[6]));
+    let p = f.simd_fma(p, V::splat(LOG_P[5]));
+    let p = f.simd_fma(p, V::splat(LOG_P[4]));
+    let p = f.simd_fma(p, V::splat(LOG_P[3]));
+    let p = f.simd_fma(p, V::splat(LOG_P[2]));
+    let p = f.simd_fma(p, V::splat(LOG_P[1]));
+
+    f.simd_fma(p, V::splat(LOG_P[0]))
+}
+
+// Splits `x > 0` into `2^e * (1 + f)` with integer-valued `e` and `1 + f` in
+// [sqrt(1 / 2), sqrt(2)), returns `e` and `f`
+#[inline(always)]
+fn log_split<V: SimdVec<f32>>(x: V) -> (V, V) {
+    // Subnormals are brought into the normal range first, 2^24 is exact
+    let tiny = x.simd_lt(V::splat(f32::MIN_POSITIVE));
+    let x = tiny.simd_select(x.simd_mul(V::splat(16777216.0)), x);
+    let e = x.simd_exponent();
+    let e = tiny.simd_select(e.simd_sub(V::splat(24.0)), e);
+
+    let m = x.simd_mantissa();
+    let big = V::splat(std::f32::consts::SQRT_2).simd_lt(m);
+    let m = big.simd_select(m.simd_mul(V::splat(0.5)), m);
+    let e = big.simd_select(e.simd_add(V::splat(1.0)), e);
+
+    // m - 1 is exact
+    (e, m.simd_sub(V::splat(1.0)))
+}
+
+// `log_split` with `y` such that `ln(1 + f) = f + y`
+#[inline(always)]
+fn log_reduce<P: Polynomials, V: SimdVec<f32>>(x: V) -> (V, V, V) {
+    let (e, f) = log_split(x);
+    let z = f.simd_mul(f);
+
+    // f^3 * P(f) - f^2 / 2
+    let y = z.simd_fma(V::splat(-0.5), f.simd_mul(z).simd_mul(P::log(f)));
+
+    (e, f, y)
+}
+
+// ln(1 + f) for f in [sqrt(1 / 2) - 1, sqrt(2) - 1] in two parts, whose sum is
+// accurate to about 2^-29 of the first
+#[inline(always)]
+fn log_reduced_parts<V: SimdVec<f32>>(f: V) -> (V, V) {
+    let z = f.simd_mul(f);
+    let z_lo = f.simd_mul_error(f);
+
+    // f - f^2 / 2 is exact as a pair, |f^2 / 2| <= |f| / 4
+    let (s, s_lo) = fast_two_sum(f, z.simd_mul(V::splat(-0.5)));
+    let y = f.simd_mul(z).simd_mul(log_poly(f));
+
+    (s, s_lo.simd_add(z_lo.simd_fma(V::splat(-0.5), y)))
+}
+
+// `log_split` with ln(1 + f) in two parts, see `log_reduced_parts`
+#[inline(always)]
+fn log_reduce_parts<V: SimdVec<f32>>(x: V) -> (V, V, V) {
+    let (e, f) = log_split(x);
+    let (s, s_lo) = log_reduced_parts(f);
+
+    (e, s, s_lo)
+}
+
+// Multiplies the pair `(a, a_lo)` by `c + c_lo`, returns the product in two parts
+#[inline(always)]
+fn mul_parts<V: SimdVec<f32>>((a, a_lo): (V, V), c: f32, c_lo: f32) -> (V, V) {
+    let p = a.simd_mul(V::splat(c));
+    let p_lo = a_lo.simd_mul(V::splat(c));
+    let p_lo = a.simd_fma(V::splat(c_lo), p_lo);
+
+    (p, a.simd_mul_error(V::splat(c)).simd_add(p_lo))
+}
+
+// e * ln(2) + ln(1 + f) from the parts of `log_reduce_parts`, in two parts
+#[inline(always)]
+fn ln_combine<V: SimdVec<f32>>(e: V, s: V, s_lo: V) -> (V, V) {
+    // e * LN2_HI is exact
+    let (z, z_lo) = two_sum(e.simd_mul(V::splat(LN2_HI)), s);
+
+    (z, e.simd_fma(V::splat(LN2_LO), z_lo.simd_add(s_lo)))
+}
+
+// ln(1 + x + x_lo) in two parts for `|x_lo|` well below the ulp of `x`, see
+// `log1p` for the special cases
+#[inline(always)]
+fn log1p_parts<V: SimdVec<f32>>(x: V, x_lo: V) -> (V, V) {
+    let one = V::splat(1.0);
+    let u = x.simd_add(one);
+
+    // Without reduction while 1 + x is in [sqrt(1 / 2), sqrt(2)], where the rounding
+    // of u would be of the order of the result for tiny x
+    let (y, y_lo) = log_reduced_parts(x);
+    let y_lo = x_lo.simd_div(u).simd_add(y_lo);
+    let small = V::splat(-0.29289323)
+        .simd_le(x)
+        .simd_and(x.simd_lt(V::splat(0.41421357)));
+
+    // ln(1 + x) = ln(u) + c / u with c the rounding error of u, computed exactly
+    let (e, s, s_lo) = log_reduce_parts(u);
+    let c = V::splat(2.0)
+        .simd_le(u)
+        .simd_select(one.simd_sub(u.simd_sub(x)), x.simd_sub(u.simd_sub(one)));
+    let (z, z_lo) = ln_combine(e, s, c.simd_add(x_lo).simd_div(u).simd_add(s_lo));
+
+    (small.simd_select(y, z), small.simd_select(y_lo, z_lo))
+}
+
+// Results of the logarithms of `x` where it is not a positive finite number
+#[inline(always)]
+fn log_special<V: SimdVec<f32>>(x: V, y: V) -> V {
+    let y = V::splat(f32::INFINITY).simd_eq(x).simd_select(x, y);
+    let y = x
+        .simd_eq(V::splat(0.0))
+        .simd_select(V::splat(f32::NEG_INFINITY), y);
+
+    V::splat(0.0).simd_le(x).simd_select(y, V::splat(f32::NAN))
+}
+
+/// Natural logarithm, see `SimdVec::simd_ln`
+#[inline(always)]
+pub(crate) fn ln<V: SimdVec<f32>>(x: V) -> V {
+    let (e, s, s_lo) = log_reduce_parts(x);
+    let (z, z_lo) = ln_combine(e, s, s_lo);
+
+    log_special(x, z.simd_add(z_lo))
+}
+
+/// Natural logarithm with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn ln_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let (e, f, y) = log_reduce::<P, V>(x);
+
+    let y = e.simd_fma(V::splat(LN2_LO), y);
+    let y = e.simd_fma(V::splat(LN2_HI), f.simd_add(y));
+
+    log_special(x, y)
+}
+
+/// Base 2 logarithm, see `SimdVec::simd_log2`
+#[inline(always)]
+pub(crate) fn log2<V: SimdVec<f32>>(x: V) -> V {
+    let (e, s, s_lo) = log_reduce_parts(x);
+
+    // e + ln(1 + f) * log2(e), e is exact so the sum is rounded once
+    let (p, p_lo) = mul_parts((s, s_lo), std::f32::consts::LOG2_E, LOG2_E_TAIL);
+    let (z, z_lo) = two_sum(e, p);
+
+    log_special(x, z.simd_add(z_lo.simd_add(p_lo)))
+}
+
+/// Base 2 logarithm with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn log2_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let (e, f, y) = log_reduce::<P, V>(x);
+
+    // (f + y) * log2(e) + e, smallest terms first
+    let z = y.simd_mul(V::splat(LOG2_EA));
+    let z = f.simd_fma(V::splat(LOG2_EA), z);
+    let z = z.simd_add(y).simd_add(f).simd_add(e);
+
+    log_special(x, z)
+}
+
+/// Base 10 logarithm, see `SimdVec::simd_log10`
+#[inline(always)]
+pub(crate) fn log10<V: SimdVec<f32>>(x: V) -> V {
+    let (e, s, s_lo) = log_reduce_parts(x);
+
+    // e * log10(2) + ln(1 + f) * log10(e), e * LOG10_2_HI is exact
+    let (p, p_lo) = mul_parts((s, s_lo), std::f32::consts::LOG10_E, LOG10_E_TAIL);
+    let (z, z_lo) = two_sum(e.simd_mul(V::splat(LOG10_2_HI)), p);
+    let z_lo = e.simd_fma(V::splat(LOG10_2_LO), z_lo.simd_add(p_lo));
+
+    log_special(x, z.simd_add(z_lo))
+}
+
+/// Base 10 logarithm with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn log10_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let (e, f, y) = log_reduce::<P, V>(x);
+
+    // (f + y) * log10(e) + e * log10(2), smallest terms first
+    let z = y.simd_mul(V::splat(LOG10_E_LO));
+    let z = f.simd_fma(V::splat(LOG10_E_LO), z);
+    let z = e.simd_fma(V::splat(LOG10_2_LO), z);
+    let z = y.simd_fma(V::splat(LOG10_E_HI), z);
+    let z = f.simd_fma(V::splat(LOG10_E_HI), z);
+    let z = e.simd_fma(V::splat(LOG10_2_HI), z);
+
+    log_special(x, z)
+}
+
+/// `ln(1 + x)`, accurate near zero, see `SimdVec::simd_log1p`
+#[inline(always)]
+pub(crate) fn log1p<V: SimdVec<f32>>(x: V) -> V {
+    let (z, z_lo) = log1p_parts(x, V::splat(0.0));
+
+    // z + z_lo turns -0 into +0
+    let zero = x.simd_eq(V::splat(0.0));
+
+    zero.simd_select(x, log_special(x.simd_add(V::splat(1.0)), z.simd_add(z_lo)))
+}
+
+/// `ln(1 + x)` with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn log1p_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+    let u = x.simd_add(one);
+    let (e, f, y) = log_reduce::<P, V>(u);
+
+    // ln(1 + x) = ln(u) + c / u with c the rounding error of u, computed exactly
+    let c = V::splat(2.0)
+        .simd_le(u)
+        .simd_select(one.simd_sub(u.simd_sub(x)), x.simd_sub(u.simd_sub(one)));
+    let y = y.simd_add(c.simd_div(u));
+
+    let y = e.simd_fma(V::splat(LN2_LO), y);
+    let y = e.simd_fma(V::splat(LN2_HI), f.simd_add(y));
+
+    // f + y turns -0 into +0
+    let zero = x.simd_eq(V::splat(0.0));
+
+    zero.simd_select(x, log_special(u, y))
+}
+
+// tanh rounds to +-1 past this magnitude, also keeps expm1 finite
+const TANH_LIMIT: f32 = 10.0;
+
+// Polynomial of (tanh(x) / x - 1) / x^2 in x^2 on [0, 0.625]
+const TANH_P: [f32; 6] = [
+    2.2927448e-3,
+    -8.343945e-3,
+    2.1768918e-2,
+    -5.3959258e-2,
+    1.3333304e-1,
+    -3.3333334e-1,
+];
+
+// (tanh(x) / x - 1) / x^2 in s = x^2 on [0, 0.625^2]
+#[inline(always)]
+fn tanh_poly<V: SimdVec<f32>>(s: V) -> V {
+    let p = s.simd_fma(V::splat(TANH_P[0]), V::splat(TANH_P[1]));
+    let p = s.simd_fma(p, V::splat(TANH_P[2]));
+    let p = s.simd_fma(p, V::splat(TANH_P[3]));
+    let p = s.simd_fma(p, V::splat(TANH_P[4]));
+
+    s.simd_fma(p, V::splat(TANH_P[5]))
+}
+
+/// Hyperbolic tangent, see `SimdVec::simd_tanh`
+#[inline(always)]
+pub(crate) fn tanh<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    // |x| + |x|^3 * P(x^2) while |x| < 0.625
+    let s = x.simd_mul(x);
+    let small = ax.simd_mul(s).simd_fma(tanh_poly(s), ax);
+
+    let large = V::splat(0.625).simd_le(ax);
+
+    if !large.simd_any() {
+        return xor_sign(small, x);
+    }
+
+    let limit = V::splat(TANH_LIMIT);
+    let ax = limit.simd_lt(ax).simd_select(limit, ax);
+
+    // tanh(|x|) = (exp(2 |x|) - 1) / (exp(2 |x|) + 1), both kept in two parts
+    let (e, e_lo) = exp_parts(ax.simd_add(ax));
+    let (n, n_lo) = two_sum(e, V::splat(-1.0));
+    let (d, d_lo) = two_sum(e, V::splat(1.0));
+    let y = div_parts((n, n_lo.simd_add(e_lo)), (d, d_lo.simd_add(e_lo)));
+
+    xor_sign(large.simd_select(y, small), x)
+}
+
+/// Hyperbolic tangent with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn tanh_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    // |x| + |x|^3 * P(x^2) while |x| < 0.625
+    let s = x.simd_mul(x);
+    let small = ax.simd_mul(s).simd_fma(P::tanh(s), ax);
+
+    let large = V::splat(0.625).simd_le(ax);
+
+    if !large.simd_any() {
+        return xor_sign(small, x);
+    }
+
+    let limit = V::splat(TANH_LIMIT);
+    let ax = limit.simd_lt(ax).simd_select(limit, ax);
+
+    // tanh(|x|) = expm1(2 |x|) / (expm1(2 |x|) + 2)
+    let e = expm1_with::<P, V>(ax.simd_add(ax));
+    let y = e.simd_div(e.simd_add(V::splat(2.0)));
+
+    xor_sign(large.simd_select(y, small), x)
+}
+
+// Polynomial of erf(x) / x - 1 in x^2 on [0, 1]
+const ERF_P: [f32; 8] = [
+    -9.667045e-6,
+    1.1265959e-4,
+    -8.4840803e-4,
+    5.2210316e-3,
+    -2.686543e-2,
+    1.1283782e-1,
+    -3.7612638e-1,
+    1.2837917e-1,
+];
+
+// Polynomial of erfc(x) * exp(x^2) * (1 + 2 x) in t = (x - 2) / (x + 2) on [0.5, 10.06],
+// the constant term last with its rounding error apart
+const ERFC_P: [f32; 12] = [
+    1.02294594e-4,
+    1.9421481e-4,
+    -4.2786862e-4,
+    -1.4496314e-3,
+    1.2644238e-3,
+    8.720921e-3,
+    -8.033146e-3,
+    -5.421982e-2,
+    1.6405123e-1,
+    -1.660304e-1,
+    -9.2763826e-2,
+    1.2769784,
+];
+const ERFC_P_LO: f32 = 8.068307e-9;
+
+// erfc rounds to 0 past this argument, also keeps t finite
+const ERFC_LIMIT: f32 = 10.5;
+
+// exp(-c * x^2) for ax >= 0 and c in {1, 1 / 2}, as exp(-c * m^2) * exp(-c * (2 m + f) * f)
+// with m = x rounded to a multiple of 1 / 128, c * m^2 is exact and the rounding
+// error of x^2 is kept out of the large exponent
+#[inline(always)]
+fn exp_neg_square<V: SimdVec<f32>>(ax: V, c: f32) -> V {
+    let m = ax
+        .simd_mul(V::splat(128.0))
+        .simd_round()
+        .simd_mul(V::splat(1.0 / 128.0));
+    let f = ax.simd_sub(m);
+
+    let c = V::splat(-c);
+    let e = m.simd_mul(m).simd_mul(c).simd_exp();
+    let g = m
+        .simd_add(m)
+        .simd_add(f)
+        .simd_mul(f)
+        .simd_mul(c)
+        .simd_expm1();
+
+    e.simd_fma(g, e)
+}
+
+// (erfc(x) * exp(x^2) * (1 + 2 x) - ERFC_P[11]) / t in t = (x - 2) / (x + 2)
+#[inline(always)]
+fn erfc_poly_tail<V: SimdVec<f32>>(t: V) -> V {
+    let p = t.simd_fma(V::splat(ERFC_P[0]), V::splat(ERFC_P[1]));
+    let p = t.simd_fma(p, V::splat(ERFC_P[2]));
+    let p = t.simd_fma(p, V::splat(ERFC_P[3]));
+    let p = t.simd_fma(p, V::splat(ERFC_P[4]));
+    let p = t.simd_fma(p, V::splat(ERFC_P[5]));
+    let p = t.simd_fma(p, V::splat(ERFC_P[6]));
+    let p = t.simd_fma(p, V::splat(ERFC_P[7]));
+    let p = t.simd_fma(p, V::splat(ERFC_P[8]));
+    let p = t.simd_fma(p, V::splat(ERFC_P[9]));
+
+    t.simd_fma(p, V::splat(ERFC_P[10]))
+}
+
+// (erf(x) / x - 1 - ERF_P[7]) / s in s = x^2
+#[inline(always)]
+fn erf_poly_tail<V: SimdVec<f32>>(s: V) -> V {
+    let p = s.simd_fma(V::splat(ERF_P[0]), V::splat(ERF_P[1]));
+    let p = s.simd_fma(p, V::splat(ERF_P[2]));
+    let p = s.simd_fma(p, V::splat(ERF_P[3]));
+    let p = s.simd_fma(p, V::splat(ERF_P[4]));
+    let p = s.simd_fma(p, V::splat(ERF_P[5]));
+
+    s.simd_fma(p, V::splat(ERF_P[6]))
+}
+
+// erf(x) - x for |x| < 1 in two parts, as x * P(x^2) with the rounding errors of
+// x^2 and of the last step of P kept
+#[inline(always)]
+fn erf_small_parts<V: SimdVec<f32>>(x: V) -> (V, V) {
+    let s = x.simd_mul(x);
+    let s_lo = x.simd_mul_error(x);
+
+    let q = erf_poly_tail(s);
+    let h = s.simd_mul(q);
+    let (p, p_lo) = two_sum(V::splat(ERF_P[7]), h);
+    let p_lo = p_lo.simd_add(s_lo.simd_fma(q, s.simd_mul_error(q)));
+
+    (x.simd_mul(p), x.simd_fma(p_lo, x.simd_mul_error(p)))
+}
+
+// erfc(z) for z = k * ax in [1 / 2, ERFC_LIMIT] given in two parts, and c = k^2 in
+// {1, 1 / 2}, as 2^n * (hi + lo) returned as (n, hi, lo)
+//
+// exp(-z^2) is taken from the exact square of ax, the rounding of z only reaches
+// P(t) / (1 + 2 z) where it is damped.
+#[inline(always)]
+fn erfc_parts<V: SimdVec<f32>>(ax: V, (az, az_lo): (V, V), c: f32) -> (V, V, V) {
+    let minus_c = V::splat(-c);
+    let s = ax.simd_mul(ax).simd_mul(minus_c);
+    let s_lo = ax.simd_mul_error(ax).simd_mul(minus_c);
+    let (n, r, r_lo) = exp_reduce_parts(s);
+
+    // s_lo is up to 2^-18, it is folded into r so that the tail stays below an ulp
+    let (r, r_lo) = fast_two_sum(r, r_lo.simd_add(s_lo));
+    let (e, e_lo) = exp_reduced_parts(r, r_lo);
+
+    // t in two parts, P(t + t_lo) = P(t) + P'(t) * t_lo with P' within 0.014 of
+    // 0.46 t^2 - 0.393 t - 0.0907, P(t) with its last step kept in two parts
+    let two = V::splat(2.0);
+    let (a, a_lo) = two_sum(az, two.simd_neg());
+    let (b, b_lo) = two_sum(az, two);
+    let (t, t_lo) = div_pair((a, a_lo.simd_add(az_lo)), (b, b_lo.simd_add(az_lo)));
+    let dp = t.simd_fma(V::splat(0.46), V::splat(-0.393));
+    let dp = t.simd_fma(dp, V::splat(-0.0907));
+
+    let q = erfc_poly_tail(t);
+    let (p, p_lo) = two_sum(V::splat(ERFC_P[11]), t.simd_mul(q));
+    let p_lo = p_lo
+        .simd_add(t.simd_mul_error(q))
+        .simd_add(dp.simd_fma(t_lo, V::splat(ERFC_P_LO)));
+
+    // 1 + 2 z, 2 z is exact and at least 1
+    let (d, d_lo) = fast_two_sum(az.simd_add(az), V::splat(1.0));
+    let (y, y_lo) = div_pair((p, p_lo), (d, az_lo.simd_fma(two, d_lo)));
+
+    let lo = e.simd_fma(y_lo, e.simd_mul_error(y));
+
+    (n, e.simd_mul(y), e_lo.simd_fma(y, lo))
+}
+
+// erfc(az) for 0 <= az <= ERFC_LIMIT given e = exp(-az^2), as e * P(t) / (1 + 2 az)
+#[inline(always)]
+fn erfc_tail<P: Polynomials, V: SimdVec<f32>>(az: V, e: V) -> V {
+    let two = V::splat(2.0);
+    let t = az.simd_sub(two).simd_div(az.simd_add(two));
+
+    e.simd_mul(P::erfc(t).simd_div(two.simd_fma(az, V::splat(1.0))))
+}
+
+// erfc(ax) for ax >= 0
+#[inline(always)]
+fn erfc_positive<P: Polynomials, V: SimdVec<f32>>(ax: V) -> V {
+    let limit = V::splat(ERFC_LIMIT);
+    let ax = limit.simd_lt(ax).simd_select(limit, ax);
+
+    erfc_tail::<P, V>(ax, exp_neg_square(ax, 1.0))
+}
+
+// erf(x) for |x| < 1, as x + x * P(x^2)
+#[inline(always)]
+fn erf_small<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    x.simd_fma(P::erf(x.simd_mul(x)), x)
+}
+
+/// Error function, see `SimdVec::simd_erf`
+#[inline(always)]
+pub(crate) fn erf<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    // |x| + (erf(|x|) - |x|) while |x| < 3 / 4, the second part is below |x| / 4
+    let (h, h_lo) = erf_small_parts(ax);
+    let (y, y_lo) = fast_two_sum(ax, h);
+    let small = y.simd_add(y_lo.simd_add(h_lo));
+
+    let large = V::splat(0.75).simd_le(ax);
+
+    if !large.simd_any() {
+        return xor_sign(small, x);
+    }
+
+    let limit = V::splat(ERFC_LIMIT);
+    let ax = limit.simd_lt(ax).simd_select(limit, ax);
+
+    // 1 - erfc(|x|), whose parts stay normal
+    let (n, hi, lo) = erfc_parts(ax, (ax, V::splat(0.0)), 1.0);
+    let (y, y_lo) = fast_two_sum(V::splat(1.0), scale(hi, n).simd_neg());
+    let y = y.simd_add(y_lo.simd_sub(scale(lo, n)));
+
+    xor_sign(large.simd_select(y, small), x)
+}
+
+/// Error function with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn erf_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let small = erf_small::<P, V>(x);
+
+    let ax = x.simd_abs();
+    let large = V::splat(1.0).simd_le(ax);
+
+    if !large.simd_any() {
+        return small;
+    }
+
+    let y = V::splat(1.0).simd_sub(erfc_positive::<P, V>(ax));
+
+    large.simd_select(xor_sign(y, x), small)
+}
+
+/// Complementary error function, see `SimdVec::simd_erfc`
+#[inline(always)]
+pub(crate) fn erfc<V: SimdVec<f32>>(x: V) -> V {
+    // 1 - x - (erf(x) - x) while |x| < 1 / 2
+    let (h, h_lo) = erf_small_parts(x);
+    let (a, a_lo) = two_sum(V::splat(1.0), x.simd_neg());
+    let (y, y_lo) = two_sum(a, h.simd_neg());
+    let small = y.simd_add(y_lo.simd_add(a_lo).simd_sub(h_lo));
+
+    let ax = x.simd_abs();
+    let large = V::splat(0.5).simd_le(ax);
+
+    if !large.simd_any() {
+        return small;
+    }
+
+    let limit = V::splat(ERFC_LIMIT);
+    let ax = limit.simd_lt(ax).simd_select(limit, ax);
+    let (n, hi, lo) = erfc_parts(ax, (ax, V::splat(0.0)), 1.0);
+
+    // erfc(-x) = 2 - erfc(x), the parts are scaled separately there as the
+    // result is at least 1
+    let (w, w_lo) = fast_two_sum(V::splat(2.0), scale(hi, n).simd_neg());
+    let w = w.simd_add(w_lo.simd_sub(scale(lo, n)));
+    let y = x
+        .simd_lt(V::splat(0.0))
+        .simd_select(w, scale(hi.simd_add(lo), n));
+
+    large.simd_select(y, small)
+}
+
+/// Complementary error function with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn erfc_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    // 1 - erf(x) loses little while |x| < 1 / 2, and is more accurate there than
+    // the product of erfc_positive
+    let small = V::splat(1.0).simd_sub(erf_small::<P, V>(x));
+
+    let ax = x.simd_abs();
+    let large = V::splat(0.5).simd_le(ax);
+
+    if !large.simd_any() {
+        return small;
+    }
+
+    let y = erfc_positive::<P, V>(ax);
+
+    // erfc(-x) = 2 - erfc(x)
+    let y = x
+        .simd_lt(V::splat(0.0))
+        .simd_select(V::splat(2.0).simd_sub(y), y);
+
+    large.simd_select(y, small)
+}
+
+// The rounding error of `std::f32::consts::FRAC_1_SQRT_2`
+const FRAC_1_SQRT_2_LO: f32 = 1.2101617e-8;
+
+/// Standard normal CDF `erfc(-x / sqrt(2)) / 2` as `2^n * (hi + lo)`, returned as
+/// `(n, hi, lo)` so that products of it are rounded once even in the left tail
+///
+/// Unlike `erfc` of the scaled argument, the rounding of `x / sqrt(2)` does not
+/// reach the exponential.
+#[inline(always)]
+pub(crate) fn normal_cdf_parts<V: SimdVec<f32>>(x: V) -> (V, V, V) {
+    let ax = x.simd_abs();
+    let limit = V::splat(ERFC_LIMIT * std::f32::consts::SQRT_2);
+    let ax = limit.simd_lt(ax).simd_select(limit, ax);
+
+    // |z| = |x| / sqrt(2) in two parts
+    let k = V::splat(std::f32::consts::FRAC_1_SQRT_2);
+    let az = ax.simd_mul(k);
+    let az_lo = ax.simd_fma(V::splat(FRAC_1_SQRT_2_LO), ax.simd_mul_error(k));
+
+    // (1 + erf(z)) / 2 while |z| < 1 / 2, with erf(z + z_lo) - erf(z) taken as
+    // 2 / sqrt(pi) * (1 - z^2) * z_lo
+    let (z, z_lo) = (xor_sign(az, x), xor_sign(az_lo, x));
+    let (h, h_lo) = erf_small_parts(z);
+    let d = V::splat(1.0)
+        .simd_sub(az.simd_mul(az))
+        .simd_mul(V::splat(std::f32::consts::FRAC_2_SQRT_PI));
+    let h_lo = d.simd_fma(z_lo, h_lo);
+    let half = V::splat(0.5);
+    let (a, a_lo) = fast_two_sum(half, z.simd_mul(half));
+    let (s, s_lo) = two_sum(a, h.simd_mul(half));
+    let s_lo = h_lo.simd_fma(half, s_lo.simd_add(a_lo));
+
+    let zero = V::splat(0.0);
+    let large = half.simd_le(az);
+
+    if !large.simd_any() {
+        return (zero, s, s_lo);
+    }
+
+    // erfc(|z|) / 2, Phi(x) = 1 - Phi(-x)
+    let (n, hi, lo) = erfc_parts(ax, (az, az_lo), 0.5);
+    let n = n.simd_sub(V::splat(1.0));
+    let (w, w_lo) = fast_two_sum(V::splat(1.0), scale(hi, n).simd_neg());
+    let w_lo = w_lo.simd_sub(scale(lo, n));
+
+    let positive = zero.simd_lt(x);
+    let n = positive.simd_select(zero, n);
+    let hi = positive.simd_select(w, hi);
+    let lo = positive.simd_select(w_lo, lo);
+
+    (
+        large.simd_select(n, zero),
+        large.simd_select(hi, s),
+        large.simd_select(lo, s_lo),
+    )
+}
+
+/// Standard normal CDF with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn normal_cdf_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let z = x.simd_mul(V::splat(std::f32::consts::FRAC_1_SQRT_2));
+    let half = V::splat(0.5);
+
+    // (1 + erf(z)) / 2 while |z| < 1 / 2
+    let small = half.simd_fma(erf_small::<P, V>(z), half);
+
+    let ax = x.simd_abs();
+    let limit = V::splat(ERFC_LIMIT * std::f32::consts::SQRT_2);
+    let ax = limit.simd_lt(ax).simd_select(limit, ax);
+    let az = ax.simd_mul(V::splat(std::f32::consts::FRAC_1_SQRT_2));
+    let large = half.simd_le(az);
+
+    if !large.simd_any() {
+        return small;
+    }
+
+    let y = erfc_tail::<P, V>(az, exp_neg_square(ax, 0.5)).simd_mul(half);
+
+    // Phi(x) = 1 - Phi(-x)
+    let y = V::splat(0.0)
+        .simd_lt(x)
+        .simd_select(V::splat(1.0).simd_sub(y), y);
+
+    large.simd_select(y, small)
+}
+
+// The logistic function and exp(x) round to 0 below this argument, even multiplied
+// by the largest arguments of the gated units, it also bounds the scaling of the parts
+const SIGMOID_MIN: f32 = -110.0;
+
+/// Logistic function `1 / (1 + exp(-k))` of `k` given in two parts, as
+/// `2^n * (hi + lo)` returned as `(n, hi, lo)`, see `mul_parts_scaled`
+///
+/// The tail of `k` reaches the reduced argument of the exponential, in the left
+/// tail its rounding would otherwise be magnified by `|k|`.
+#[inline(always)]
+pub(crate) fn sigmoid_parts<V: SimdVec<f32>>(k: V, k_lo: V) -> (V, V, V) {
+    let zero = V::splat(0.0);
+
+    // exp(-|k|) = 2^n * (e + e_lo)
+    let a = k
+        .simd_abs()
+        .simd_neg()
+        .simd_clamp(V::splat(SIGMOID_MIN), zero);
+    let (n, r, r_lo) = exp_reduce_parts(a);
+    let (r, r_lo) = two_sum(r, r_lo.simd_sub(xor_sign(k_lo, k)));
+    let (e, e_lo) = exp_reduced_parts(r, r_lo);
+
+    // 1 + exp(-|k|), the exponential is below the rounding of 1 where n is clamped
+    let t = n.simd_max(V::splat(-126.0)).simd_exp2i();
+    let (d, d_lo) = two_sum(V::splat(1.0), e.simd_mul(t));
+    let d_lo = e_lo.simd_fma(t, d_lo);
+
+    // 1 / (1 + exp(-k)) for positive k, exp(k) / (1 + exp(k)) elsewhere
+    let negative = k.simd_lt(zero);
+    let a = negative.simd_select(e, V::splat(1.0));
+    let a_lo = negative.simd_select(e_lo, zero);
+    let (hi, lo) = div_pair((a, a_lo), (d, d_lo));
+
+    (negative.simd_select(n, zero), hi, lo)
+}
+
+/// `a * 2^n * (hi + lo)` for the parts of `sigmoid_parts` or `normal_cdf_parts`,
+/// rounded once unless the result is subnormal
+#[inline(always)]
+pub(crate) fn mul_parts_scaled<V: SimdVec<f32>>(a: V, (n, hi, lo): (V, V, V)) -> V {
+    let y = a.simd_mul(hi);
+    let y = scale(y.simd_add(a.simd_fma(lo, a.simd_mul_error(hi))), n);
+
+    // The tail would turn -0 into +0
+    a.simd_eq(V::splat(0.0)).simd_select(a, y)
+}
+
+/// `ln(1 + exp(x))` as `max(x, 0) + ln(1 + exp(-|x|))`, the second term in two parts
+#[inline(always)]
+pub(crate) fn softplus<V: SimdVec<f32>>(x: V) -> V {
+    let zero = V::splat(0.0);
+
+    // exp(-|x|) = 2^n * (e + e_lo)
+    let a = x
+        .simd_abs()
+        .simd_neg()
+        .simd_clamp(V::splat(SIGMOID_MIN), zero);
+    let (n, r, r_lo) = exp_reduce_parts(a);
+    let (e, e_lo) = exp_reduced_parts(r, r_lo);
+
+    // The exponential is below the rounding of x where n is clamped
+    let t = n.simd_max(V::splat(-126.0)).simd_exp2i();
+    let u = e.simd_mul(t);
+    let (l, l_lo) = log1p_parts(u, e_lo.simd_mul(t));
+    let relu = x.simd_lt(zero).simd_select(zero, x);
+    let (y, y_lo) = two_sum(relu, l);
+    let y = y.simd_add(y_lo.simd_add(l_lo));
+
+    // exp(x) - exp(2 x) / 2 below -16, where it may be subnormal
+    let tail = u.simd_mul(V::splat(-0.5)).simd_fma(e, e_lo);
+    let y = x
+        .simd_lt(V::splat(-16.0))
+        .simd_select(scale(e.simd_add(tail), n), y);
+
+    V::splat(f32::INFINITY).simd_eq(x).simd_select(x, y)
+}
+
+// pi split in two parts for `r * pi` with a rounding error below the one of `r * PI_HI`
+pub(crate) const PI_HI: f32 = std::f32::consts::PI;
+pub(crate) const PI_LO: f32 = -8.742278e-8;
+
+// sin(pi r) / r in r^2 on [0, 1 / 4], the three lowest coefficients in two parts,
+// the others highest degree first
+const SINPI_HI: [f32; 3] = [std::f32::consts::PI, -5.1677127, 2.5501633];
+const SINPI_LO: [f32; 3] = [-8.787945e-8, -6.469678e-8, 0.0];
+const SINPI_P: [f32; 3] = [-7.028304e-3, 8.205035e-2, -5.9925216e-1];
+
+// gamma(3 / 2 + t) on [-1 / 2, 1 / 2], like `SINPI_HI` with three coefficients in
+// two parts
+const GAMMA_HI: [f32; 3] = [8.8622695e-1, 3.2338403e-2, 4.1481346e-1];
+const GAMMA_LO: [f32; 3] = [-2.6622798e-8, -1.6166437e-9, -7.7788735e-9];
+const GAMMA_P: [f32; 10] = [
+    7.4847164e-3,
+    -1.1218867e-2,
+    1.0731902e-2,
+    -1.6036829e-2,
+    2.596033e-2,
+    -3.8185973e-2,
+    5.860119e-2,
+    -7.750938e-2,
+    1.4464565e-1,
+    -1.0729524e-1,
+];
+
+// gamma overflows past 35.04 and the reflection underflows before -x reaches this,
+// also bounds the product loop
+pub(crate) const GAMMA_LIMIT: f32 = 44.0;
+
+// Factors of the product past which it is kept apart, (z + 24)! / z! still fits
+pub(crate) const GAMMA_SPLIT: f32 = 24.0;
+
+// Smallest |x| with a finite gamma(x), and the first overflowing x
+const GAMMA_MIN: f32 = 2.938737e-39;
+const GAMMA_MAX: f32 = 35.0401;
+
+// c + c_lo + t * (h + h_lo) in two parts, a step of a polynomial whose lowest
+// coefficients are split in two
+#[inline(always)]
+fn horner_parts<V: SimdVec<f32>>(t: V, (h, h_lo): (V, V), c: f32, c_lo: f32) -> (V, V) {
+    let p = t.simd_mul(h);
+    let (s, e) = two_sum(V::splat(c), p);
+    let lo = t.simd_mul_error(h).simd_add(e).simd_add(V::splat(c_lo));
+
+    (s, t.simd_fma(h_lo, lo))
+}
+
+// (a + a_lo) * (b + b_lo) in two parts, for products that do not overflow
+#[inline(always)]
+fn mul_pair<V: SimdVec<f32>>((a, a_lo): (V, V), (b, b_lo): (V, V)) -> (V, V) {
+    let p = a.simd_mul(b);
+
+    (
+        p,
+        a.simd_mul_error(b)
+            .simd_add(a.simd_fma(b_lo, a_lo.simd_mul(b))),
+    )
+}
+
+// The pair `a` in the lanes of `mask`, `b` in the others
+#[inline(always)]
+fn select_pair<V: SimdVec<f32>>(mask: V, (a, a_lo): (V, V), (b, b_lo): (V, V)) -> (V, V) {
+    (mask.simd_select(a, b), mask.simd_select(a_lo, b_lo))
+}
+
+// Polynomial of (atanh(u) / u - 1) / u^2 in u^2 on [0, 0.03], its Taylor series with
+// 1 / 3 in two parts
+const ATANH_P: [f32; 5] = [1.0 / 13.0, 1.0 / 11.0, 1.0 / 9.0, 1.0 / 7.0, 1.0 / 5.0];
+const THIRD_HI: f32 = 3.3333334e-1;
+const THIRD_LO: f32 = -9.934108e-9;
+
+// ln(a + a_lo) in two parts for finite a > 0, more accurate than `log_reduce_parts`
+// with ln(1 + f) = 2 atanh(u) on u = f / (2 + f), every term kept in two parts
+#[inline(always)]
+fn ln_pair<V: SimdVec<f32>>((a, a_lo): (V, V)) -> (V, V) {
+    let (e, f) = log_split(a);
+    let u = div_pair((f, V::splat(0.0)), two_sum(V::splat(2.0), f));
+
+    let w = u.0.simd_mul(u.0);
+    let h = w.simd_fma(V::splat(ATANH_P[0]), V::splat(ATANH_P[1]));
+    let h = w.simd_fma(h, V::splat(ATANH_P[2]));
+    let h = w.simd_fma(h, V::splat(ATANH_P[3]));
+    let h = w.simd_fma(h, V::splat(ATANH_P[4]));
+    let h = horner_parts(w, (h, V::splat(0.0)), THIRD_HI, THIRD_LO);
+
+    // u^2 in two parts with the cross term of the tail of u
+    let two = V::splat(2.0);
+    let w_lo = u.0.simd_mul(u.1).simd_fma(two, u.0.simd_mul_error(u.0));
+    let (t, t_lo) = mul_pair(mul_pair((w, w_lo), u), h);
+
+    // 2 u + 2 t, the doublings are exact
+    let (s, s_lo) = fast_two_sum(u.0.simd_mul(two), t.simd_mul(two));
+    let s_lo = u.1.simd_add(t_lo).simd_fma(two, s_lo);
+
+    ln_combine(e, s, a_lo.simd_div(a).simd_add(s_lo))
+}
+
+// sin(pi r) / r in two parts given r^2 = s + s_lo
+#[inline(always)]
+fn sinpi_parts<V: SimdVec<f32>>(s: V, s_lo: V) -> (V, V) {
+    let h = s.simd_fma(V::splat(SINPI_P[0]), V::splat(SINPI_P[1]));
+    let h = s.simd_fma(h, V::splat(SINPI_P[2]));
+    let h = horner_parts(s, (h, V::splat(0.0)), SINPI_HI[2], SINPI_LO[2]);
+    let h = horner_parts(s, h, SINPI_HI[1], SINPI_LO[1]);
+    let (u, u_lo) = horner_parts(s, h, SINPI_HI[0], SINPI_LO[0]);
+
+    // s_lo times the derivative, to first order
+    let d = s.simd_fma(V::splat(2.0 * SINPI_HI[2]), V::splat(SINPI_HI[1]));
+
+    (u, s_lo.simd_fma(d, u_lo))
+}
+
+// gamma(3 / 2 + t + t_lo) in two parts for t in [-1 / 2, 1 / 2]
+#[inline(always)]
+fn gamma_reduced_parts<V: SimdVec<f32>>(t: V, t_lo: V) -> (V, V) {
+    let h = t.simd_fma(V::splat(GAMMA_P[0]), V::splat(GAMMA_P[1]));
+    let h = t.simd_fma(h, V::splat(GAMMA_P[2]));
+    let h = t.simd_fma(h, V::splat(GAMMA_P[3]));
+    let h = t.simd_fma(h, V::splat(GAMMA_P[4]));
+    let h = t.simd_fma(h, V::splat(GAMMA_P[5]));
+    let h = t.simd_fma(h, V::splat(GAMMA_P[6]));
+    let h = t.simd_fma(h, V::splat(GAMMA_P[7]));
+    let h = t.simd_fma(h, V::splat(GAMMA_P[8]));
+    let h = t.simd_fma(h, V::splat(GAMMA_P[9]));
+    let h = horner_parts(t, (h, V::splat(0.0)), GAMMA_HI[2], GAMMA_LO[2]);
+    let h = horner_parts(t, h, GAMMA_HI[1], GAMMA_LO[1]);
+    let (g, g_lo) = horner_parts(t, h, GAMMA_HI[0], GAMMA_LO[0]);
+
+    // t_lo times the derivative, to first order
+    let d = t.simd_fma(V::splat(2.0 * GAMMA_HI[2]), V::splat(GAMMA_HI[1]));
+
+    (g, t_lo.simd_fma(d, g_lo))
+}
+
+/// Gamma function, see `SimdVec::simd_tgamma`
+#[inline(always)]
+pub(crate) fn tgamma<V: SimdVec<f32>>(x: V) -> V {
+    let zero = V::splat(0.0);
+    let one = V::splat(1.0);
+
+    // gamma(x) = pi / (sin(pi x) gamma(1 - x)) for negative x, with gamma(1 - x)
+    // = y gamma(y) on y = -x, exact unlike 1 - x whose rounding gamma would amplify
+    let neg = x.simd_lt(zero);
+    let y = x.simd_abs();
+    let limit = V::splat(GAMMA_LIMIT);
+    let y = limit.simd_lt(y).simd_select(limit, y);
+
+    // y = z + 1 + n with z in [0, 1), gamma(y) = gamma(1 + z) (z + 1) ... (z + n),
+    // below 1 the polynomial gives gamma(1 + y) instead
+    let floor = y.simd_floor();
+    let z = y.simd_sub(floor);
+    let n = floor.simd_sub(one);
+    let below1 = y.simd_lt(one);
+    let (t, t_lo) = two_sum(below1.simd_select(y, z), V::splat(-0.5));
+    let g = gamma_reduced_parts(t, t_lo);
+
+    // The products of the factors up to GAMMA_SPLIT and past it, in two parts, each
+    // factor z + k being exact
+    let (mut p, mut q) = ((one, zero), (one, zero));
+    let mut k = 1.0;
+
+    loop {
+        let active = V::splat(k).simd_le(n);
+
+        if !active.simd_any() {
+            break;
+        }
+
+        let a = (active.simd_select(z.simd_add(V::splat(k)), one), zero);
+
+        if k <= GAMMA_SPLIT {
+            p = mul_pair(p, a);
+        } else {
+            q = mul_pair(q, a);
+        }
+
+        k += 1.0;
+    }
+
+    let gp = mul_pair(g, p);
+    let (r, r_lo) = mul_pair(gp, q);
+
+    // gamma(y) = gamma(1 + y) / y below 1
+    let pos = below1.simd_select(div_parts(g, (y, zero)), r.simd_add(r_lo));
+    let pos = V::splat(GAMMA_MAX)
+        .simd_le(y)
+        .simd_select(V::splat(f32::INFINITY), pos);
+
+    let y = if neg.simd_any() {
+        // sin(pi x) = (-1)^m r U(r^2) with m = round(x) and r = x - m exact
+        let m = x.simd_round();
+        let r = x.simd_sub(m);
+        let u = sinpi_parts(r.simd_mul(r), r.simd_mul_error(r));
+
+        // pi / (U gamma(1 + y)) / (r q) keeps the large factors apart
+        let d = mul_pair(u, select_pair(below1, g, mul_pair(gp, (y, zero))));
+        let a = div_pair((V::splat(PI_HI), V::splat(PI_LO)), d);
+        let (h, h_lo) = div_pair(a, mul_pair(q, (r, zero)));
+        let reflected = h.simd_add(h_lo);
+
+        let half = m.simd_mul(V::splat(0.5));
+        let even = half.simd_floor().simd_eq(half);
+        let reflected = even.simd_select(reflected, reflected.simd_neg());
+
+        // Poles at the negative integers
+        let reflected = r.simd_eq(zero).simd_select(V::splat(f32::NAN), reflected);
+
+        neg.simd_select(reflected, pos)
+    } else {
+        pos
+    };
+
+    // 1 / x overflows near zero, whose sign it keeps
+    let y = x
+        .simd_abs()
+        .simd_lt(V::splat(GAMMA_MIN))
+        .simd_select(xor_sign(V::splat(f32::INFINITY), x), y);
+
+    x.simd_eq(x).simd_select(y, x)
+}
+
+// lgamma(1 + z) / z on [-1 / 2, 1 / 2], like `SINPI_HI` with four coefficients in
+// two parts
+const LGAMMA1_HI: [f32; 4] = [-5.772157e-1, 8.22467e-1, -4.0068567e-1, 2.7058086e-1];
+const LGAMMA1_LO: [f32; 4] = [6.682476e-9, 4.1581214e-9, -2.8830747e-9, -1.3589773e-8];
+const LGAMMA1_P: [f32; 12] = [
+    1.6680773e-1,
+    -1.7667657e-1,
+    2.100029e-2,
+    -2.3789736e-2,
+    9.4955094e-2,
+    -1.0317368e-1,
+    9.867301e-2,
+    -1.0983213e-1,
+    1.2560315e-1,
+    -1.4414832e-1,
+    1.695542e-1,
+    -2.0738243e-1,
+];
+
+// lgamma(2 + z) / z on [-1 / 2, 1], like `SINPI_HI` with three coefficients in two
+// parts
+const LGAMMA2_HI: [f32; 3] = [4.2278433e-1, 3.2246703e-1, -6.7352295e-2];
+const LGAMMA2_LO: [f32; 3] = [6.5555326e-9, 3.913433e-9, -4.6413373e-10];
+const LGAMMA2_P: [f32; 9] = [
+    7.5093335e-6,
+    -3.9087714e-5,
+    1.04949075e-4,
+    -2.2923607e-4,
+    5.0985406e-4,
+    -1.1912553e-3,
+    2.8902323e-3,
+    -7.3856954e-3,
+    2.0580838e-2,
+];
+
+// ln(2 pi) / 2 - 1 / 2 in two parts and the Stirling series of lgamma past 8, in
+// 1 / x^2
+const LGAMMA_C_HI: f32 = 4.1893855e-1;
+const LGAMMA_C_LO: f32 = -1.4168145e-8;
+const STIRLING_P: [f32; 3] = [7.936508e-4, -2.7777778e-3, 8.3333336e-2];
+
+// The first overflowing x
+const LGAMMA_MAX: f32 = 4.0850034e36;
+
+// Reflected lanes whose result is below this fraction of ln(pi / |sin(pi x)|), near
+// the zeros of lgamma, are computed in f64
+const LGAMMA_CANCEL: f32 = 0.0625;
+
+// z * lgamma(1 + z) / z in two parts for z in [-1 / 2, 1 / 2]
+#[inline(always)]
+fn lgamma1_parts<V: SimdVec<f32>>(z: V) -> (V, V) {
+    let h = z.simd_fma(V::splat(LGAMMA1_P[0]), V::splat(LGAMMA1_P[1]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[2]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[3]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[4]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[5]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[6]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[7]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[8]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[9]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[10]));
+    let h = z.simd_fma(h, V::splat(LGAMMA1_P[11]));
+    let h = horner_parts(z, (h, V::splat(0.0)), LGAMMA1_HI[3], LGAMMA1_LO[3]);
+    let h = horner_parts(z, h, LGAMMA1_HI[2], LGAMMA1_LO[2]);
+    let h = horner_parts(z, h, LGAMMA1_HI[1], LGAMMA1_LO[1]);
+    let h = horner_parts(z, h, LGAMMA1_HI[0], LGAMMA1_LO[0]);
+
+    mul_pair(h, (z, V::splat(0.0)))
+}
+
+// z * lgamma(2 + z) / z in two parts for z in [-1 / 2, 1]
+#[inline(always)]
+fn lgamma2_parts<V: SimdVec<f32>>(z: V) -> (V, V) {
+    let h = z.simd_fma(V::splat(LGAMMA2_P[0]), V::splat(LGAMMA2_P[1]));
+    let h = z.simd_fma(h, V::splat(LGAMMA2_P[2]));
+    let h = z.simd_fma(h, V::splat(LGAMMA2_P[3]));
+    let h = z.simd_fma(h, V::splat(LGAMMA2_P[4]));
+    let h = z.simd_fma(h, V::splat(LGAMMA2_P[5]));
+    let h = z.simd_fma(h, V::splat(LGAMMA2_P[6]));
+    let h = z.simd_fma(h, V::splat(LGAMMA2_P[7]));
+    let h = z.simd_fma(h, V::splat(LGAMMA2_P[8]));
+    let h = horner_parts(z, (h, V::splat(0.0)), LGAMMA2_HI[2], LGAMMA2_LO[2]);
+    let h = horner_parts(z, h, LGAMMA2_HI[1], LGAMMA2_LO[1]);
+    let h = horner_parts(z, h, LGAMMA2_HI[0], LGAMMA2_LO[0]);
+
+    mul_pair(h, (z, V::splat(0.0)))
+}
+
+// a + b in two parts for pairs that do not cancel each other
+#[inline(always)]
+fn add_pair<V: SimdVec<f32>>((a, a_lo): (V, V), (b, b_lo): (V, V)) -> (V, V) {
+    let (s, e) = two_sum(a, b);
+
+    (s, e.simd_add(a_lo).simd_add(b_lo))
+}
+
+/// Logarithm of the absolute value of the gamma function, see `SimdVec::simd_lgamma`
+#[inline(always)]
+pub(crate) fn lgamma<V: SimdVec<f32>>(x: V) -> V {
+    let zero = V::splat(0.0);
+    let one = V::splat(1.0);
+    let inf = V::splat(f32::INFINITY);
+
+    // lgamma(x) = ln(pi / |sin(pi x)|) - lgamma(1 - x) for negative x, with
+    // lgamma(1 - x) = lgamma(y) + ln(y) on y = -x, exact unlike 1 - x
+    let neg = x.simd_lt(zero);
+    let y = x.simd_abs();
+
+    let below1 = y.simd_lt(V::splat(0.5));
+    let below2 = y.simd_lt(V::splat(1.5));
+    let below8 = y.simd_lt(V::splat(8.0));
+
+    // [3, 8) is brought down to [2, 3) with lgamma(w + 1) = lgamma(w) + ln(w), the
+    // product in two parts
+    let mut w = y;
+    let mut p = (one, zero);
+
+    for _ in 0..5 {
+        let active = below8.simd_and(V::splat(3.0).simd_le(w));
+        w = active.simd_select(w.simd_sub(one), w);
+        p = select_pair(active, mul_pair(p, (w, zero)), p);
+    }
+
+    // A single logarithm, of y below 0.5 and past 8, of the product in between
+    let l = ln_pair((below8.simd_select(below1.simd_select(y, p.0), y), p.1));
+    let minus_l = (l.0.simd_neg(), l.1.simd_neg());
+
+    // Below 1.5, z L1(z) around the root at 1, minus ln(y) below 0.5 where
+    // lgamma(y) = lgamma(1 + y) - ln(y)
+    let a = lgamma1_parts(below1.simd_select(y, y.simd_sub(one)));
+    let r1 = add_pair(a, select_pair(below1, minus_l, (zero, zero)));
+
+    // Up to 8, z L2(z) around the root at 2 plus the logarithm of the product
+    let r2 = add_pair(lgamma2_parts(w.simd_sub(V::splat(2.0))), l);
+
+    // Past 8, Stirling's (y - 1 / 2) (ln(y) - 1) + ln(2 pi) / 2 - 1 / 2 + S(1 / y^2) / y
+    let v = one.simd_div(y);
+    let v2 = v.simd_mul(v);
+    let s = v2.simd_fma(V::splat(STIRLING_P[0]), V::splat(STIRLING_P[1]));
+    let s = v2.simd_fma(s, V::splat(STIRLING_P[2]));
+    let c = (s.simd_fma(v, V::splat(LGAMMA_C_HI)), V::splat(LGAMMA_C_LO));
+    let m = add_pair(l, (V::splat(-1.0), zero));
+    let r3 = add_pair(mul_pair(two_sum(y, V::splat(-0.5)), m), c);
+
+    let (h, h_lo) = select_pair(below2, r1, select_pair(below8, r2, r3));
+    let pos = V::splat(LGAMMA_MAX)
+        .simd_le(y)
+        .simd_select(inf, h.simd_add(h_lo));
+    let pos = y.simd_eq(zero).simd_select(inf, pos);
+
+    let y = if neg.simd_any() {
+        // |sin(pi x)| = |r| U(r^2) with r = x - round(x) exact
+        let r = x.simd_sub(x.simd_round());
+        let u = sinpi_parts(r.simd_mul(r), r.simd_mul_error(r));
+
+        // lgamma(1 + y) is at hand below 0.5, where y^2 may underflow, elsewhere
+        // |r| y is folded into the logarithm
+        let ar = r.simd_abs();
+        let ry = (ar.simd_mul(y), ar.simd_mul_error(y));
+        let d = mul_pair(u, select_pair(below1, (one, zero), ry));
+        let t = ln_pair(div_pair((V::splat(PI_HI), V::splat(PI_LO)), d));
+        let (b, b_lo) = select_pair(below1, add_pair(a, l), (h, h_lo));
+        let (e, e_lo) = add_pair(t, (b.simd_neg(), b_lo.simd_neg()));
+        let reflected = e.simd_add(e_lo);
+
+        // The difference loses the accuracy of its terms near the zeros of lgamma
+        let cancel = e
+            .simd_abs()
+            .simd_lt(t.0.simd_abs().simd_mul(V::splat(LGAMMA_CANCEL)));
+        let reflected = f64_lanes(x, cancel, reflected, crate::ops::accuracy::libm::lgamma);
+
+        // Poles at the negative integers and -inf
+        let reflected = r.simd_eq(zero).simd_select(inf, reflected);
+        let reflected = inf.simd_neg().simd_eq(x).simd_select(inf, reflected);
+
+        neg.simd_select(reflected, pos)
+    } else {
+        pos
+    };
+
+    x.simd_eq(x).simd_select(y, x)
+}
+
+// pi / 2 split in two parts like PI_HI and PI_LO
+const PI_2_HI: f32 = std::f32::consts::FRAC_PI_2;
+const PI_2_LO: f32 = -4.371139e-8;
+
+// Polynomial of (atan(x) / x - 1) / x^2 in x^2 on [0, 1]
+const ATAN_P: [f32; 13] = [
+    -3.778858e-4,
+    2.8754857e-3,
+    -1.0257166e-2,
+    2.3248374e-2,
+    -3.876702e-2,
+    5.2943908e-2,
+    -6.4870626e-2,
+    7.654295e-2,
+    -9.085624e-2,
+    1.11106664e-1,
+    -1.4285694e-1,
+    2.0e-1,
+    -3.3333334e-1,
+];
+
+// (atan(x) / x - 1 - ATAN_P[12]) / s in s = x^2
+#[inline(always)]
+fn atan_poly_tail<V: SimdVec<f32>>(s: V) -> V {
+    let p = s.simd_fma(V::splat(ATAN_P[0]), V::splat(ATAN_P[1]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[2]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[3]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[4]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[5]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[6]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[7]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[8]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[9]));
+    let p = s.simd_fma(p, V::splat(ATAN_P[10]));
+
+    s.simd_fma(p, V::splat(ATAN_P[11]))
+}
+
+// a + a^3 * (c + s * q) in two parts for s = a^2 and s_lo its rounding error, with
+// |a^3 * (c + s * q)| below |a| / 2, the rounding errors of a^3 and of the last
+// step of the polynomial kept
+#[inline(always)]
+fn odd_poly_parts<V: SimdVec<f32>>(a: V, (s, s_lo): (V, V), q: V, c: f32) -> (V, V) {
+    let h = s.simd_mul(q);
+    let (p, p_lo) = two_sum(V::splat(c), h);
+    let p_lo = p_lo.simd_add(s_lo.simd_fma(q, s.simd_mul_error(q)));
+
+    let m = a.simd_mul(s);
+    let m_lo = a.simd_fma(s_lo, a.simd_mul_error(s));
+    let u = m.simd_mul(p);
+    let u_lo = m.simd_fma(p_lo, m.simd_mul_error(p));
+    let u_lo = m_lo.simd_fma(p, u_lo);
+
+    let (y, y_lo) = fast_two_sum(a, u);
+
+    (y, y_lo.simd_add(u_lo))
+}
+
+// atan(a + a_lo) for a in [0, 1] in two parts, atan'(a) * a_lo added to the tail
+#[inline(always)]
+fn atan_parts<V: SimdVec<f32>>(a: V, a_lo: V) -> (V, V) {
+    let s = a.simd_mul(a);
+    let s_lo = a.simd_mul_error(a);
+    let (y, y_lo) = odd_poly_parts(a, (s, s_lo), atan_poly_tail(s), ATAN_P[12]);
+
+    (y, y_lo.simd_add(a_lo.simd_div(V::splat(1.0).simd_add(s))))
+}
+
+/// Arctangent, see `SimdVec::simd_atan`
+#[inline(always)]
+pub(crate) fn atan<V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+    let zero = V::splat(0.0);
+    let ax = x.simd_abs();
+
+    // atan(|x|) = pi / 2 - atan(1 / |x|) past 1, 1 / |x| kept in two parts
+    let inv = one.simd_lt(ax);
+    let (q, q_lo) = div_pair((one, zero), (ax, zero));
+    let a = inv.simd_select(q, ax);
+    let a_lo = inv.simd_and(q_lo.simd_eq(q_lo)).simd_select(q_lo, zero);
+    let (r, r_lo) = atan_parts(a, a_lo);
+
+    let (w, w_lo) = fast_two_sum(V::splat(PI_2_HI), r.simd_neg());
+    let w = w.simd_add(V::splat(PI_2_LO).simd_add(w_lo.simd_sub(r_lo)));
+    let y = inv.simd_select(w, r.simd_add(r_lo));
+
+    xor_sign(y, x)
+}
+
+/// Arctangent with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn atan_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+    let ax = x.simd_abs();
+
+    // atan(|x|) = pi / 2 - atan(1 / |x|) past 1
+    let inv = one.simd_lt(ax);
+    let r = P::atan(inv.simd_select(one.simd_div(ax), ax));
+    let y = inv.simd_select(V::splat(PI_2_HI).simd_add(V::splat(PI_2_LO).simd_sub(r)), r);
+
+    xor_sign(y, x)
+}
+
+/// Four-quadrant arctangent of `y / x`, see `SimdVec::simd_atan2`
+#[inline(always)]
+pub(crate) fn atan2<V: SimdVec<f32>>(y: V, x: V) -> V {
+    let zero = V::splat(0.0);
+    let one = V::splat(1.0);
+    let ax = x.simd_abs();
+    let ay = y.simd_abs();
+
+    // The ratio of the smaller magnitude to the larger one in two parts, exactly 1
+    // for two infinities and 0 for two zeros, its tail is NaN past an infinity
+    let lo = ax.simd_min(ay);
+    let hi = ax.simd_max(ay);
+    let (a, a_lo) = div_pair((lo, zero), (hi, zero));
+    let equal = lo.simd_eq(hi);
+    let a = equal.simd_select(one, a);
+    let a_lo = equal.simd_select(zero, a_lo);
+    let both_zero = hi.simd_eq(zero);
+    let a = both_zero.simd_select(zero, a);
+    let a_lo = a_lo.simd_eq(a_lo).simd_select(a_lo, zero);
+    let (r, r_lo) = atan_parts(a, both_zero.simd_select(zero, a_lo));
+
+    // From the first octant to the one of (x, |y|): |y| > |x| reflects r around
+    // pi / 4, to pi / 2 - r, and x with its sign bit set around pi / 2, to pi - r
+    let swap = ax.simd_lt(ay);
+    let neg = xor_sign(one, x).simd_lt(zero);
+    let minus = swap.simd_xor(neg);
+    let (m, m_lo) = (
+        minus.simd_select(r.simd_neg(), r),
+        minus.simd_select(r_lo.simd_neg(), r_lo),
+    );
+    let base_hi = neg.simd_select(V::splat(PI_HI), zero);
+    let base_lo = neg.simd_select(V::splat(PI_LO), zero);
+    let base_hi = swap.simd_select(V::splat(PI_2_HI), base_hi);
+    let base_lo = swap.simd_select(V::splat(PI_2_LO), base_lo);
+    let (t, t_lo) = two_sum(base_hi, m);
+    let t = t.simd_add(t_lo.simd_add(base_lo.simd_add(m_lo)));
+
+    // min and max drop NaN operands
+    let ordered = x.simd_eq(x).simd_and(y.simd_eq(y));
+    let t = ordered.simd_select(t, x.simd_add(y));
+
+    xor_sign(t, y)
+}
+
+/// Four-quadrant arctangent with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn atan2_with<P: Polynomials, V: SimdVec<f32>>(y: V, x: V) -> V {
+    let zero = V::splat(0.0);
+    let one = V::splat(1.0);
+    let ax = x.simd_abs();
+    let ay = y.simd_abs();
+
+    // The ratio of the smaller magnitude to the larger one is in [0, 1], 1 for two
+    // infinities and 0 for two zeros
+    let lo = ax.simd_min(ay);
+    let hi = ax.simd_max(ay);
+    let a = lo.simd_div(hi);
+    let a = lo.simd_eq(hi).simd_select(one, a);
+    let a = hi.simd_eq(zero).simd_select(zero, a);
+    let r = P::atan(a);
+
+    // From the first octant to the one of (x, |y|): |y| > |x| reflects r around
+    // pi / 4, to pi / 2 - r, and x with its sign bit set around pi / 2, to pi - r
+    let swap = ax.simd_lt(ay);
+    let neg = xor_sign(one, x).simd_lt(zero);
+    let minus = swap.simd_select(
+        neg.simd_select(r, r.simd_neg()),
+        neg.simd_select(r.simd_neg(), r),
+    );
+    let base_hi = neg.simd_select(V::splat(PI_HI), zero);
+    let base_lo = neg.simd_select(V::splat(PI_LO), zero);
+    let base_hi = swap.simd_select(V::splat(PI_2_HI), base_hi);
+    let base_lo = swap.simd_select(V::splat(PI_2_LO), base_lo);
+    let t = base_hi.simd_add(base_lo.simd_add(minus));
+
+    // min and max drop NaN operands
+    let ordered = x.simd_eq(x).simd_and(y.simd_eq(y));
+    let t = ordered.simd_select(t, x.simd_add(y));
+
+    xor_sign(t, y)
+}
+
+// Polynomial of (asin(x) / x - 1) / x^2 in x^2 on [0, 1 / 2]
+const ASIN_P: [f32; 9] = [
+    1.0889241e-1,
+    -1.2109443e-1,
+    9.3778655e-2,
+    -8.723525e-3,
+    2.7194394e-2,
+    2.9892417e-2,
+    4.466735e-2,
+    7.499953e-2,
+    1.6666667e-1,
+];
+
+// (asin(x) / x - 1 - ASIN_P[8]) / s in s = x^2
+#[inline(always)]
+fn asin_poly_tail<V: SimdVec<f32>>(s: V) -> V {
+    let p = s.simd_fma(V::splat(ASIN_P[0]), V::splat(ASIN_P[1]));
+    let p = s.simd_fma(p, V::splat(ASIN_P[2]));
+    let p = s.simd_fma(p, V::splat(ASIN_P[3]));
+    let p = s.simd_fma(p, V::splat(ASIN_P[4]));
+    let p = s.simd_fma(p, V::splat(ASIN_P[5]));
+    let p = s.simd_fma(p, V::splat(ASIN_P[6]));
+
+    s.simd_fma(p, V::splat(ASIN_P[7]))
+}
+
+// Reduces |x| to [0, sqrt(1 / 2)] like `asin_reduce`, returns the mask of the lanes
+// past it and asin of the reduced argument in two parts, the rounding of the square
+// root included as asin'(a) * c with asin'(a) = 1 / sqrt(1 - a^2) within 2.5% of
+// 1 + a^2 / 2 + 3 a^4 / 8 + 5 a^6 / 16
+#[inline(always)]
+fn asin_reduce_parts<V: SimdVec<f32>>(x: V) -> (V, V, V) {
+    let zero = V::splat(0.0);
+    let ax = x.simd_abs();
+
+    let large = V::splat(std::f32::consts::FRAC_1_SQRT_2).simd_lt(ax);
+    let z = V::splat(1.0).simd_sub(ax).simd_mul(V::splat(0.5));
+    let r = z.simd_sqrt();
+    let a = large.simd_select(r, ax);
+
+    // sqrt(z) - r = (z - r^2) / (2 r), z - r * r is exact
+    let c = z
+        .simd_sub(r.simd_mul(r))
+        .simd_sub(r.simd_mul_error(r))
+        .simd_div(r.simd_add(r));
+    let c = large.simd_and(zero.simd_lt(r)).simd_select(c, zero);
+
+    let s = a.simd_mul(a);
+    let (y, y_lo) = odd_poly_parts(a, (s, a.simd_mul_error(a)), asin_poly_tail(s), ASIN_P[8]);
+    let d = s.simd_fma(V::splat(5.0 / 16.0), V::splat(3.0 / 8.0));
+    let d = s.simd_fma(d, V::splat(0.5));
+    let d = s.simd_fma(d, V::splat(1.0));
+
+    (large, y, d.simd_fma(c, y_lo))
+}
+
+// Reduces |x| to [0, sqrt(1 / 2)], returns the mask of the lanes past it, asin of the
+// reduced argument and its correction for the rounding of the square root, where
+// asin(|x|) = pi / 2 - 2 asin(sqrt((1 - |x|) / 2))
+#[inline(always)]
+fn asin_reduce<P: Polynomials, V: SimdVec<f32>>(x: V) -> (V, V, V) {
+    let half = V::splat(0.5);
+    let ax = x.simd_abs();
+
+    // 1 - |x| is exact past 1 / 2, and negative past 1 for a NaN square root. The
+    // split is at sqrt(1 / 2) as below it, 2 asin(a) is close to pi / 2 - 2 asin(a)
+    // which would double its rounding error
+    let large = V::splat(std::f32::consts::FRAC_1_SQRT_2).simd_lt(ax);
+    let z = V::splat(1.0).simd_sub(ax).simd_mul(half);
+    let r = z.simd_sqrt();
+    let a = large.simd_select(r, ax);
+
+    // sqrt(z) - r = (z - r^2) / (2 r), zero without a fused multiply-add and at |x| = 1
+    let zero = V::splat(0.0);
+    let c = r.simd_neg().simd_fma(r, z).simd_div(r.simd_add(r));
+    let c = large.simd_and(zero.simd_lt(r)).simd_select(c, zero);
+
+    let s = a.simd_mul(a);
+
+    (large, a.simd_mul(s).simd_fma(P::asin(s), a), c)
+}
+
+/// Arcsine, see `SimdVec::simd_asin`
+#[inline(always)]
+pub(crate) fn asin<V: SimdVec<f32>>(x: V) -> V {
+    let (large, r, r_lo) = asin_reduce_parts(x);
+
+    // pi / 2 - 2 asin(a) past sqrt(1 / 2)
+    let (w, w_lo) = fast_two_sum(V::splat(PI_2_HI), r.simd_mul(V::splat(-2.0)));
+    let w_lo = r_lo.simd_fma(V::splat(-2.0), w_lo.simd_add(V::splat(PI_2_LO)));
+    let y = large.simd_select(w.simd_add(w_lo), r.simd_add(r_lo));
+
+    xor_sign(y, x)
+}
+
+/// Arcsine with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn asin_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let (large, r, c) = asin_reduce::<P, V>(x);
+
+    let lo = V::splat(PI_2_LO).simd_sub(c.simd_add(c));
+    let y = V::splat(PI_2_HI).simd_add(lo.simd_sub(r.simd_add(r)));
+
+    xor_sign(large.simd_select(y, r), x)
+}
+
+/// Arccosine, see `SimdVec::simd_acos`
+#[inline(always)]
+pub(crate) fn acos<V: SimdVec<f32>>(x: V) -> V {
+    let (large, r, r_lo) = asin_reduce_parts(x);
+
+    // pi / 2 - asin(x) up to sqrt(1 / 2), 2 asin(a) past it and pi - 2 asin(a) below
+    // -sqrt(1 / 2)
+    let (w, w_lo) = fast_two_sum(V::splat(PI_2_HI), xor_sign(r, x).simd_neg());
+    let small = w.simd_add(w_lo.simd_add(V::splat(PI_2_LO).simd_sub(xor_sign(r_lo, x))));
+    let two = V::splat(2.0);
+    let (w, w_lo) = fast_two_sum(V::splat(PI_HI), r.simd_mul(two.simd_neg()));
+    let w_lo = r_lo.simd_fma(two.simd_neg(), w_lo.simd_add(V::splat(PI_LO)));
+    let y = x
+        .simd_lt(V::splat(0.0))
+        .simd_select(w.simd_add(w_lo), r.simd_add(r_lo).simd_mul(two));
+
+    large.simd_select(y, small)
+}
+
+/// Arccosine with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn acos_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let (large, r, c) = asin_reduce::<P, V>(x);
+
+    // pi / 2 - asin(x) up to sqrt(1 / 2), 2 asin(a) past it and pi - 2 asin(a) below
+    // -sqrt(1 / 2)
+    let small = V::splat(PI_2_HI).simd_add(V::splat(PI_2_LO).simd_sub(xor_sign(r, x)));
+    let r2 = r.simd_add(c).simd_mul(V::splat(2.0));
+    let lo = V::splat(PI_LO).simd_sub(c.simd_add(c));
+    let y = x
+        .simd_lt(V::splat(0.0))
+        .simd_select(V::splat(PI_HI).simd_add(lo.simd_sub(r.simd_add(r))), r2);
+
+    large.simd_select(y, small)
+}
+
+// Polynomial of (sinh(x) / x - 1) / x^2 in x^2 on [0, 1]
+const SINH_P: [f32; 4] = [2.8062802e-6, 1.9838102e-4, 8.333339e-3, 1.6666667e-1];
+
+// Past HYP_LARGE exp(-|x|) is negligible, exp(|x|) / 2 is computed as
+// exp(|x| - HYP_SHIFT) * HYP_SCALE to overflow along with sinh and cosh instead of
+// past 88.7, |x| - HYP_SHIFT being exact
+const HYP_LARGE: f32 = 32.0;
+const HYP_SHIFT: f32 = 44.0;
+const HYP_SCALE: f32 = 6.4258e18;
+
+// exp(|x|) where |x| < HYP_LARGE, and exp(|x| - HYP_SHIFT) elsewhere for `hyp`
+#[inline(always)]
+fn hyp_exp<P: Polynomials, V: SimdVec<f32>>(ax: V, large: V) -> V {
+    exp_with::<P, V>(large.simd_select(ax.simd_sub(V::splat(HYP_SHIFT)), ax))
+}
+
+// (e + k / e) / 2 below HYP_LARGE, e * HYP_SCALE past it, see `hyp_exp`
+#[inline(always)]
+fn hyp<V: SimdVec<f32>>(e: V, large: V, k: f32) -> V {
+    let y = V::splat(k).simd_div(e).simd_add(e).simd_mul(V::splat(0.5));
+
+    large.simd_select(e.simd_mul(V::splat(HYP_SCALE)), y)
+}
+
+// (sinh(x) / x - 1 - SINH_P[3]) / s in s = x^2
+#[inline(always)]
+fn sinh_poly_tail<V: SimdVec<f32>>(s: V) -> V {
+    let p = s.simd_fma(V::splat(SINH_P[0]), V::splat(SINH_P[1]));
+
+    s.simd_fma(p, V::splat(SINH_P[2]))
+}
+
+// The rounding error of HYP_SCALE
+const HYP_SCALE_LO: f32 = 1.1727069e11;
+
+// Arguments are clamped to this bound past which sinh and cosh overflow, for the
+// exponent of `exp_parts` to stay in range
+const HYP_MAX: f32 = 100.0;
+
+// (e + k / e) / 2 in two parts for e = exp(|x|) below HYP_LARGE, exp(|x|) / 2 as
+// exp(|x| - HYP_SHIFT) * HYP_SCALE past it, rounded once
+#[inline(always)]
+fn hyp_parts<V: SimdVec<f32>>(ax: V, k: f32) -> V {
+    let ax = ax.simd_clamp(V::splat(0.0), V::splat(HYP_MAX));
+    let huge = V::splat(HYP_LARGE).simd_le(ax);
+    let (e, e_lo) = exp_parts(huge.simd_select(ax.simd_sub(V::splat(HYP_SHIFT)), ax));
+
+    let (r, r_lo) = div_pair((V::splat(k), V::splat(0.0)), (e, e_lo));
+    let (y, y_lo) = two_sum(e, r);
+    let half = V::splat(0.5);
+    let y = y
+        .simd_add(y_lo.simd_add(e_lo).simd_add(r_lo))
+        .simd_mul(half);
+
+    // The tail of the product is NaN where it overflows
+    let scale = V::splat(HYP_SCALE);
+    let p = e.simd_mul(scale);
+    let p_lo = e.simd_fma(V::splat(HYP_SCALE_LO), e.simd_mul_error(scale));
+    let p_lo = e_lo.simd_fma(scale, p_lo);
+    let p = V::splat(f32::INFINITY)
+        .simd_eq(p)
+        .simd_select(p, p.simd_add(p_lo));
+
+    huge.simd_select(p, y)
+}
+
+/// Hyperbolic sine, see `SimdVec::simd_sinh`
+#[inline(always)]
+pub(crate) fn sinh<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    // |x| + |x|^3 * P(x^2) below 1, where (e - 1 / e) / 2 cancels
+    let s = x.simd_mul(x);
+    let (y, y_lo) = odd_poly_parts(ax, (s, x.simd_mul_error(x)), sinh_poly_tail(s), SINH_P[3]);
+    let small = y.simd_add(y_lo);
+
+    let large = V::splat(1.0).simd_le(ax);
+
+    if !large.simd_any() {
+        return xor_sign(small, x);
+    }
+
+    xor_sign(large.simd_select(hyp_parts(ax, -1.0), small), x)
+}
+
+/// Hyperbolic sine with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn sinh_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+    let ax = x.simd_abs();
+
+    // |x| + |x|^3 * P(x^2) below 1, where (e - 1 / e) / 2 cancels
+    let s = x.simd_mul(x);
+    let small = ax.simd_mul(s).simd_fma(P::sinh(s), ax);
+
+    let large = one.simd_le(ax);
+
+    if !large.simd_any() {
+        return xor_sign(small, x);
+    }
+
+    let huge = V::splat(HYP_LARGE).simd_le(ax);
+    let y = hyp(hyp_exp::<P, V>(ax, huge), huge, -1.0);
+
+    xor_sign(large.simd_select(y, small), x)
+}
+
+/// Hyperbolic cosine, see `SimdVec::simd_cosh`
+#[inline(always)]
+pub(crate) fn cosh<V: SimdVec<f32>>(x: V) -> V {
+    hyp_parts(x.simd_abs(), 1.0)
+}
+
+/// Hyperbolic cosine with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn cosh_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+    let huge = V::splat(HYP_LARGE).simd_le(ax);
+
+    hyp(hyp_exp::<P, V>(ax, huge), huge, 1.0)
+}
+
+// Past this, sqrt(x^2 + 1) and sqrt(x^2 - 1) round to |x|, so that asinh and acosh
+// are ln(2 |x|), computed as ln(|x|) + ln(2) not to overflow
+const AHYP_LARGE: f32 = 4096.0;
+
+/// Inverse hyperbolic sine, see `SimdVec::simd_asinh`
+#[inline(always)]
+pub(crate) fn asinh<V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+    let ax = x.simd_abs();
+
+    // log1p(|x| + x^2 / (1 + sqrt(x^2 + 1))) with the argument in two parts
+    let s = ax.simd_mul(ax);
+    let (u, u_lo) = fast_two_sum(one, s);
+    let (r, r_lo) = sqrt_parts(u, u_lo.simd_add(ax.simd_mul_error(ax)));
+    let (d, d_lo) = fast_two_sum(r, one);
+    let (g, g_lo) = div_pair((s, ax.simd_mul_error(ax)), (d, d_lo.simd_add(r_lo)));
+    let (a, a_lo) = fast_two_sum(ax, g);
+    let (y, y_lo) = log1p_parts(a, a_lo.simd_add(g_lo));
+
+    // ln(2 |x|) past AHYP_LARGE
+    let (e, m, m_lo) = log_reduce_parts(ax);
+    let (z, z_lo) = ln_combine(e.simd_add(one), m, m_lo);
+
+    let large = V::splat(AHYP_LARGE).simd_le(ax);
+    let y = large.simd_select(log_special(ax, z.simd_add(z_lo)), y.simd_add(y_lo));
+
+    xor_sign(y, x)
+}
+
+/// Inverse hyperbolic sine with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn asinh_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+    let ax = x.simd_abs();
+
+    // ln(|x| + sqrt(x^2 + 1)) = log1p(|x| + x^2 / (1 + sqrt(x^2 + 1)))
+    let s = ax.simd_mul(ax);
+    let y = log1p_with::<P, V>(
+        s.simd_div(one.simd_add(one.simd_add(s).simd_sqrt()))
+            .simd_add(ax),
+    );
+
+    let large = V::splat(AHYP_LARGE).simd_le(ax);
+    let y = large.simd_select(
+        ln_with::<P, V>(ax).simd_add(V::splat(std::f32::consts::LN_2)),
+        y,
+    );
+
+    xor_sign(y, x)
+}
+
+/// Inverse hyperbolic cosine, see `SimdVec::simd_acosh`
+#[inline(always)]
+pub(crate) fn acosh<V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+
+    // log1p(t + sqrt(t (t + 2))) with t = x - 1, exact, and the argument in two parts
+    let t = x.simd_sub(one);
+    let (w, w_lo) = two_sum(t, V::splat(2.0));
+    let v = t.simd_mul(w);
+    let (r, r_lo) = sqrt_parts(v, t.simd_fma(w_lo, t.simd_mul_error(w)));
+    let (a, a_lo) = fast_two_sum(r, t);
+    let (y, y_lo) = log1p_parts(a, a_lo.simd_add(r_lo));
+
+    // ln(2 x) past AHYP_LARGE
+    let (e, m, m_lo) = log_reduce_parts(x);
+    let (z, z_lo) = ln_combine(e.simd_add(one), m, m_lo);
+
+    let large = V::splat(AHYP_LARGE).simd_le(x);
+    let y = large.simd_select(log_special(x, z.simd_add(z_lo)), y.simd_add(y_lo));
+
+    x.simd_lt(one).simd_select(V::splat(f32::NAN), y)
+}
+
+/// Inverse hyperbolic cosine with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn acosh_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let one = V::splat(1.0);
+
+    // ln(x + sqrt(x^2 - 1)) = log1p(t + sqrt(t (t + 2))) with t = x - 1, exact
+    let t = x.simd_sub(one);
+    let y = log1p_with::<P, V>(t.simd_fma(t, t.simd_add(t)).simd_sqrt().simd_add(t));
+
+    let large = V::splat(AHYP_LARGE).simd_le(x);
+    let y = large.simd_select(
+        ln_with::<P, V>(x).simd_add(V::splat(std::f32::consts::LN_2)),
+        y,
+    );
+
+    x.simd_lt(one).simd_select(V::splat(f32::NAN), y)
+}
+
+/// Inverse hyperbolic tangent, see `SimdVec::simd_atanh`
+#[inline(always)]
+pub(crate) fn atanh<V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    // log1p(2 |x| / (1 - |x|)) / 2 with the argument in two parts, it is below -2
+    // past 1 for a NaN logarithm
+    let (d, d_lo) = two_sum(V::splat(1.0), ax.simd_neg());
+    let (a, a_lo) = div_pair((ax.simd_add(ax), V::splat(0.0)), (d, d_lo));
+    let (y, y_lo) = log1p_parts(a, a_lo);
+    let y = log_special(a.simd_add(V::splat(1.0)), y.simd_add(y_lo));
+
+    xor_sign(y.simd_mul(V::splat(0.5)), x)
+}
+
+/// Inverse hyperbolic tangent with the polynomials of `P`
+#[inline(always)]
+pub(crate) fn atanh_with<P: Polynomials, V: SimdVec<f32>>(x: V) -> V {
+    let ax = x.simd_abs();
+
+    // ln((1 + |x|) / (1 - |x|)) / 2 = log1p(2 |x| / (1 - |x|)) / 2, the argument is
+    // below -2 past 1 for a NaN logarithm
+    let y = log1p_with::<P, V>(ax.simd_add(ax).simd_div(V::splat(1.0).simd_sub(ax)))
+        .simd_mul(V::splat(0.5));
+
+    xor_sign(y, x)
+}
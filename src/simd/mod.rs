@@ -1,7 +1,16 @@
-#[cfg(all(avx512, rustc_channel = "nightly"))]
-pub(crate) mod f32x16_nightly;
-
+pub mod backend;
+pub mod features;
 pub mod utils;
 
+pub(crate) mod fast_math;
+pub(crate) mod math;
+
+pub mod scalar;
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
 pub mod f32x4;
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
 pub mod f32x8;
+
+#[cfg(target_arch = "x86_64")]
+pub mod f32x16;
@@ -0,0 +1,782 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::math;
+use super::utils::SimdVec;
+
+pub const SIZE: usize = 8;
+
+/// A portable vector of 8 32-bit floating point values
+/// The reference `SimdVec`, lane-wise loops left for the compiler to auto-vectorize
+#[derive(Copy, Clone, Debug)]
+pub struct ScalarVec {
+    size: usize,
+
+    elements: [f32; SIZE],
+}
+
+impl SimdVec<f32> for ScalarVec {
+    const SIZE: usize = SIZE;
+
+    #[inline(always)]
+    fn new(slice: &[f32]) -> Self {
+        match slice.len().cmp(&SIZE) {
+            std::cmp::Ordering::Less => unsafe { Self::load_partial(slice.as_ptr(), slice.len()) },
+            std::cmp::Ordering::Equal | std::cmp::Ordering::Greater => unsafe {
+                Self::load(slice.as_ptr(), SIZE)
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn splat(value: f32) -> Self {
+        Self {
+            elements: [value; SIZE],
+            size: SIZE,
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn load(ptr: *const f32, size: usize) -> Self {
+        assert!(size == SIZE, "Size must be == {}", SIZE);
+
+        let mut elements = [0f32; SIZE];
+        std::ptr::copy_nonoverlapping(ptr, elements.as_mut_ptr(), SIZE);
+
+        Self { elements, size }
+    }
+
+    #[inline(always)]
+    unsafe fn load_partial(ptr: *const f32, size: usize) -> Self {
+        assert!(size < SIZE, "Size must be < {}", SIZE);
+
+        let mut elements = [0f32; SIZE];
+        std::ptr::copy_nonoverlapping(ptr, elements.as_mut_ptr(), size);
+
+        Self { elements, size }
+    }
+
+    #[inline(always)]
+    unsafe fn load_strided(ptr: *const f32, stride: usize) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (i, element) in elements.iter_mut().enumerate() {
+            *element = *ptr.add(i * stride);
+        }
+
+        Self {
+            elements,
+            size: SIZE,
+        }
+    }
+
+    #[inline(always)]
+    fn to_vec(self) -> Vec<f32> {
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
+
+        if self.size == SIZE {
+            self.store()
+        } else {
+            self.store_partial()
+        }
+    }
+
+    #[inline(always)]
+    fn store(&self) -> Vec<f32> {
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
+
+        self.elements.to_vec()
+    }
+
+    #[inline(always)]
+    fn store_partial(&self) -> Vec<f32> {
+        assert!(self.size < SIZE, "Size must be < {}", SIZE);
+
+        self.elements[..self.size].to_vec()
+    }
+
+    #[inline(always)]
+    unsafe fn store_at(&self, ptr: *mut f32) {
+        assert!(self.size <= SIZE, "Size must be <= {}", SIZE);
+
+        std::ptr::copy_nonoverlapping(self.elements.as_ptr(), ptr, SIZE);
+    }
+
+    #[inline(always)]
+    unsafe fn store_at_partial(&self, ptr: *mut f32) {
+        assert!(self.size < SIZE, "Size must be < {}", SIZE);
+
+        std::ptr::copy_nonoverlapping(self.elements.as_ptr(), ptr, self.size);
+    }
+
+    #[inline(always)]
+    fn simd_mask_add(&self, rhs: Self) -> Self {
+        self.simd_add(rhs)
+    }
+
+    #[inline(always)]
+    fn simd_add(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = a + b;
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sub(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = a - b;
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mul(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = a * b;
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_div(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = a / b;
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_min(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = a.min(b);
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_max(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = a.max(b);
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_clamp(&self, lo: Self, hi: Self) -> Self {
+        assert!(
+            self.size == lo.size && self.size == hi.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for (((c, x), lo), hi) in elements
+            .iter_mut()
+            .zip(self.elements)
+            .zip(lo.elements)
+            .zip(hi.elements)
+        {
+            // Comparisons with NaN are false, NaN lanes are kept as is
+            *c = if x < lo {
+                lo
+            } else if x > hi {
+                hi
+            } else {
+                x
+            };
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_fma(&self, b: Self, c: Self) -> Self {
+        assert!(
+            self.size == b.size && self.size == c.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for (((d, a), b), c) in elements
+            .iter_mut()
+            .zip(self.elements)
+            .zip(b.elements)
+            .zip(c.elements)
+        {
+            *d = a.mul_add(b, c);
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mul_error(&self, b: Self) -> Self {
+        assert!(
+            self.size == b.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(b.elements) {
+            *c = a.mul_add(b, -(a * b));
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_abs(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = a.abs();
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_neg(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = -a;
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sqrt(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = a.sqrt();
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        // In f64, 1 / sqrt(x) rounds twice in f32
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = (1.0 / (a as f64).sqrt()) as f32;
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_rsqrt_approx(&self) -> Self {
+        // No estimate instruction to mimic, the refined result is the reference
+        self.simd_rsqrt()
+    }
+
+    #[inline(always)]
+    fn simd_recip(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = 1.0 / a;
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_floor(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = a.floor();
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_ceil(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = a.ceil();
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_round(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = a.round_ties_even();
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_trunc(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = a.trunc();
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_eq(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = mask(a == b);
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_lt(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = mask(a < b);
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_le(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = mask(a <= b);
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_select(&self, a: Self, b: Self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (((c, m), a), b) in elements
+            .iter_mut()
+            .zip(self.elements)
+            .zip(a.elements)
+            .zip(b.elements)
+        {
+            *c = if m.to_bits() != 0 { a } else { b };
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_and(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = f32::from_bits(a.to_bits() & b.to_bits());
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_xor(&self, rhs: Self) -> Self {
+        assert!(
+            self.size == rhs.size,
+            "Operands must have the same size {}",
+            self.size
+        );
+
+        let mut elements = [0f32; SIZE];
+
+        for ((c, a), b) in elements.iter_mut().zip(self.elements).zip(rhs.elements) {
+            *c = f32::from_bits(a.to_bits() ^ b.to_bits());
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_any(&self) -> bool {
+        self.elements[..self.size].iter().any(|m| m.to_bits() != 0)
+    }
+
+    #[inline(always)]
+    fn simd_exp2i(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = f32::from_bits(((a as i32 + 127) as u32) << 23);
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_exponent(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = (((a.to_bits() >> 23) & 0xff) as i32 - 127) as f32;
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_mantissa(&self) -> Self {
+        let mut elements = [0f32; SIZE];
+
+        for (b, a) in elements.iter_mut().zip(self.elements) {
+            *b = f32::from_bits((a.to_bits() & 0x007f_ffff) | 0x3f80_0000);
+        }
+
+        Self {
+            elements,
+            size: self.size,
+        }
+    }
+
+    #[inline(always)]
+    fn simd_sin(&self) -> Self {
+        math::sin(*self)
+    }
+
+    #[inline(always)]
+    fn simd_cos(&self) -> Self {
+        math::cos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tan(&self) -> Self {
+        math::tan(*self)
+    }
+
+    #[inline(always)]
+    fn simd_sincos(&self) -> (Self, Self) {
+        math::sincos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_exp(&self) -> Self {
+        math::exp(*self)
+    }
+
+    #[inline(always)]
+    fn simd_exp2(&self) -> Self {
+        math::exp2(*self)
+    }
+
+    #[inline(always)]
+    fn simd_expm1(&self) -> Self {
+        math::expm1(*self)
+    }
+
+    #[inline(always)]
+    fn simd_ln(&self) -> Self {
+        math::ln(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log2(&self) -> Self {
+        math::log2(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log10(&self) -> Self {
+        math::log10(*self)
+    }
+
+    #[inline(always)]
+    fn simd_log1p(&self) -> Self {
+        math::log1p(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tanh(&self) -> Self {
+        math::tanh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_erf(&self) -> Self {
+        math::erf(*self)
+    }
+
+    #[inline(always)]
+    fn simd_erfc(&self) -> Self {
+        math::erfc(*self)
+    }
+
+    #[inline(always)]
+    fn simd_tgamma(&self) -> Self {
+        math::tgamma(*self)
+    }
+
+    #[inline(always)]
+    fn simd_lgamma(&self) -> Self {
+        math::lgamma(*self)
+    }
+
+    #[inline(always)]
+    fn simd_asin(&self) -> Self {
+        math::asin(*self)
+    }
+
+    #[inline(always)]
+    fn simd_acos(&self) -> Self {
+        math::acos(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atan(&self) -> Self {
+        math::atan(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atan2(&self, x: Self) -> Self {
+        math::atan2(*self, x)
+    }
+
+    #[inline(always)]
+    fn simd_sinh(&self) -> Self {
+        math::sinh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_cosh(&self) -> Self {
+        math::cosh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_asinh(&self) -> Self {
+        math::asinh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_acosh(&self) -> Self {
+        math::acosh(*self)
+    }
+
+    #[inline(always)]
+    fn simd_atanh(&self) -> Self {
+        math::atanh(*self)
+    }
+}
+
+// Lane of a comparison mask, all bits set when `cond` holds
+#[inline(always)]
+fn mask(cond: bool) -> f32 {
+    f32::from_bits(if cond { u32::MAX } else { 0 })
+}
+
+/// Implementation of Add trait for ScalarVec
+impl Add for ScalarVec {
+    type Output = ScalarVec;
+
+    #[inline(always)]
+    fn add(self, rhs: ScalarVec) -> Self::Output {
+        self.simd_add(rhs)
+    }
+}
+
+/// Implementation of Sub trait for ScalarVec
+impl Sub for ScalarVec {
+    type Output = ScalarVec;
+
+    #[inline(always)]
+    fn sub(self, rhs: ScalarVec) -> Self::Output {
+        self.simd_sub(rhs)
+    }
+}
+
+/// Implementation of Mul trait for ScalarVec
+impl Mul for ScalarVec {
+    type Output = ScalarVec;
+
+    #[inline(always)]
+    fn mul(self, rhs: ScalarVec) -> Self::Output {
+        self.simd_mul(rhs)
+    }
+}
+
+/// Implementation of Div trait for ScalarVec
+impl Div for ScalarVec {
+    type Output = ScalarVec;
+
+    #[inline(always)]
+    fn div(self, rhs: ScalarVec) -> Self::Output {
+        self.simd_div(rhs)
+    }
+}
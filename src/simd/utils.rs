@@ -1,4 +1,7 @@
-pub trait SimdVec<T> {
+pub trait SimdVec<T>: Copy {
+    /// Number of lanes in the register
+    const SIZE: usize;
+
     fn new(slice: &[T]) -> Self;
 
     fn splat(value: T) -> Self;
@@ -17,6 +20,15 @@ pub trait SimdVec<T> {
     /// .
     unsafe fn load_partial(ptr: *const T, size: usize) -> Self;
 
+    /// Loads `SIZE` elements spaced `stride` elements apart, starting at `ptr`
+    ///
+    /// Gathers on AVX2 and AVX-512, loads lane by lane on the other backends.
+    ///
+    /// # Safety
+    ///
+    /// `ptr.add(i * stride)` must be valid for reads for every lane `i`.
+    unsafe fn load_strided(ptr: *const T, stride: usize) -> Self;
+
     fn to_vec(self) -> Vec<T>;
 
     fn store(&self) -> Vec<T>;
@@ -41,5 +53,205 @@ pub trait SimdVec<T> {
 
     fn simd_add(&self, rhs: Self) -> Self;
 
-    fn simd_sin(&self);
+    fn simd_sub(&self, rhs: Self) -> Self;
+
+    fn simd_mul(&self, rhs: Self) -> Self;
+
+    fn simd_div(&self, rhs: Self) -> Self;
+
+    /// Lane-wise minimum, IEEE 754 minNum: a NaN lane yields the other operand
+    fn simd_min(&self, rhs: Self) -> Self;
+
+    /// Lane-wise maximum, IEEE 754 maxNum: a NaN lane yields the other operand
+    fn simd_max(&self, rhs: Self) -> Self;
+
+    /// Clamps every lane to `[lo, hi]`, NaN lanes of `self` are propagated
+    fn simd_clamp(&self, lo: Self, hi: Self) -> Self;
+
+    /// Computes `self * b + c`, fused (single rounding) on every backend but SSE
+    fn simd_fma(&self, b: Self, c: Self) -> Self;
+
+    /// Rounding error of `self * b`, exactly `self * b - round(self * b)` unless the
+    /// product overflows or its error is subnormal
+    ///
+    /// A fused multiply-add on every backend but SSE, which multiplies in f64.
+    fn simd_mul_error(&self, b: Self) -> Self;
+
+    /// Absolute value, clears the sign bit (NaN lanes included)
+    fn simd_abs(&self) -> Self;
+
+    /// Negation, flips the sign bit (NaN lanes included)
+    fn simd_neg(&self) -> Self;
+
+    /// Square root, correctly rounded
+    fn simd_sqrt(&self) -> Self;
+
+    /// Reciprocal square root, the hardware estimate refined by Newton-Raphson
+    ///
+    /// Within 1.5 ulp of `1 / sqrt(x)` on SSE, whose last step has no FMA, and
+    /// 0.6 ulp on the other backends. Exact for `0` (infinity of the same sign)
+    /// and `+inf` (zero).
+    fn simd_rsqrt(&self) -> Self;
+
+    /// Reciprocal square root, the raw hardware estimate
+    ///
+    /// Relative error below 2^-11 on SSE, AVX2 and NEON (whose 8 bit estimate
+    /// gets one Newton-Raphson step), 2^-14 on AVX-512. Exact on the scalar backend.
+    /// SSE and AVX2 flush subnormal inputs to zero, returning an infinity for them.
+    fn simd_rsqrt_approx(&self) -> Self;
+
+    /// Reciprocal `1 / x`, correctly rounded
+    fn simd_recip(&self) -> Self;
+
+    /// Rounds toward negative infinity
+    fn simd_floor(&self) -> Self;
+
+    /// Rounds toward positive infinity
+    fn simd_ceil(&self) -> Self;
+
+    /// Rounds to the nearest integer, ties to even (like `np.round`, unlike `f32::round`)
+    fn simd_round(&self) -> Self;
+
+    /// Rounds toward zero
+    fn simd_trunc(&self) -> Self;
+
+    /// Lane mask of `self == rhs`, all bits set where it holds and cleared elsewhere
+    ///
+    /// Comparisons involving NaN do not hold.
+    fn simd_eq(&self, rhs: Self) -> Self;
+
+    /// Lane mask of `self < rhs`, see `simd_eq`
+    fn simd_lt(&self, rhs: Self) -> Self;
+
+    /// Lane mask of `self <= rhs`, see `simd_eq`
+    fn simd_le(&self, rhs: Self) -> Self;
+
+    /// Picks the lanes of `a` where the mask `self` is set and those of `b` elsewhere
+    ///
+    /// `self` must be a mask returned by `simd_eq`, `simd_lt` or `simd_le`.
+    fn simd_select(&self, a: Self, b: Self) -> Self;
+
+    /// Bitwise and of the lanes
+    fn simd_and(&self, rhs: Self) -> Self;
+
+    /// Bitwise xor of the lanes
+    fn simd_xor(&self, rhs: Self) -> Self;
+
+    /// Whether any lane of the mask `self` is set, see `simd_select`
+    fn simd_any(&self) -> bool;
+
+    /// `2^self` built from the exponent bits, for integer-valued lanes in [-126, 127]
+    fn simd_exp2i(&self) -> Self;
+
+    /// Unbiased exponent field of each lane as an integer-valued float,
+    /// `floor(log2(|x|))` for normal lanes, -127 for zero and subnormals, 128 for
+    /// infinities and NaN
+    fn simd_exponent(&self) -> Self;
+
+    /// Significand of each lane with the sign cleared and the exponent field set to
+    /// 0, in [1, 2) for normal lanes
+    fn simd_mantissa(&self) -> Self;
+
+    /// Sine, within 1 ulp
+    ///
+    /// Arguments are reduced to [-pi / 4, pi / 4] in f32 up to `|x| <= 8192`,
+    /// larger ones fall back to f64 lane by lane, which is much slower.
+    /// Infinities and NaN give NaN.
+    fn simd_sin(&self) -> Self;
+
+    /// Cosine, within 1 ulp, see `simd_sin`
+    fn simd_cos(&self) -> Self;
+
+    /// Tangent, within 1 ulp, see `simd_sin`
+    fn simd_tan(&self) -> Self;
+
+    /// Sine and cosine sharing a single range reduction, see `simd_sin`
+    fn simd_sincos(&self) -> (Self, Self);
+
+    /// Natural exponential, within 1 ulp
+    ///
+    /// Overflows to infinity past `ln(f32::MAX)`, results below `f32::MIN_POSITIVE`
+    /// are subnormal then round to zero. NaN gives NaN.
+    fn simd_exp(&self) -> Self;
+
+    /// Base 2 exponential, within 1 ulp, exact for integers, see `simd_exp`
+    fn simd_exp2(&self) -> Self;
+
+    /// `exp(x) - 1` without the cancellation near zero, within 1 ulp, see `simd_exp`
+    fn simd_expm1(&self) -> Self;
+
+    /// Natural logarithm, within 1 ulp
+    ///
+    /// Subnormal lanes are handled exactly, zero gives -inf, negative lanes and NaN
+    /// give NaN, inf gives inf.
+    fn simd_ln(&self) -> Self;
+
+    /// Base 2 logarithm, within 1 ulp, exact for powers of two, see `simd_ln`
+    fn simd_log2(&self) -> Self;
+
+    /// Base 10 logarithm, within 1 ulp, see `simd_ln`
+    fn simd_log10(&self) -> Self;
+
+    /// `ln(1 + x)` without the cancellation near zero, within 1 ulp
+    ///
+    /// -1 gives -inf, lanes below -1 and NaN give NaN, inf gives inf.
+    fn simd_log1p(&self) -> Self;
+
+    /// Hyperbolic tangent, within 1 ulp
+    fn simd_tanh(&self) -> Self;
+
+    /// Error function, within 1 ulp
+    fn simd_erf(&self) -> Self;
+
+    /// Complementary error function `1 - erf(x)`, within 1 ulp, accurate in the tail
+    /// until it rounds to 0 past 10.05
+    fn simd_erfc(&self) -> Self;
+
+    /// Gamma function, within 1 ulp
+    ///
+    /// Negative lanes use the reflection formula, the negative integers give NaN,
+    /// zero gives inf of the same sign, lanes past 35.04 overflow to inf.
+    fn simd_tgamma(&self) -> Self;
+
+    /// Natural logarithm of `|gamma(x)|`, within 1 ulp
+    ///
+    /// Negative lanes use the reflection formula, computed in f64 near the zeros of
+    /// lgamma where its terms cancel. Zero and the negative integers give inf.
+    fn simd_lgamma(&self) -> Self;
+
+    /// Arcsine, within 1 ulp
+    ///
+    /// Lanes past 1 in magnitude and NaN give NaN.
+    fn simd_asin(&self) -> Self;
+
+    /// Arccosine, within 1 ulp, see `simd_asin`
+    fn simd_acos(&self) -> Self;
+
+    /// Arctangent, within 1 ulp
+    fn simd_atan(&self) -> Self;
+
+    /// Four-quadrant arctangent of `self / x`, within 1 ulp
+    ///
+    /// Zeros and infinities give the results of `f32::atan2`, e.g. pi for `(0, -0)`
+    /// and `3 pi / 4` for `(inf, -inf)`.
+    fn simd_atan2(&self, x: Self) -> Self;
+
+    /// Hyperbolic sine, within 1 ulp, overflows to infinity past 89.41
+    fn simd_sinh(&self) -> Self;
+
+    /// Hyperbolic cosine, within 1 ulp, see `simd_sinh`
+    fn simd_cosh(&self) -> Self;
+
+    /// Inverse hyperbolic sine, within 1 ulp
+    fn simd_asinh(&self) -> Self;
+
+    /// Inverse hyperbolic cosine, within 1 ulp
+    ///
+    /// Lanes below 1 and NaN give NaN.
+    fn simd_acosh(&self) -> Self;
+
+    /// Inverse hyperbolic tangent, within 1 ulp
+    ///
+    /// +-1 give infinities of the same sign, lanes past 1 in magnitude and NaN give NaN.
+    fn simd_atanh(&self) -> Self;
 }
@@ -0,0 +1,1319 @@
+//! Accuracy tiers of the transcendental functions against 50 digit references from
+//! mpmath, on every backend the CPU supports
+
+mod common;
+
+use std::f32::consts::{FRAC_PI_2, LN_2, PI};
+
+use arithmetics::ops::accuracy::Accuracy;
+use arithmetics::ops::atan2::SimdAtan2;
+use arithmetics::ops::unary::{SimdUnary, UnaryFn};
+use arithmetics::simd::backend::Backend;
+use common::{check_exact, for_each_backend, ulp_error};
+
+// Whether `got` meets the bound of `accuracy` for the exact `want`
+fn within(accuracy: Accuracy, got: f32, want: f64) -> bool {
+    match accuracy {
+        Accuracy::Fast => {
+            let err = (got as f64 - want).abs();
+
+            err <= 1e-4 * want.abs() || err <= 1e-37
+        }
+        Accuracy::Default => ulp_error(got, want) <= 1.0,
+        Accuracy::CorrectlyRounded => got.to_bits() == (want as f32).to_bits(),
+    }
+}
+
+// A tiered function with its references, away from the poles and zeros where the
+// fast kernels lose their relative accuracy
+type Case = (UnaryFn, &'static [(f32, f64)]);
+
+const FUNCTIONS: [Case; 30] = [
+    (UnaryFn::SIN, &SIN),
+    (UnaryFn::COS, &COS),
+    (UnaryFn::TAN, &TAN),
+    (UnaryFn::EXP, &EXP),
+    (UnaryFn::EXP2, &EXP2),
+    (UnaryFn::EXPM1, &EXPM1),
+    (UnaryFn::LN, &LN),
+    (UnaryFn::LOG2, &LOG2),
+    (UnaryFn::LOG10, &LOG10),
+    (UnaryFn::LOG1P, &LOG1P),
+    (UnaryFn::TANH, &TANH),
+    (UnaryFn::ERF, &ERF),
+    (UnaryFn::ERFC, &ERFC),
+    (UnaryFn::TGAMMA, &TGAMMA),
+    (UnaryFn::LGAMMA, &LGAMMA),
+    (UnaryFn::ASIN, &ASIN),
+    (UnaryFn::ACOS, &ACOS),
+    (UnaryFn::ATAN, &ATAN),
+    (UnaryFn::SINH, &SINH),
+    (UnaryFn::COSH, &COSH),
+    (UnaryFn::ASINH, &ASINH),
+    (UnaryFn::ACOSH, &ACOSH),
+    (UnaryFn::ATANH, &ATANH),
+    (UnaryFn::SIGMOID, &SIGMOID),
+    (UnaryFn::SOFTPLUS, &SOFTPLUS),
+    (UnaryFn::SILU, &SILU),
+    (UnaryFn::GELU, &GELU),
+    (UnaryFn::GELU_TANH, &GELU_TANH),
+    (UnaryFn::SQRT, &SQRT),
+    (UnaryFn::RELU, &RELU),
+];
+
+const INF: f32 = f32::INFINITY;
+const NEG_INF: f32 = f32::NEG_INFINITY;
+const NAN: f32 = f32::NAN;
+
+// IEEE results of every tier at the signed zeros, infinities, NaN and the edges of
+// the domains
+type Special = (UnaryFn, &'static [(f32, f32)]);
+
+const SPECIALS: [Special; 30] = [
+    (
+        UnaryFn::SIN,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, NAN),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+        ],
+    ),
+    (
+        UnaryFn::COS,
+        &[
+            (0.0, 1.0),
+            (-0.0, 1.0),
+            (INF, NAN),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+        ],
+    ),
+    (
+        UnaryFn::TAN,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, NAN),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+        ],
+    ),
+    (
+        UnaryFn::EXP,
+        &[
+            (0.0, 1.0),
+            (-0.0, 1.0),
+            (INF, INF),
+            (NEG_INF, 0.0),
+            (NAN, NAN),
+            (200.0, INF),
+            (-200.0, 0.0),
+        ],
+    ),
+    (
+        UnaryFn::EXP2,
+        &[
+            (0.0, 1.0),
+            (-0.0, 1.0),
+            (INF, INF),
+            (NEG_INF, 0.0),
+            (NAN, NAN),
+            (200.0, INF),
+            (-200.0, 0.0),
+        ],
+    ),
+    (
+        UnaryFn::EXPM1,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, -1.0),
+            (NAN, NAN),
+            (200.0, INF),
+            (-200.0, -1.0),
+        ],
+    ),
+    (
+        UnaryFn::LN,
+        &[
+            (0.0, NEG_INF),
+            (-0.0, NEG_INF),
+            (INF, INF),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, 0.0),
+            (-1.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::LOG2,
+        &[
+            (0.0, NEG_INF),
+            (-0.0, NEG_INF),
+            (INF, INF),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, 0.0),
+            (-1.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::LOG10,
+        &[
+            (0.0, NEG_INF),
+            (-0.0, NEG_INF),
+            (INF, INF),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, 0.0),
+            (-1.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::LOG1P,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (-1.0, NEG_INF),
+            (-2.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::TANH,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, 1.0),
+            (NEG_INF, -1.0),
+            (NAN, NAN),
+            (200.0, 1.0),
+            (-200.0, -1.0),
+        ],
+    ),
+    (
+        UnaryFn::ERF,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, 1.0),
+            (NEG_INF, -1.0),
+            (NAN, NAN),
+            (200.0, 1.0),
+            (-200.0, -1.0),
+        ],
+    ),
+    (
+        UnaryFn::ERFC,
+        &[
+            (0.0, 1.0),
+            (-0.0, 1.0),
+            (INF, 0.0),
+            (NEG_INF, 2.0),
+            (NAN, NAN),
+            (200.0, 0.0),
+            (-200.0, 2.0),
+        ],
+    ),
+    (
+        UnaryFn::TGAMMA,
+        &[
+            (0.0, INF),
+            (-0.0, NEG_INF),
+            (INF, INF),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, 1.0),
+            (2.0, 1.0),
+            (200.0, INF),
+            (-1.0, NAN),
+            (-2.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::LGAMMA,
+        &[
+            (0.0, INF),
+            (-0.0, INF),
+            (INF, INF),
+            (NEG_INF, INF),
+            (NAN, NAN),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (-1.0, INF),
+            (-2.0, INF),
+        ],
+    ),
+    (
+        UnaryFn::ASIN,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, NAN),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, FRAC_PI_2),
+            (-1.0, -FRAC_PI_2),
+            (2.0, NAN),
+            (-2.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::ACOS,
+        &[
+            (0.0, FRAC_PI_2),
+            (-0.0, FRAC_PI_2),
+            (INF, NAN),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, 0.0),
+            (-1.0, PI),
+            (2.0, NAN),
+            (-2.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::ATAN,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, FRAC_PI_2),
+            (NEG_INF, -FRAC_PI_2),
+            (NAN, NAN),
+        ],
+    ),
+    (
+        UnaryFn::SINH,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, NEG_INF),
+            (NAN, NAN),
+            (200.0, INF),
+            (-200.0, NEG_INF),
+        ],
+    ),
+    (
+        UnaryFn::COSH,
+        &[
+            (0.0, 1.0),
+            (-0.0, 1.0),
+            (INF, INF),
+            (NEG_INF, INF),
+            (NAN, NAN),
+            (200.0, INF),
+            (-200.0, INF),
+        ],
+    ),
+    (
+        UnaryFn::ASINH,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, NEG_INF),
+            (NAN, NAN),
+        ],
+    ),
+    (
+        UnaryFn::ACOSH,
+        &[
+            (0.0, NAN),
+            (-0.0, NAN),
+            (INF, INF),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, 0.0),
+            (-1.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::ATANH,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, NAN),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, INF),
+            (-1.0, NEG_INF),
+            (2.0, NAN),
+            (-2.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::SIGMOID,
+        &[
+            (0.0, 0.5),
+            (-0.0, 0.5),
+            (INF, 1.0),
+            (NEG_INF, 0.0),
+            (NAN, NAN),
+            (200.0, 1.0),
+            (-200.0, 0.0),
+        ],
+    ),
+    (
+        UnaryFn::SOFTPLUS,
+        &[
+            (0.0, LN_2),
+            (-0.0, LN_2),
+            (INF, INF),
+            (NEG_INF, 0.0),
+            (NAN, NAN),
+            (200.0, 200.0),
+            (-200.0, 0.0),
+        ],
+    ),
+    (
+        UnaryFn::SILU,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, -0.0),
+            (NAN, NAN),
+            (200.0, 200.0),
+            (-200.0, -0.0),
+        ],
+    ),
+    (
+        UnaryFn::GELU,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, -0.0),
+            (NAN, NAN),
+            (200.0, 200.0),
+            (-200.0, -0.0),
+        ],
+    ),
+    (
+        UnaryFn::GELU_TANH,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, -0.0),
+            (NAN, NAN),
+            (200.0, 200.0),
+            (-200.0, -0.0),
+        ],
+    ),
+    (
+        UnaryFn::SQRT,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, NAN),
+            (NAN, NAN),
+            (1.0, 1.0),
+            (-1.0, NAN),
+        ],
+    ),
+    (
+        UnaryFn::RELU,
+        &[
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (INF, INF),
+            (NEG_INF, 0.0),
+            (NAN, NAN),
+            (-1.0, 0.0),
+        ],
+    ),
+];
+
+// IEEE results of atan2 at the signed zeros and the infinities against finite values,
+// the quotient of two infinities goes through the polynomials of each tier
+const ATAN2_SPECIALS: [(f32, f32, f32); 14] = [
+    (0.0, 0.0, 0.0),
+    (-0.0, 0.0, -0.0),
+    (0.0, -0.0, PI),
+    (-0.0, -0.0, -PI),
+    (0.0, 1.0, 0.0),
+    (-0.0, 1.0, -0.0),
+    (0.0, -1.0, PI),
+    (-0.0, -1.0, -PI),
+    (1.0, 0.0, FRAC_PI_2),
+    (-1.0, -0.0, -FRAC_PI_2),
+    (1.0, NEG_INF, PI),
+    (-1.0, NEG_INF, -PI),
+    (INF, 1.0, FRAC_PI_2),
+    (NAN, 1.0, NAN),
+];
+
+// Every function and atan2 pinned to `accuracy` against the references
+fn check_tier(accuracy: Accuracy) {
+    for_each_backend(|backend| {
+        for (f, table) in FUNCTIONS {
+            let x: Vec<f32> = table.iter().map(|&(x, _)| x).collect();
+            let y = x.as_slice().simd_map(f.with_accuracy(accuracy));
+
+            for (&(x, want), &got) in table.iter().zip(&y) {
+                assert!(
+                    within(accuracy, got, want),
+                    "{:?} {:?}({:e}) = {:e}, expected {:e} ({:.2} ulp)",
+                    backend,
+                    f.with_accuracy(accuracy),
+                    x,
+                    got,
+                    want,
+                    ulp_error(got, want)
+                );
+            }
+        }
+
+        Accuracy::set_active(accuracy);
+        check_atan2(backend, accuracy);
+        Accuracy::set_active(Accuracy::Default);
+    });
+}
+
+// atan2 with `Accuracy::active` against the references
+fn check_atan2(backend: Backend, accuracy: Accuracy) {
+    let y: Vec<f32> = ATAN2.iter().map(|&(y, ..)| y).collect();
+    let x: Vec<f32> = ATAN2.iter().map(|&(_, x, _)| x).collect();
+    let got = y.as_slice().simd_atan2(x.as_slice());
+
+    for (&(y, x, want), &got) in ATAN2.iter().zip(&got) {
+        assert!(
+            within(accuracy, got, want),
+            "{:?} {:?} atan2({:e}, {:e}) = {:e}, expected {:e} ({:.2} ulp)",
+            backend,
+            accuracy,
+            y,
+            x,
+            got,
+            want,
+            ulp_error(got, want)
+        );
+    }
+}
+
+#[test]
+fn names() {
+    for accuracy in Accuracy::ALL {
+        assert_eq!(Accuracy::from_name(accuracy.name()), Some(accuracy));
+    }
+
+    assert_eq!(
+        Accuracy::from_name(" Correctly-Rounded "),
+        Some(Accuracy::CorrectlyRounded)
+    );
+    assert_eq!(
+        Accuracy::from_name("exact"),
+        Some(Accuracy::CorrectlyRounded)
+    );
+    assert_eq!(Accuracy::from_name("precise"), None);
+}
+
+#[test]
+fn fast() {
+    check_tier(Accuracy::Fast);
+}
+
+#[test]
+fn default() {
+    check_tier(Accuracy::Default);
+}
+
+#[test]
+fn correctly_rounded() {
+    check_tier(Accuracy::CorrectlyRounded);
+}
+
+#[test]
+fn special_values() {
+    for_each_backend(|backend| {
+        for accuracy in Accuracy::ALL {
+            for (f, cases) in SPECIALS {
+                let f = f.with_accuracy(accuracy);
+                check_exact(&format!("{:?}", f), backend, cases, |x| x.simd_map(f));
+            }
+
+            Accuracy::set_active(accuracy);
+
+            let y: Vec<f32> = ATAN2_SPECIALS.iter().map(|&(y, ..)| y).collect();
+            let x: Vec<f32> = ATAN2_SPECIALS.iter().map(|&(_, x, _)| x).collect();
+            let got = y.as_slice().simd_atan2(x.as_slice());
+
+            for (&(y, x, want), &got) in ATAN2_SPECIALS.iter().zip(&got) {
+                assert!(
+                    got.to_bits() == want.to_bits() || (got.is_nan() && want.is_nan()),
+                    "{:?} {:?} atan2({:e}, {:e}) = {:e}, expected {:e}",
+                    backend,
+                    accuracy,
+                    y,
+                    x,
+                    got,
+                    want
+                );
+            }
+        }
+
+        Accuracy::set_active(Accuracy::Default);
+    });
+}
+
+#[test]
+fn active() {
+    for_each_backend(|backend| {
+        let x: Vec<f32> = EXP.iter().map(|&(x, _)| x).collect();
+
+        for accuracy in Accuracy::ALL {
+            Accuracy::set_active(accuracy);
+
+            let unpinned = x.as_slice().simd_map(UnaryFn::EXP);
+            let pinned = x.as_slice().simd_map(UnaryFn::EXP.with_accuracy(accuracy));
+            assert_eq!(unpinned, pinned, "{:?} {:?}", backend, accuracy);
+
+            check_atan2(backend, accuracy);
+        }
+
+        Accuracy::set_active(Accuracy::Default);
+    });
+}
+
+const SIN: [(f32, f64); 28] = [
+    (-1.4631987e3, 7.057251204648483e-1),
+    (-3.65239e-1, -3.571725345910494e-1),
+    (-1.6753186e-2, -1.675240186749967e-2),
+    (-8.7671715e-4, -8.767170406930219e-4),
+    (-2.4098815e-16, -2.409881526832389e-16),
+    (-6.082324e-18, -6.082323977780498e-18),
+    (-1.6540267e-26, -1.6540266926154592e-26),
+    (-2.739433e-29, -2.7394331232466823e-29),
+    (0.0e0, 0.0e0),
+    (1.0e-30, 1.0000000031710769e-30),
+    (4.689213e-22, 4.689212878477542e-22),
+    (1.0e-4, 9.999999730712087e-5),
+    (9.379695e-3, 9.379557634697064e-3),
+    (4.0191866e-2, 4.018104643416049e-2),
+    (5.0e-1, 4.79425538604203e-1),
+    (7.853982e-1, 7.071067966408575e-1),
+    (1.5707964e0, 9.99999999999999e-1),
+    (3.1415927e0, -8.742278000372475e-8),
+    (1.0e2, -5.063656411097588e-1),
+    (2.5289821e2, 1.0e0),
+    (2.3585647e3, 6.971292929655021e-1),
+    (4.712389e3, 1.7977155917400206e-4),
+    (8.192e3, -9.561731528431463e-1),
+    (1.0e6, -3.4999350217129294e-1),
+    (8.0e7, 3.247992709920362e-1),
+    (1.0e15, 9.944343070894763e-1),
+    (1.0e30, -7.911634385219837e-1),
+    (f32::MAX, -5.218765233336585e-1),
+];
+
+const COS: [(f32, f64); 27] = [
+    (-7.950533e3, -6.690971122836241e-1),
+    (-1.247031e-11, 1.0e0),
+    (-2.0403787e-26, 1.0e0),
+    (-3.3608907e-27, 1.0e0),
+    (0.0e0, 1.0e0),
+    (2.3680642e-29, 1.0e0),
+    (2.64895e-24, 1.0e0),
+    (1.458694e-18, 1.0e0),
+    (4.2206034e-15, 1.0e0),
+    (5.8065074e-11, 1.0e0),
+    (3.7146446e-9, 1.0e0),
+    (1.0e-4, 9.999999950000003e-1),
+    (5.167554e-3, 9.999866482218549e-1),
+    (5.0e-1, 8.775825618903728e-1),
+    (7.853982e-1, 7.071067657322372e-1),
+    (1.5707964e0, -4.371139000186241e-8),
+    (3.1415927e0, -9.999999999999962e-1),
+    (1.0e2, 8.623188722876839e-1),
+    (2.5289821e2, -4.185706803757208e-9),
+    (4.712389e3, 9.999999838410931e-1),
+    (4.9330923e3, 7.025520049065506e-1),
+    (8.192e3, 2.928018131467037e-1),
+    (1.0e6, 9.367521275331447e-1),
+    (8.0e7, -9.45782973817483e-1),
+    (1.0e15, 1.0535847798574674e-1),
+    (1.0e30, -6.116047854178966e-1),
+    (f32::MAX, 8.530210398303042e-1),
+];
+
+const TAN: [(f32, f64); 27] = [
+    (-8.1118433e3, -2.5643952548277305e-1),
+    (-2.4357798e-16, -2.4357798238924854e-16),
+    (-7.019352e-17, -7.019352107729036e-17),
+    (-8.989308e-29, -8.989308108082804e-29),
+    (0.0e0, 0.0e0),
+    (1.0e-30, 1.0000000031710769e-30),
+    (1.5026305e-28, 1.5026305241340944e-28),
+    (3.9117694e-21, 3.911769428505946e-21),
+    (5.90691e-16, 5.906910253828767e-16),
+    (1.62403e-14, 1.6240299917044156e-14),
+    (1.3096273e-13, 1.3096273197843455e-13),
+    (9.132231e-10, 9.132231082453757e-10),
+    (2.116547e-9, 2.1165469377137924e-9),
+    (1.0e-4, 9.999999780712083e-5),
+    (5.0e-1, 5.463024898437905e-1),
+    (7.853982e-1, 1.000000043711391e0),
+    (1.0e0, 1.5574077246549023e0),
+    (1.5707964e0, -2.287733242885646e7),
+    (3.1415927e0, 8.742278000372508e-8),
+    (2.5289821e2, -2.3890827687748507e8),
+    (4.7634136e3, 9.487615190833589e-1),
+    (7.644274e3, 9.87284058028932e-1),
+    (1.0e6, -3.73624453987599e-1),
+    (8.0e7, -3.43418395111346e-1),
+    (1.0e15, 9.43857889845378e0),
+    (1.0e30, 1.2935860826880194e0),
+    (f32::MAX, -6.117979498342481e-1),
+];
+
+const EXP: [(f32, f64); 22] = [
+    (-1.03e2, 1.8521167695179754e-45),
+    (-8.795526e1, 6.331628033028485e-39),
+    (-8.733655e1, 1.1754907056089184e-38),
+    (-8.53089e1, 8.92926967288542e-38),
+    (-7.490621e1, 2.9420193984260855e-33),
+    (-7.1062744e1, 1.3735432874098397e-31),
+    (-6.1079647e1, 2.974721575736548e-27),
+    (-3.1569447e1, 1.9478861095659052e-14),
+    (-2.0e1, 2.061153622438558e-9),
+    (-1.0e0, 3.6787944117144233e-1),
+    (-3.465736e-1, 7.071067805131506e-1),
+    (0.0e0, 1.0e0),
+    (1.0e-7, 1.0000001000000063e0),
+    (3.465736e-1, 1.414213563719889e0),
+    (1.0e0, std::f64::consts::E),
+    (6.1448627e0, 4.663155970682222e2),
+    (2.0e1, 4.851651954097903e8),
+    (2.7739372e1, 1.114438998002588e12),
+    (4.129973e1, 8.634638210208876e17),
+    (6.549918e1, 2.7921196845816735e28),
+    (7.855283e1, 1.3033532875222678e34),
+    (8.8e1, 1.6516362549940018e38),
+];
+
+const EXP2: [(f32, f64); 21] = [
+    (-1.49e2, 1.401298464324817e-45),
+    (-1.26912415e2, 6.245344479086958e-39),
+    (-1.26e2, 1.1754943508222875e-38),
+    (-6.515845e1, 2.4285827288575466e-20),
+    (-5.1042553e1, 4.311818855872009e-16),
+    (-2.5279789e1, 2.4548515563910697e-8),
+    (-2.426373e1, 4.9646625606125703e-8),
+    (-2.272547e1, 1.4419559323136274e-7),
+    (-1.2471088e1, 1.7612796213733916e-4),
+    (-1.05e1, 6.905339660024879e-4),
+    (-1.0e0, 5.0e-1),
+    (0.0e0, 1.0e0),
+    (1.0e-7, 1.0000000693147213e0),
+    (2.4126092e-1, 1.1820253006463772e0),
+    (5.0e-1, std::f64::consts::SQRT_2),
+    (1.0e0, 2.0e0),
+    (1.0e1, 1.024e3),
+    (2.177455e1, 3.5875051800772003e6),
+    (3.9048775e1, 5.686597429820604e11),
+    (1.2317817e2, 1.2031610259975854e37),
+    (1.27e2, 1.7014118346046923e38),
+];
+
+const EXPM1: [(f32, f64); 22] = [
+    (-2.0e1, -9.999999979388464e-1),
+    (-1.8266106e1, -9.999999883283808e-1),
+    (-8.041731e0, -9.996782484457022e-1),
+    (-1.0e0, -6.321205588285577e-1),
+    (-3.494235e-1, -2.949055390027882e-1),
+    (-1.0e-3, -9.99500214074986e-4),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (0.0e0, 0.0e0),
+    (1.0e-7, 1.0000000616861003e-7),
+    (3.494235e-1, 4.182496889646597e-1),
+    (3.87331e-1, 4.7304400127451934e-1),
+    (1.0e0, 1.7182818284590453e0),
+    (4.7908716e0, 1.1940627147467232e2),
+    (2.0e1, 4.851651944097903e8),
+    (2.5064287e1, 7.678592450709004e10),
+    (4.1143387e1, 7.384921305402842e17),
+    (4.589481e1, 8.547962547504048e19),
+    (5.6484924e1, 3.3969639762653e24),
+    (6.062692e1, 2.137648461051227e26),
+    (6.160643e1, 5.6928877687167835e26),
+    (6.698284e1, 1.2310577939378847e29),
+    (8.8e1, 1.6516362549940018e38),
+];
+
+const LN: [(f32, f64); 22] = [
+    (1.0e-45, -1.0327892990343184e2),
+    (1.0e-40, -9.210340910966488e1),
+    (1.0e-38, -8.749823359872809e1),
+    (1.5844732e-28, -6.401213064427472e1),
+    (3.5883042e-23, -5.168177740970084e1),
+    (1.3261953e-19, -4.346680261416831e1),
+    (4.003041e-13, -2.8546551857443426e1),
+    (2.674274e-12, -2.664734317552192e1),
+    (5.0e-1, -std::f64::consts::LN_2),
+    (7.071068e-1, -3.4657352310054895e-1),
+    (9.9999994e-1, -5.960464655174753e-8),
+    (1.0e0, 0.0e0),
+    (1.0000001e0, 1.1920928244535446e-7),
+    (1.3954898e0, 3.332454732848794e-1),
+    (std::f32::consts::SQRT_2, 3.465735731657015e-1),
+    (2.0e0, std::f64::consts::LN_2),
+    (1.0e1, std::f64::consts::LN_10),
+    (2.1083912e5, 1.2258850680894131e1),
+    (1.5355001e10, 2.34547070450235e1),
+    (1.4920289e25, 5.796476421200182e1),
+    (6.8765613e28, 6.640050132237488e1),
+    (8.104405e33, 7.807771577577003e1),
+];
+
+const LOG2: [(f32, f64); 21] = [
+    (1.0e-45, -1.49e2),
+    (1.0e-38, -1.262332676994291e2),
+    (2.1209822e-25, -8.196346985021235e1),
+    (2.2429343e-24, -7.856088692157665e1),
+    (3.4131204e-20, -6.466747059112865e1),
+    (4.100632e-12, -3.782729080352448e1),
+    (1.039328e-5, -1.6553989476927313e1),
+    (6.3225003e-3, -7.305289085245393e0),
+    (5.0e-1, -1.0e0),
+    (7.071068e-1, -4.999999030805785e-1),
+    (9.9999994e-1, -8.599132799414562e-8),
+    (1.0e0, 0.0e0),
+    (1.0000001e0, 1.7198264061184464e-7),
+    (1.396743e0, 4.8206665111868663e-1),
+    (2.0e0, 1.0e0),
+    (1.0e1, std::f64::consts::LOG2_10),
+    (1.024e3, 1.0e1),
+    (2.3410997e10, 3.444646733971401e1),
+    (4.4959076e11, 3.8709821414329674e1),
+    (4.638655e17, 5.8686484177050595e1),
+    (5.263876e35, 1.1866360880830746e2),
+];
+
+const LOG10: [(f32, f64); 20] = [
+    (1.0e-45, -4.48534693539332e1),
+    (1.0e-38, -3.800000002820932e1),
+    (1.241565e-25, -2.490603053669646e1),
+    (1.9539936e-19, -1.870907686917339e1),
+    (4.4513355e-19, -1.8351509674878788e1),
+    (5.566562e-16, -1.525441296286712e1),
+    (1.5824954e-8, -7.800657549429817e0),
+    (5.0e-1, -std::f64::consts::LOG10_2),
+    (9.9999994e-1, -2.588596909321764e-8),
+    (1.0e0, 0.0e0),
+    (1.0000001e0, 5.1771933557663626e-8),
+    (1.3329226e0, 1.2480492436842919e-1),
+    (2.0e0, std::f64::consts::LOG10_2),
+    (1.0e1, 1.0e0),
+    (1.0e3, 3.0e0),
+    (3.3626904e7, 7.526686884037471e0),
+    (1.8128603e17, 1.7258364348187172e1),
+    (3.627814e28, 2.8559645014633347e1),
+    (4.7877e30, 3.0680126916429032e1),
+    (1.0988755e32, 3.204094847671515e1),
+];
+
+const LOG1P: [(f32, f64); 19] = [
+    (-5.0e-1, -std::f64::consts::LN_2),
+    (-4.8891193e-1, -6.712133489260181e-1),
+    (-3.0142874e-1, -3.587180800866308e-1),
+    (-2.9289323e-1, -3.465736073942438e-1),
+    (-1.0e-7, -1.000000061686102e-7),
+    (-4.7938407e-11, -4.793840671761248e-11),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (0.0e0, 0.0e0),
+    (1.0e-30, 1.0000000031710769e-30),
+    (1.3702913e-30, 1.3702912561094808e-30),
+    (2.0516012e-11, 2.0516012499761028e-11),
+    (7.9480623e-11, 7.948062346846973e-11),
+    (1.0e-7, 9.999999616860996e-8),
+    (1.7415587e-7, 1.7415585678078141e-7),
+    (4.1421357e-1, 3.4657359423912587e-1),
+    (1.0e0, std::f64::consts::LN_2),
+    (1.0e10, 2.3025850930040455e1),
+    (2.098816e24, 5.600341563911104e1),
+    (2.035596e28, 6.518317120854994e1),
+];
+
+const TANH: [(f32, f64); 21] = [
+    (-1.0e1, -9.999999958776927e-1),
+    (-8.860642e0, -9.999999597492704e-1),
+    (-8.597558e0, -9.999999318782231e-1),
+    (-4.027713e0, -9.993654508354336e-1),
+    (-3.7210498e0, -9.988285796269994e-1),
+    (-2.805528e0, -9.927122411263097e-1),
+    (-2.7987978e0, -9.92613848294897e-1),
+    (-5.234748e-1, -4.8037742677361545e-1),
+    (-2.3882398e-1, -2.3438464242889714e-1),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (0.0e0, 0.0e0),
+    (1.0e-4, 9.999999714045421e-5),
+    (4.216314e-2, 4.213817392546043e-2),
+    (5.072128e-1, 4.677707190582021e-1),
+    (6.25e-1, 5.545997223493823e-1),
+    (7.0e-1, 6.043677695504779e-1),
+    (1.0e0, 7.615941559557649e-1),
+    (5.0e0, 9.999092042625951e-1),
+    (5.5433035e0, 9.999693682993035e-1),
+    (8.264295e0, 9.999998673354579e-1),
+    (9.0e0, 9.99999969540041e-1),
+];
+
+const ERF: [(f32, f64); 21] = [
+    (-5.0e0, -9.999999999984626e-1),
+    (-4.4788265e0, -9.999999997611412e-1),
+    (-4.034887e0, -9.999999884468116e-1),
+    (-3.7007015e0, -9.999998337439958e-1),
+    (-3.6953886e0, -9.999998268478729e-1),
+    (-7.321977e-1, -6.995570280486806e-1),
+    (-1.0e-30, -1.1283791706736896e-30),
+    (0.0e0, 0.0e0),
+    (1.0e-8, 1.1283791602378209e-8),
+    (2.1031536e-1, 2.3386239602608513e-1),
+    (5.0e-1, 5.204998778130465e-1),
+    (7.5e-1, 7.111556336535151e-1),
+    (8.264497e-1, 7.575062541103335e-1),
+    (1.0e0, 8.427007929497149e-1),
+    (1.7036985e0, 9.840209431130966e-1),
+    (2.0e0, 9.953222650189527e-1),
+    (2.0952032e0, 9.969540748770164e-1),
+    (3.5e0, 9.999992569016276e-1),
+    (3.7819784e0, 9.999999113261209e-1),
+    (3.9555604e0, 9.999999778112291e-1),
+    (4.5e0, 9.999999998033839e-1),
+];
+
+const ERFC: [(f32, f64); 23] = [
+    (-5.0e0, 1.9999999999984626e0),
+    (-4.312528e0, 1.9999999989315622e0),
+    (-3.398432e0, 1.9999984610371249e0),
+    (-1.0e0, 1.8427007929497148e0),
+    (-3.0e-1, 1.3286267717527154e0),
+    (-2.92412e-1, 1.320783875282412e0),
+    (0.0e0, 1.0e0),
+    (1.0e-6, 9.999988716208358e-1),
+    (4.9e-1, 4.88331730347372e-1),
+    (5.0e-1, 4.795001221869535e-1),
+    (1.0e0, 1.5729920705028513e-1),
+    (3.0e0, 2.209049699858544e-5),
+    (5.5e0, 7.357847917974398e-15),
+    (7.229625e0, 1.5444404558414798e-24),
+    (7.761736e0, 4.943795003865387e-28),
+    (7.9818187e0, 1.5042693362012363e-29),
+    (8.542445e0, 1.3334744525768356e-33),
+    (8.63203e0, 2.8335019455868562e-34),
+    (9.214068e0, 8.189562049160057e-39),
+    (9.218851e0, 7.494644786291902e-39),
+    (9.642029e0, 2.449907290825759e-42),
+    (9.764842e0, 2.2315335452058774e-43),
+    (1.0e1, 2.088487583762545e-45),
+];
+
+// skip TGAMMA 24.781692504882812
+const TGAMMA: [(f32, f64); 19] = [
+    (5.0e-2, 1.9470085013906726e1),
+    (5.0e-1, 1.772453850905516e0),
+    (1.0e0, 1.0e0),
+    (1.1771383e0, 9.245649533975513e-1),
+    (1.4616321e0, 8.856031944108887e-1),
+    (2.068912e0, 1.0311190733352669e0),
+    (2.5e0, 1.329340388179137e0),
+    (2.6357784e0, 1.468998606748546e0),
+    (3.0091565e0, 2.017003833648083e0),
+    (5.5e0, 5.234277778455352e1),
+    (1.0e1, 3.6288e5),
+    (1.6863665e1, 1.428465082251838e13),
+    (2.45e1, 1.2599063430729375e23),
+    (2.6238638e1, 3.363485233456157e25),
+    (2.659053e1, 1.0573369476973458e26),
+    (3.3154716e1, 4.510735442547755e35),
+    (3.33e1, 7.487557657016626e35),
+    (3.394103e1, 7.059488820146932e36),
+    (3.5e1, 2.9523279903960416e38),
+];
+
+const LGAMMA: [(f32, f64); 19] = [
+    (-1.0e-40, 9.210340910966488e1),
+    (-1.0e-45, 1.0327892990343184e2),
+    (3.0e0, std::f64::consts::LN_2),
+    (3.5e0, 1.2009736023470743e0),
+    (5.5e0, 3.9578139676187165e0),
+    (1.0e1, 1.2801827480081469e1),
+    (1.0e2, 3.591342053695754e2),
+    (7.7777104e3, 6.189937136021828e4),
+    (1.0e4, 8.209971749644238e4),
+    (5.55095e5, 6.787082335308242e6),
+    (2.505936e8, 4.595721990638765e9),
+    (1.0e10, 2.2025850928881058e11),
+    (2.0071829e11, 5.023008889964636e12),
+    (7.6578705e11, 2.0189339830988062e13),
+    (1.3506202e12, 3.6374341386824266e13),
+    (7.711276e18, 3.276460586318835e20),
+    (2.697474e20, 1.2420253675659322e22),
+    (2.9905758e27, 1.8620899127440524e29),
+    (3.812051e29, 2.5583869413723807e31),
+];
+
+const ASIN: [(f32, f64); 22] = [
+    (-1.0e0, -std::f64::consts::FRAC_PI_2),
+    (-8.028089e-1, -9.319914060343104e-1),
+    (-7.002703e-1, -7.757760557600175e-1),
+    (-6.4877087e-1, -7.05968135334347e-1),
+    (-5.0e-1, -std::f64::consts::FRAC_PI_6),
+    (-3.853858e-1, -3.9562589391299746e-1),
+    (-1.03966966e-1, -1.0415518196159639e-1),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (0.0e0, 0.0e0),
+    (1.0e-4, 9.999999764045418e-5),
+    (5.2595492e-3, 5.2595734945677175e-3),
+    (7.475589e-2, 7.482569602199879e-2),
+    (7.997009e-2, 8.005557548232893e-2),
+    (2.268158e-1, 2.2880702800181435e-1),
+    (5.0e-1, std::f64::consts::FRAC_PI_6),
+    (6.9377166e-1, 7.66712931226779e-1),
+    (7.071068e-1, 7.853982305768765e-1),
+    (8.515942e-1, 1.0190190374955304e0),
+    (8.720138e-1, 1.0593015304102962e0),
+    (9.0e-1, 1.119769460301664e0),
+    (9.9999994e-1, 1.5704510598101804e0),
+    (1.0e0, std::f64::consts::FRAC_PI_2),
+];
+
+const ACOS: [(f32, f64); 22] = [
+    (-1.0e0, std::f64::consts::PI),
+    (-9.9999994e-1, 3.141247386605077e0),
+    (-8.693912e-1, 2.624765236254263e0),
+    (-7.3543966e-1, 2.3971116350414303e0),
+    (-6.3689315e-1, 2.2612579678294873e0),
+    (-6.1081207e-1, 2.227882141419026e0),
+    (-5.5810267e-1, 2.1628937924227163e0),
+    (-5.0e-1, 2.0943951023931957e0),
+    (-1.4498968e-1, 1.716298873346244e0),
+    (-4.3376908e-2, 1.614186848712509e0),
+    (0.0e0, std::f64::consts::FRAC_PI_2),
+    (1.0e-4, 1.5706963267972562e0),
+    (5.0e-1, std::f64::consts::FRAC_PI_3),
+    (5.7550013e-1, 9.57580755316524e-1),
+    (6.9319713e-1, 8.04880830459023e-1),
+    (6.980709e-1, 7.980965696872734e-1),
+    (7.071068e-1, 7.8539809621802e-1),
+    (7.4208236e-1, 7.346247225356632e-1),
+    (9.0e-1, 4.5102686649323265e-1),
+    (9.8574144e-1, 1.6907144873807484e-1),
+    (9.9999994e-1, 3.452669847162036e-4),
+    (1.0e0, 0.0e0),
+];
+
+const ATAN: [(f32, f64); 22] = [
+    (-1.0e6, -1.5707953267948966e0),
+    (-1.0092655e0, -7.900095381310822e-1),
+    (-1.0e0, -std::f64::consts::FRAC_PI_4),
+    (-5.211978e-1, -4.8046167907061416e-1),
+    (-1.6096947e-5, -1.6096946636454364e-5),
+    (-3.7719244e-22, -3.771924408144591e-22),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (0.0e0, 0.0e0),
+    (2.5270593e-29, 2.527059323646333e-29),
+    (2.3266808e-24, 2.3266807505703725e-24),
+    (1.7542106e-18, 1.7542106104376376e-18),
+    (1.1134519e-14, 1.11345191422009e-14),
+    (4.5146815e-14, 4.514681543107274e-14),
+    (1.3421625e-13, 1.3421624651521052e-13),
+    (1.0e-4, 9.999999714045421e-5),
+    (5.0e-1, 4.636476090008061e-1),
+    (9.7598994e-1, 7.732478582706163e-1),
+    (9.821473e-1, 7.763916475413032e-1),
+    (1.0e0, std::f64::consts::FRAC_PI_4),
+    (2.0e0, 1.1071487177940904e0),
+    (6.3217656e3, 1.570638143140048e0),
+    (1.0e6, 1.5707953267948966e0),
+];
+
+const SINH: [(f32, f64); 21] = [
+    (-8.9e1, -2.2448064095871728e38),
+    (-8.518114e1, -4.927967132242595e36),
+    (-8.510132e1, -4.5499117220591915e36),
+    (-6.881467e1, -3.844147533827315e29),
+    (-6.304466e1, -1.1992711166871929e27),
+    (-4.251072e1, -1.4492416865837284e18),
+    (-4.1570972e1, -5.6625746909008826e17),
+    (-2.5078539e1, -3.894404518837911e10),
+    (-1.0e0, -1.1752011936438014e0),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (0.0e0, 0.0e0),
+    (1.0e-4, 9.999999764045416e-5),
+    (5.0e-1, 5.210953054937474e-1),
+    (9.9e-1, 1.15982890526823e0),
+    (1.0e0, 1.1752011936438014e0),
+    (7.8571663e0, 1.292093383496073e3),
+    (2.7607183e1, 4.8822210666306573e11),
+    (4.712499e1, 1.4624886149841386e20),
+    (5.225894e1, 2.4816469287939697e22),
+    (7.862249e1, 6.986884018617348e33),
+    (8.9e1, 2.2448064095871728e38),
+];
+
+const COSH: [(f32, f64); 19] = [
+    (-8.9e1, 2.2448064095871728e38),
+    (-8.342608e1, 8.520315417890835e35),
+    (-4.4503555e1, 1.0632086353325509e19),
+    (-4.324881e1, 3.0317225611058463e18),
+    (-4.1570972e1, 5.6625746909008826e17),
+    (-4.142856e1, 4.91093616278531e17),
+    (-3.3113808e1, 1.2025799361365792e14),
+    (-1.33162775e1, 3.034980072038009e5),
+    (-1.2197683e0, 1.8408507743858742e0),
+    (-1.0e0, 1.5430806348152437e0),
+    (0.0e0, 1.0e0),
+    (1.0e-4, 1.0000000049999997e0),
+    (5.0e-1, 1.1276259652063807e0),
+    (1.0e0, 1.5430806348152437e0),
+    (1.08114605e1, 2.4792917702144026e4),
+    (4.712499e1, 1.4624886149841386e20),
+    (6.628654e1, 3.0679432502314426e28),
+    (6.902942e1, 4.7650311194544596e29),
+    (8.9e1, 2.2448064095871728e38),
+];
+
+const ASINH: [(f32, f64); 18] = [
+    (-8.857528e29, -6.964938262199665e1),
+    (-5.2051354e28, -6.681517550439706e1),
+    (-8.077518e25, -6.034685920233482e1),
+    (-3.0101075e8, -2.021580372356535e1),
+    (-3.4548908e-1, -3.389609044414093e-1),
+    (-8.3389634e-23, -8.338963432113552e-23),
+    (-1.611361e-27, -1.611360969519166e-27),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (0.0e0, 0.0e0),
+    (1.0992009e-26, 1.0992008653392978e-26),
+    (1.3848852e-24, 1.3848851565911999e-24),
+    (1.0e-4, 9.999999730712087e-5),
+    (1.4647953e-1, 1.4596070908342854e-1),
+    (3.375e-1, 3.3140052524896463e-1),
+    (5.0e-1, 4.8121182505960347e-1),
+    (1.0e0, 8.81373587019543e-1),
+    (4.096e3, 9.01091336218045e0),
+    (3.9099538e5, 1.3569598190814624e1),
+];
+
+const ACOSH: [(f32, f64); 16] = [
+    (1.0e0, 0.0e0),
+    (1.0000001e0, 4.882812451493617e-4),
+    (1.0558199e0, 3.3259029899307907e-1),
+    (1.5e0, 9.624236501192069e-1),
+    (2.0e0, 1.3169578969248168e0),
+    (4.096e3, 9.010913332378127e0),
+    (2.7999188e4, 1.0933077951120222e1),
+    (8.598632e7, 1.896284595235735e1),
+    (1.2212347e11, 2.6221445589817314e1),
+    (1.8015368e11, 2.661022328120291e1),
+    (5.00635e12, 2.9934875421179846e1),
+    (1.5610528e14, 3.337469897349207e1),
+    (5.5828103e22, 5.3069711508034295e1),
+    (6.3861304e22, 5.320414773900497e1),
+    (4.0124486e25, 5.964717618632042e1),
+    (1.27509e29, 6.771113167811573e1),
+];
+
+const ATANH: [(f32, f64); 16] = [
+    (-9.6602553e-1, -2.029079982427694e0),
+    (-4.7647074e-1, -5.184084792907682e-1),
+    (-4.4161576e-1, -4.742362461742568e-1),
+    (-1.6537948e-1, -1.6691244570522543e-1),
+    (-1.4560325e-1, -1.4664548892553614e-1),
+    (-1.1939131e-1, -1.19963488092359e-1),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (0.0e0, 0.0e0),
+    (1.0e-4, 9.999999780712083e-5),
+    (9.114187e-2, 9.139550445215337e-2),
+    (1.6767813e-1, 1.6927666412542094e-1),
+    (5.0e-1, 5.493061443340549e-1),
+    (7.3898697e-1, 9.482438438169741e-1),
+    (7.5784993e-1, 9.911445303460789e-1),
+    (7.732509e-1, 1.0283627888129787e0),
+    (9.8234504e-1, 2.360509643197892e0),
+];
+
+const SIGMOID: [(f32, f64); 20] = [
+    (-8.0e1, 1.8048513878454153e-35),
+    (-7.033997e1, 2.8296822695501557e-31),
+    (-6.403978e1, 1.5412641260077218e-28),
+    (-5.2972813e1, 9.867332754341107e-24),
+    (-2.8740349e1, 3.297805980630243e-13),
+    (-2.8064266e1, 6.484015563009699e-13),
+    (-1.7e1, 4.1399375473943306e-8),
+    (-1.0e0, 2.689414213699951e-1),
+    (0.0e0, 5.0e-1),
+    (1.0e-8, 5.000000025e-1),
+    (1.0e0, 7.310585786300049e-1),
+    (4.7885475e0, 9.917441859858521e-1),
+    (5.4925647e0, 9.958996112659e-1),
+    (1.7e1, 9.999999586006245e-1),
+    (4.3562336e1, 1.0e0),
+    (5.415408e1, 1.0e0),
+    (5.8793377e1, 1.0e0),
+    (6.6066154e1, 1.0e0),
+    (7.9073265e1, 1.0e0),
+    (8.0e1, 1.0e0),
+];
+
+const SOFTPLUS: [(f32, f64); 19] = [
+    (-8.0e1, 1.8048513878454153e-35),
+    (-7.452581e1, 4.303797735022478e-33),
+    (-6.119548e1, 2.6493575169242094e-27),
+    (-3.6154545e1, 1.9873789451335404e-16),
+    (-3.2983795e1, 4.734997643187559e-15),
+    (-2.5567123e1, 7.876610744957589e-12),
+    (-1.7e1, 4.139937633089748e-8),
+    (-1.6e1, 1.1253516838717681e-7),
+    (-6.6053505e0, 1.3521942066941062e-3),
+    (-1.0e0, 3.132616875182228e-1),
+    (0.0e0, std::f64::consts::LN_2),
+    (2.980373e-8, 6.9314719546181e-1),
+    (1.0e0, 1.3132616875182228e0),
+    (7.9367456e0, 7.937102947364579e0),
+    (1.6e1, 1.600000011253517e1),
+    (2.1268896e1, 2.126889610348475e1),
+    (3.1403116e1, 3.140311622619631e1),
+    (6.3144505e1, 6.314450454711914e1),
+    (8.0e1, 8.0e1),
+];
+
+const SILU: [(f32, f64); 20] = [
+    (-8.0e1, -1.4438811102763322e-33),
+    (-7.600483e1, -7.453550172925458e-32),
+    (-4.3386204e1, -6.236813612975642e-18),
+    (-2.345549e1, -1.526351599055311e-9),
+    (-2.2536388e1, -3.6766746079738107e-9),
+    (-2.252705e1, -3.709631808216737e-9),
+    (-1.7e1, -7.037893830570362e-7),
+    (-1.0e0, -2.689414213699951e-1),
+    (-1.0e-30, -5.000000015855384e-31),
+    (0.0e0, 0.0e0),
+    (1.0e-8, 4.999999994612645e-9),
+    (1.0e0, 7.310585786300049e-1),
+    (1.7682837e1, 1.768283616277005e1),
+    (3.6109352e1, 3.61093521118164e1),
+    (4.3663265e1, 4.3663265228271484e1),
+    (4.885448e1, 4.88544807434082e1),
+    (6.095527e1, 6.095526885986328e1),
+    (7.033744e1, 7.033744049072266e1),
+    (7.79803e1, 7.798030090332031e1),
+    (8.0e1, 8.0e1),
+];
+
+const GELU: [(f32, f64); 20] = [
+    (-1.0e1, -7.619853024160526e-23),
+    (-2.904259e0, -5.345630334046412e-3),
+    (-2.0070858e0, -4.489903914940556e-2),
+    (-1.0e0, -1.5865525393145705e-1),
+    (-5.0e-1, -1.5426876936299344e-1),
+    (-1.0e-30, -5.000000015855384e-31),
+    (0.0e0, 0.0e0),
+    (1.0e-8, 5.000000009506873e-9),
+    (5.0e-1, 3.4573123063700656e-1),
+    (7.0e-1, 5.306254318017016e-1),
+    (1.0e0, 8.413447460685429e-1),
+    (1.7813885e0, 1.7147209445638147e0),
+    (2.0277848e0, 1.984611040840514e0),
+    (2.8561637e0, 2.8500402116942047e0),
+    (3.7140222e0, 3.713643346178036e0),
+    (3.7205234e0, 3.7201535181110486e0),
+    (4.311615e0, 4.31158005182688e0),
+    (6.844818e0, 6.844818115208168e0),
+    (9.571275e0, 9.571274757385254e0),
+    (1.0e1, 1.0e1),
+];
+
+const GELU_TANH: [(f32, f64); 18] = [
+    (-1.0e1, -1.204092348209806e-37),
+    (-9.249015e0, -1.0907080760295518e-30),
+    (-1.0e0, -1.588080093917233e-1),
+    (-5.0e-1, -1.5428599017485609e-1),
+    (-1.0e-30, -5.000000015855384e-31),
+    (0.0e0, 0.0e0),
+    (1.0e-8, 5.000000009506873e-9),
+    (9.485002e-1, 7.857572580035496e-1),
+    (1.0e0, 8.411919906082767e-1),
+    (1.3080043e0, 1.1829425381111776e0),
+    (1.4627442e0, 1.3575396655678744e0),
+    (1.5496356e0, 1.4555019316087308e0),
+    (4.223364e0, 4.223340740760756e0),
+    (7.3121886e0, 7.312188625335693e0),
+    (7.851583e0, 7.851583003997803e0),
+    (8.690282e0, 8.690281867980957e0),
+    (9.263421e0, 9.263421058654785e0),
+    (1.0e1, 1.0e1),
+];
+
+const SQRT: [(f32, f64); 16] = [
+    (0.0e0, 0.0e0),
+    (1.0e-45, 3.743392130574644e-23),
+    (1.0e-30, 1.0000000015855385e-15),
+    (4.2264946e-17, 6.501149559857061e-9),
+    (2.1435951e-13, 4.6298975029249936e-7),
+    (1.6580735e-8, 1.2876620289194686e-4),
+    (1.2756167e-2, 1.1294320245003912e-1),
+    (5.0e-1, std::f64::consts::FRAC_1_SQRT_2),
+    (1.0e0, 1.0e0),
+    (2.0e0, std::f64::consts::SQRT_2),
+    (3.4669287e2, 1.861969041347761e1),
+    (1.404429e3, 3.747571153531478e1),
+    (1.5576314e4, 1.2480510587762426e2),
+    (5.8339475e5, 7.638028214140087e2),
+    (1.0581067e25, 3.25285526709372e12),
+    (2.6358988e25, 5.134100545495025e12),
+];
+
+const RELU: [(f32, f64); 17] = [
+    (-1.0e1, 0.0e0),
+    (-5.5646496e0, 0.0e0),
+    (-4.644772e0, 0.0e0),
+    (-3.1492524e0, 0.0e0),
+    (-1.8653419e0, 0.0e0),
+    (-1.5027795e0, 0.0e0),
+    (-1.0e0, 0.0e0),
+    (-5.75904e-1, 0.0e0),
+    (-1.0e-30, 0.0e0),
+    (0.0e0, 0.0e0),
+    (1.0e-30, 1.0000000031710769e-30),
+    (1.0e0, 1.0e0),
+    (1.862962e0, 1.862962007522583e0),
+    (7.5174756e0, 7.517475605010986e0),
+    (8.847832e0, 8.847831726074219e0),
+    (9.922875e0, 9.92287540435791e0),
+    (1.0e1, 1.0e1),
+];
+
+const ATAN2: [(f32, f32, f64); 23] = [
+    (1.0e0, 1.0e0, std::f64::consts::FRAC_PI_4),
+    (-1.0e0, 1.0e0, -std::f64::consts::FRAC_PI_4),
+    (1.0e0, -1.0e0, 2.356194490192345e0),
+    (-1.0e0, -1.0e0, -2.356194490192345e0),
+    (5.0e-1, 2.0e0, 2.4497866312686414e-1),
+    (2.0e0, 5.0e-1, 1.3258176636680326e0),
+    (-9.4930154e-1, 9.4217205e-1, -7.891674238536949e-1),
+    (-3.396987e-1, 3.2986146e-1, -8.000891990710235e-1),
+    (1.0e-30, 1.0e0, 1.0000000031710769e-30),
+    (1.0e0, 1.0e-30, std::f64::consts::FRAC_PI_2),
+    (1.0e-30, -1.0e0, std::f64::consts::PI),
+    (3.0e38, 1.0e0, std::f64::consts::FRAC_PI_2),
+    (1.0e0, 3.0e38, 3.333333327224716e-39),
+    (-5.0e0, -1.0e-3, -1.5709963268017295e0),
+    (7.0e0, -3.0e0, 1.97568811307998e0),
+    (6.732115e14, -1.161268e-10, std::f64::consts::FRAC_PI_2),
+    (-5.510191e4, 5.468413e15, -1.0076398742621069e-11),
+    (1.8043025e-7, 1.1893011e3, 1.5171115314651266e-10),
+    (3.0607925e6, -2.6792154e0, 1.570797202128795e0),
+    (-2.1991182e4, -8.216332e-12, -1.570796326794897e0),
+    (8.6427495e-2, 2.7378011e16, 3.1568215032419336e-18),
+    (-1.0988395e-14, -1.0637853e-3, -3.1415926535794636e0),
+    (1.6580141e-12, 1.1833677e-15, 1.5700826009598488e0),
+];
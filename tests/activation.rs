@@ -0,0 +1,293 @@
+//! Accuracy of the activation functions against 50 digit references from mpmath,
+//! on every backend the CPU supports
+
+mod common;
+
+use arithmetics::ops::activation::SimdActivation;
+use arithmetics::simd::backend::Backend;
+use common::{check, check_exact, for_each_backend};
+
+// Every 1 / 1024 over [lo, hi] against an f64 evaluation, whose own error is far
+// below the f32 spacing
+fn sweep(
+    name: &str,
+    backend: Backend,
+    (lo, hi): (f32, f32),
+    ulp: f64,
+    f: impl Fn(&[f32]) -> Vec<f32>,
+    reference: impl Fn(f64) -> f64,
+) {
+    let n = ((hi - lo) * 1024.0) as usize;
+    let x: Vec<f32> = (0..=n).map(|i| lo + i as f32 / 1024.0).collect();
+    let table: Vec<(f32, f64)> = x.iter().map(|&x| (x, reference(x as f64))).collect();
+
+    check(name, backend, &table, ulp, f);
+}
+
+#[test]
+fn sigmoid() {
+    for_each_backend(|backend| {
+        check("sigmoid", backend, &SIGMOID, 1.0, |x| x.simd_sigmoid());
+        sweep(
+            "sigmoid",
+            backend,
+            (-80.0, 80.0),
+            1.0,
+            |x| x.simd_sigmoid(),
+            |x| 1.0 / (1.0 + (-x).exp()),
+        );
+        check_exact(
+            "sigmoid",
+            backend,
+            &[
+                (0.0, 0.5),
+                (-0.0, 0.5),
+                (f32::INFINITY, 1.0),
+                (f32::NEG_INFINITY, 0.0),
+                (-200.0, 0.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_sigmoid(),
+        );
+    });
+}
+
+#[test]
+fn softplus() {
+    for_each_backend(|backend| {
+        check("softplus", backend, &SOFTPLUS, 1.0, |x| x.simd_softplus());
+        sweep(
+            "softplus",
+            backend,
+            (-80.0, 80.0),
+            1.0,
+            |x| x.simd_softplus(),
+            |x| x.max(0.0) + (-x.abs()).exp().ln_1p(),
+        );
+        check_exact(
+            "softplus",
+            backend,
+            &[
+                (0.0, std::f32::consts::LN_2),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, 0.0),
+                (-200.0, 0.0),
+                (200.0, 200.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_softplus(),
+        );
+    });
+}
+
+#[test]
+fn silu() {
+    for_each_backend(|backend| {
+        check("silu", backend, &SILU, 1.0, |x| x.simd_silu());
+        sweep(
+            "silu",
+            backend,
+            (-80.0, 80.0),
+            1.0,
+            |x| x.simd_silu(),
+            |x| x / (1.0 + (-x).exp()),
+        );
+        check_exact(
+            "silu",
+            backend,
+            &[
+                (0.0, 0.0),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, -0.0),
+                (200.0, 200.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_silu(),
+        );
+    });
+}
+
+#[test]
+fn gelu() {
+    for_each_backend(|backend| {
+        check("gelu", backend, &GELU, 1.0, |x| x.simd_gelu());
+        check_exact(
+            "gelu",
+            backend,
+            &[
+                (0.0, 0.0),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, -0.0),
+                (200.0, 200.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_gelu(),
+        );
+    });
+}
+
+const SIGMOID: [(f32, f64); 38] = [
+    (-8.7e1, 1.6458114310822737e-38),
+    (-8.0e1, 1.8048513878454153e-35),
+    (-4.0e1, 4.248354255291589e-18),
+    (-1.9055353e1, 5.301091098291762e-9),
+    (-1.8062387e1, 1.4308851849420347e-8),
+    (-1.7e1, 4.1399375473943306e-8),
+    (-1.5176317e1, 2.5645375745381756e-7),
+    (-1.4385257e1, 5.656686620245773e-7),
+    (-1.2629854e1, 3.272823548639387e-6),
+    (-1.073034e1, 2.1870718405229825e-5),
+    (-7.2219267e0, 7.29860734732442e-4),
+    (-6.1742067e0, 2.07812958958432e-3),
+    (-4.145895e0, 1.5582601268508621e-2),
+    (-1.9501852e0, 1.2453316762909343e-1),
+    (-1.9469539e0, 1.2488588521333538e-1),
+    (-1.0e0, 2.689414213699951e-1),
+    (-1.0e-3, 4.9975000000895897e-1),
+    (0.0e0, 5.0e-1),
+    (1.0e-8, 5.000000025e-1),
+    (5.0e-1, 6.224593312018546e-1),
+    (1.0e0, 7.310585786300049e-1),
+    (2.5e0, 9.241418199787564e-1),
+    (3.5822327e0, 9.729391290934462e-1),
+    (4.0456915e0, 9.828032999446547e-1),
+    (6.0568495e0, 9.976637039985042e-1),
+    (7.4104147e0, 9.993954459218675e-1),
+    (8.0e0, 9.996646498695335e-1),
+    (8.324599e0, 9.997575807068501e-1),
+    (1.5254696e1, 9.99999762879202e-1),
+    (1.5582038e1, 9.999998290745084e-1),
+    (1.6e1, 9.999998874648379e-1),
+    (1.6953966e1, 9.999999566503056e-1),
+    (1.7283504e1, 9.999999688204847e-1),
+    (1.75e1, 9.99999974890009e-1),
+    (1.8328375e1, 9.999999890329978e-1),
+    (1.8379402e1, 9.999999895785763e-1),
+    (1.9945227e1, 9.99999997822801e-1),
+    (3.0e1, 9.999999999999064e-1),
+];
+
+const SOFTPLUS: [(f32, f64); 38] = [
+    (-8.7e1, 1.6458114310823998e-38),
+    (-4.0e1, 4.248354255291589e-18),
+    (-1.7e1, 4.139937633089748e-8),
+    (-1.4311065e1, 6.092330141461999e-7),
+    (-1.25988655e1, 3.37583722764757e-6),
+    (-1.2394688e1, 4.140517728107041e-6),
+    (-9.663564e0, 6.35555960799782e-5),
+    (-7.572752e0, 5.141430203907984e-4),
+    (-7.5011053e0, 5.523208217726311e-4),
+    (-1.2889885e0, 2.4337696293568067e-1),
+    (-1.2840695e0, 2.4444162164152128e-1),
+    (-1.269416e0, 2.4763765247721478e-1),
+    (-1.2629884e0, 2.4905111844359032e-1),
+    (-1.0e0, 3.132616875182228e-1),
+    (-1.0e-3, 6.926473055362032e-1),
+    (1.0e-8, 6.931471855599453e-1),
+    (5.0e-1, 9.740769841801067e-1),
+    (1.0e0, 1.3132616875182228e0),
+    (2.5e0, 2.5788897342925496e0),
+    (2.6525166e0, 2.7206178040145907e0),
+    (3.3129556e0, 3.348716897608969e0),
+    (4.492985e0, 4.50410986048241e0),
+    (6.035042e0, 6.037432345930154e0),
+    (6.7000303e0, 6.701260444511761e0),
+    (7.9354124e0, 7.935770187273145e0),
+    (8.0e0, 8.000335406372896e0),
+    (1.0958086e1, 1.0958103430255685e1),
+    (1.1244384e1, 1.1244396892419738e1),
+    (1.3009038e1, 1.3009040211486727e1),
+    (1.3992339e1, 1.399233997213937e1),
+    (1.6e1, 1.600000011253517e1),
+    (1.7519556e1, 1.7519556070155936e1),
+    (1.8185373e1, 1.818537331892739e1),
+    (1.826868e1, 1.826868058415137e1),
+    (1.8447681e1, 1.8447681436735557e1),
+    (3.0e1, 3.0000000000000092e1),
+    (1.0e4, 1.0e4),
+    (1.0e30, 1.0000000150474662e30),
+];
+
+const SILU: [(f32, f64); 39] = [
+    (-8.0e1, -1.4438811102763322e-33),
+    (-4.0e1, -1.6993417021166355e-16),
+    (-1.8709427e1, -1.401714698246428e-7),
+    (-1.8034996e1, -2.6522642202316613e-7),
+    (-1.7e1, -7.037893830570362e-7),
+    (-1.6475622e1, -1.1523117655749318e-6),
+    (-8.10726e0, -2.442334041002988e-3),
+    (-7.707748e0, -3.461784334768168e-3),
+    (-5.048861e0, -3.219015923835069e-2),
+    (-3.6223152e0, -9.427193240013984e-2),
+    (-3.2568305e0, -1.2077049941612485e-1),
+    (-3.2192504e0, -1.2377273242831056e-1),
+    (-3.2108254e0, -1.2445277698940985e-1),
+    (-2.6611264e0, -1.7378896673296906e-1),
+    (-1.0e0, -2.689414213699951e-1),
+    (-1.0e-3, -4.997500237458103e-4),
+    (-1.0e-30, -5.000000015855384e-31),
+    (0.0e0, 0.0e0),
+    (1.0e-8, 4.999999994612645e-9),
+    (2.5118747e-1, 1.4128510600917657e-1),
+    (5.0e-1, 3.112296656009273e-1),
+    (6.9246584e-1, 4.615390373128268e-1),
+    (1.0e0, 7.310585786300049e-1),
+    (1.1296285e0, 8.537397459441616e-1),
+    (1.2726629e0, 9.942020105228645e-1),
+    (1.7248206e0, 1.4639392888585512e0),
+    (2.5e0, 2.310354549946891e0),
+    (3.3037546e0, 3.1866608838379626e0),
+    (3.8253794e0, 3.7437280642936352e0),
+    (6.4399934e0, 6.429728851471665e0),
+    (6.9295087e0, 6.922734918074948e0),
+    (8.0e0, 7.997317198956268e0),
+    (1.6e1, 1.5999998199437407e1),
+    (1.6658543e1, 1.6658541662716857e1),
+    (1.7003237e1, 1.7003236068981256e1),
+    (1.9859365e1, 1.985936541614256e1),
+    (3.0e1, 2.9999999999997193e1),
+    (1.0e4, 1.0e4),
+    (1.0e30, 1.0000000150474662e30),
+];
+
+const GELU: [(f32, f64); 39] = [
+    (-1.2e1, -2.1317785344932147e-32),
+    (-1.1334446e1, -5.020646197490795e-29),
+    (-1.1097524e1, -7.15611181633022e-28),
+    (-1.0261815e1, -5.373066176919316e-24),
+    (-8.914874e0, -2.1768826608807275e-18),
+    (-8.557314e0, -4.9430313967366495e-17),
+    (-8.372866e0, -2.354185741669281e-16),
+    (-8.0e0, -4.976768459417427e-15),
+    (-6.090815e0, -3.4211274278672828e-9),
+    (-5.7155023e0, -3.125821505577432e-8),
+    (-5.597406e0, -6.089498145389088e-8),
+    (-5.5702534e0, -7.084503034322414e-8),
+    (-5.0e0, -1.4332578593959695e-6),
+    (-4.788137e0, -4.0300971634116425e-6),
+    (-4.749543e0, -4.8416048497392375e-6),
+    (-4.228332e0, -4.9773846371675245e-5),
+    (-3.0e0, -4.0496940948902835e-3),
+    (-2.7841175e0, -7.471679346143103e-3),
+    (-2.361855e0, -2.1473692312695317e-2),
+    (-2.1607316e0, -3.3184603775189545e-2),
+    (-1.0e0, -1.5865525393145705e-1),
+    (-5.0e-1, -1.5426876936299344e-1),
+    (-3.5260302e-1, -1.277103581351395e-1),
+    (-1.0e-3, -4.996010814969171e-4),
+    (-1.0e-30, -5.000000015855384e-31),
+    (0.0e0, 0.0e0),
+    (1.0e-8, 5.000000009506873e-9),
+    (5.0e-1, 3.4573123063700656e-1),
+    (7.0e-1, 5.306254318017016e-1),
+    (1.0e0, 8.413447460685429e-1),
+    (2.3263311e0, 2.303066789579283e0),
+    (2.5e0, 2.4844758366855597e0),
+    (4.119007e0, 4.118928744754071e0),
+    (4.7310753e0, 4.731070003823263e0),
+    (5.138822e0, 5.138821368350016e0),
+    (8.0e0, 7.999999999999995e0),
+    (1.6e1, 1.6e1),
+    (1.0e4, 1.0e4),
+    (1.0e30, 1.0000000150474662e30),
+];
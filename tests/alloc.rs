@@ -0,0 +1,182 @@
+//! The `_into` and `_assign` variants write into the caller's buffers without allocating,
+//! on every backend the CPU supports
+
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use arithmetics::ops::activation::SimdActivationInto;
+use arithmetics::ops::add::{SimdAddAssign, SimdAddInto};
+use arithmetics::ops::atan2::SimdAtan2Into;
+use arithmetics::ops::clamp::{SimdClampAssign, SimdClampInto};
+use arithmetics::ops::div::{SimdDivAssign, SimdDivInto};
+use arithmetics::ops::fma::{SimdFmaAssign, SimdFmaInto};
+use arithmetics::ops::max::SimdMaxInto;
+use arithmetics::ops::min::SimdMinInto;
+use arithmetics::ops::mul::{SimdMulAssign, SimdMulInto};
+use arithmetics::ops::sub::{SimdSubAssign, SimdSubInto};
+use arithmetics::ops::unary::{SimdUnaryAssign, SimdUnaryInto, UnaryFn};
+use arithmetics::simd::backend::Backend;
+use common::GLOBALS;
+
+// Counts the allocations of the threads that opted in, outputs of a single chunk
+// are computed on the calling thread
+struct Counting;
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = COUNTING.try_with(|counting| {
+            if counting.get() {
+                ALLOCATIONS.with(|n| n.set(n.get() + 1));
+            }
+        });
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+// Around the register sizes and up to a full chunk
+const LENGTHS: [usize; 8] = [0, 1, 3, 4, 15, 16, 17, 16384];
+
+// Number of allocations made by `f` on this thread
+fn allocations(f: impl FnOnce()) -> usize {
+    ALLOCATIONS.with(|n| n.set(0));
+    COUNTING.with(|counting| counting.set(true));
+    f();
+    COUNTING.with(|counting| counting.set(false));
+
+    ALLOCATIONS.with(|n| n.get())
+}
+
+// Runs `f` on every backend and length once to warm up the lazily initialized
+// globals, then checks that it does not allocate
+fn check(name: &str, f: impl Fn(&[f32], &[f32], &[f32], &mut [f32])) {
+    let _guard = GLOBALS.lock().unwrap_or_else(|err| err.into_inner());
+
+    for backend in Backend::ALL {
+        if !backend.is_supported() {
+            continue;
+        }
+
+        Backend::set_active(backend);
+
+        for len in LENGTHS {
+            // With a few arguments past the f64 fallback of the trigonometric functions
+            let a: Vec<f32> = (0..len)
+                .map(|i| match i % 37 {
+                    5 => 1e5 + i as f32,
+                    _ => (i % 200) as f32 * 0.5 - 50.0,
+                })
+                .collect();
+            let b: Vec<f32> = (0..len).map(|i| (i % 7) as f32 + 0.5).collect();
+            let c: Vec<f32> = (0..len).map(|i| (i % 13) as f32 - 6.0).collect();
+            let mut out = vec![0.0; len];
+
+            f(&a, &b, &c, &mut out);
+            let n = allocations(|| f(&a, &b, &c, &mut out));
+
+            assert_eq!(n, 0, "{:?} {} of {} elements allocated", backend, name, len);
+        }
+    }
+}
+
+#[test]
+fn binary_into() {
+    check("add_into", |a, b, _, out| a.simd_add_into(b, out));
+    check("sub_into", |a, b, _, out| a.simd_sub_into(b, out));
+    check("mul_into", |a, b, _, out| a.simd_mul_into(b, out));
+    check("div_into", |a, b, _, out| a.simd_div_into(b, out));
+    check("min_into", |a, b, _, out| a.simd_min_into(b, out));
+    check("max_into", |a, b, _, out| a.simd_max_into(b, out));
+    check("atan2_into", |a, b, _, out| a.simd_atan2_into(b, out));
+    check("fma_into", |a, b, c, out| a.simd_fma_into(b, c, out));
+}
+
+#[test]
+fn scalar_into() {
+    check("add_into scalar", |a, _, _, out| a.simd_add_into(2.0, out));
+    check("sub_into scalar", |a, _, _, out| 2.0.simd_sub_into(a, out));
+    check("mul_into scalar", |a, _, _, out| a.simd_mul_into(2.0, out));
+    check("div_into scalar", |a, _, _, out| 2.0.simd_div_into(a, out));
+    check("clamp_into", |a, _, _, out| {
+        a.simd_clamp_into(-1.0, 1.0, out)
+    });
+    check("leaky_relu_into", |a, _, _, out| {
+        a.simd_leaky_relu_into(0.1, out)
+    });
+}
+
+#[test]
+fn unary_into() {
+    let functions = [
+        UnaryFn::SQRT,
+        UnaryFn::RSQRT,
+        UnaryFn::FLOOR,
+        UnaryFn::SIN,
+        UnaryFn::COS,
+        UnaryFn::TAN,
+        UnaryFn::EXP,
+        UnaryFn::LN,
+        UnaryFn::ERF,
+        UnaryFn::LGAMMA,
+        UnaryFn::ATAN,
+        UnaryFn::SIGMOID,
+        UnaryFn::GELU,
+    ];
+
+    for f in functions {
+        check(f.name(), |a, _, _, out| a.simd_map_into(f, out));
+    }
+
+    check("sincos_into", |a, b, _, out| {
+        let mut cos = b.to_vec();
+        let n = allocations(|| a.simd_sincos_into(out, &mut cos));
+
+        assert_eq!(n, 0, "sincos_into allocated");
+    });
+}
+
+#[test]
+fn assign() {
+    check("add_assign", |a, b, _, out| {
+        out.copy_from_slice(a);
+        out.simd_add_assign(b);
+    });
+    check("sub_assign scalar", |a, _, _, out| {
+        out.copy_from_slice(a);
+        out.simd_sub_assign(2.0);
+    });
+    check("mul_assign", |a, b, _, out| {
+        out.copy_from_slice(a);
+        out.simd_mul_assign(b);
+    });
+    check("div_assign", |a, b, _, out| {
+        out.copy_from_slice(a);
+        out.simd_div_assign(b);
+    });
+    check("fma_assign", |a, b, c, out| {
+        out.copy_from_slice(a);
+        out.simd_fma_assign(b, c);
+    });
+    check("clamp_assign", |a, _, _, out| {
+        out.copy_from_slice(a);
+        out.simd_clamp_assign(-1.0, 1.0);
+    });
+    check("map_assign", |a, _, _, out| {
+        out.copy_from_slice(a);
+        out.simd_map_assign(UnaryFn::TANH);
+    });
+}
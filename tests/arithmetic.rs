@@ -0,0 +1,149 @@
+//! Subtraction, multiplication and division on every backend the CPU supports,
+//! through the slice traits and the register operators
+
+mod common;
+
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::div::SimdDiv;
+use arithmetics::ops::mul::SimdMul;
+use arithmetics::ops::sub::SimdSub;
+use arithmetics::simd::backend::Backend;
+use arithmetics::simd::scalar::ScalarVec;
+use arithmetics::simd::utils::SimdVec;
+use common::{assert_same, for_each_backend, values, LENGTHS};
+use std::ops::{Div, Mul, Sub};
+
+fn check(
+    name: &str,
+    op: fn(f32, f32) -> f32,
+    slices: impl Fn(&[f32], &[f32]) -> Vec<f32>,
+    vecs: impl Fn(Vec<f32>, Vec<f32>) -> Vec<f32>,
+) {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values(len, 1);
+            let b = values(len, 2);
+            let want: Vec<f32> = a.iter().zip(&b).map(|(&a, &b)| op(a, b)).collect();
+
+            assert_same(name, backend, &slices(&a, &b), &want);
+            assert_same(name, backend, &vecs(a, b), &want);
+        }
+
+        let a = [1.0, -1.0, 0.0, -0.0, f32::INFINITY, f32::NAN, 3.0, f32::MAX];
+        let b = [
+            0.0,
+            0.0,
+            0.0,
+            f32::INFINITY,
+            f32::INFINITY,
+            1.0,
+            f32::NAN,
+            -f32::MAX,
+        ];
+        let want: Vec<f32> = a.iter().zip(&b).map(|(&a, &b)| op(a, b)).collect();
+
+        assert_same(name, backend, &slices(&a, &b), &want);
+    });
+}
+
+#[test]
+fn sub() {
+    check(
+        "sub",
+        |a, b| a - b,
+        |a, b| a.simd_sub(b),
+        |a, b| a.simd_sub(b),
+    );
+}
+
+#[test]
+fn mul() {
+    check(
+        "mul",
+        |a, b| a * b,
+        |a, b| a.simd_mul(b),
+        |a, b| a.simd_mul(b),
+    );
+}
+
+#[test]
+fn div() {
+    check(
+        "div",
+        |a, b| a / b,
+        |a, b| a.simd_div(b),
+        |a, b| a.simd_div(b),
+    );
+}
+
+#[test]
+fn length_mismatch() {
+    let a = values(17, 1);
+    let b = values(16, 2);
+
+    for_each_backend(|backend| {
+        let err = Err(ArithmeticsError::LengthMismatch {
+            left: 17,
+            right: 16,
+        });
+
+        assert_eq!(
+            a.as_slice().try_simd_sub(b.as_slice()),
+            err,
+            "{:?}",
+            backend
+        );
+        assert_eq!(
+            a.as_slice().try_simd_mul(b.as_slice()),
+            err,
+            "{:?}",
+            backend
+        );
+        assert_eq!(
+            a.as_slice().try_simd_div(b.as_slice()),
+            err,
+            "{:?}",
+            backend
+        );
+    });
+}
+
+// Full and partial registers through the `Sub`, `Mul` and `Div` operators
+fn check_operators<V>(backend: Backend)
+where
+    V: SimdVec<f32> + Sub<Output = V> + Mul<Output = V> + Div<Output = V>,
+{
+    let a = values(V::SIZE, 1);
+    let b = values(V::SIZE, 2);
+
+    for size in 1..=V::SIZE {
+        let (a, b) = (&a[..size], &b[..size]);
+        let (x, y) = (V::new(a), V::new(b));
+
+        let want: Vec<f32> = a.iter().zip(b).map(|(a, b)| a - b).collect();
+        assert_same("Sub", backend, &(x - y).to_vec(), &want);
+
+        let want: Vec<f32> = a.iter().zip(b).map(|(a, b)| a * b).collect();
+        assert_same("Mul", backend, &(x * y).to_vec(), &want);
+
+        let want: Vec<f32> = a.iter().zip(b).map(|(a, b)| a / b).collect();
+        assert_same("Div", backend, &(x / y).to_vec(), &want);
+    }
+}
+
+#[test]
+fn operators() {
+    for_each_backend(|backend| match backend {
+        Backend::Scalar => check_operators::<ScalarVec>(backend),
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 => check_operators::<arithmetics::simd::f32x16::F32x16>(backend),
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        Backend::Avx2 => check_operators::<arithmetics::simd::f32x8::F32x8>(backend),
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        Backend::Sse41 | Backend::Neon => {
+            check_operators::<arithmetics::simd::f32x4::F32x4>(backend)
+        }
+        #[allow(unreachable_patterns)]
+        _ => {}
+    });
+}
@@ -0,0 +1,237 @@
+//! NumPy broadcasting of the arrays on every backend the CPU supports, with stretched
+//! (stride 0) and transposed operands and outputs past the parallel chunk size
+
+mod common;
+
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::add::SimdAdd;
+use arithmetics::ops::div::SimdDiv;
+use arithmetics::ops::max::SimdMax;
+use arithmetics::ops::min::SimdMin;
+use arithmetics::ops::mul::SimdMul;
+use arithmetics::ops::sub::SimdSub;
+use arithmetics::simd::backend::Backend;
+use arithmetics::NdArray;
+use common::{for_each_backend, values};
+
+type Op = (
+    &'static str,
+    fn(&NdArray, &NdArray) -> NdArray,
+    fn(f32, f32) -> f32,
+);
+
+const OPS: [Op; 6] = [
+    ("add", |a, b| a.simd_add(b), |a, b| a + b),
+    ("sub", |a, b| a.simd_sub(b), |a, b| a - b),
+    ("mul", |a, b| a.simd_mul(b), |a, b| a * b),
+    ("div", |a, b| a.simd_div(b), |a, b| a / b),
+    ("min", |a, b| a.simd_min(b), f32::min),
+    ("max", |a, b| a.simd_max(b), f32::max),
+];
+
+fn array(shape: &[usize], seed: u32) -> NdArray {
+    NdArray::new(values(shape.iter().product(), seed), shape)
+}
+
+// Transposed view of a row-major array, its strides are decreasing
+fn transposed(shape: &[usize], seed: u32) -> NdArray {
+    let mut reversed = shape.to_vec();
+    reversed.reverse();
+
+    array(&reversed, seed).transpose()
+}
+
+// Element of `a` at `index` of the broadcast shape, stretching its size 1 axes
+fn broadcast_get(a: &NdArray, index: &[usize]) -> f32 {
+    let pad = index.len() - a.ndim();
+    let index: Vec<usize> = a
+        .shape()
+        .iter()
+        .zip(&index[pad..])
+        .map(|(&size, &i)| if size == 1 { 0 } else { i })
+        .collect();
+
+    a.get(&index).unwrap()
+}
+
+// Every operation against the lane by lane results over the broadcast shape
+fn check(backend: Backend, a: &NdArray, b: &NdArray, shape: &[usize]) {
+    let len = shape.iter().product();
+
+    for (name, op, scalar) in OPS {
+        let c = op(a, b);
+
+        assert_eq!(c.shape(), shape, "{:?} {}", backend, name);
+        assert!(c.is_contiguous(), "{:?} {}", backend, name);
+
+        let got = c.to_vec();
+        assert_eq!(got.len(), len, "{:?} {}", backend, name);
+
+        let mut index = vec![0; shape.len()];
+
+        for (i, &got) in got.iter().enumerate() {
+            let want = scalar(broadcast_get(a, &index), broadcast_get(b, &index));
+
+            assert!(
+                got.to_bits() == want.to_bits() || (got.is_nan() && want.is_nan()),
+                "{:?} {} of {:?} and {:?}, element {} at {:?} = {:e}, expected {:e}",
+                backend,
+                name,
+                a.shape(),
+                b.shape(),
+                i,
+                index,
+                got,
+                want
+            );
+
+            // Next row-major index
+            for axis in (0..shape.len()).rev() {
+                index[axis] += 1;
+
+                if index[axis] < shape[axis] {
+                    break;
+                }
+                index[axis] = 0;
+            }
+        }
+    }
+}
+
+#[test]
+fn broadcast() {
+    // Left shape, right shape and their broadcast shape
+    let cases: [(&[usize], &[usize], &[usize]); 14] = [
+        (&[17], &[17], &[17]),
+        (&[40000], &[40000], &[40000]),
+        // Rows added to every row, short ones are tiled
+        (&[2500, 16], &[16], &[2500, 16]),
+        (&[17], &[1031, 17], &[1031, 17]),
+        (&[3, 16385], &[16385], &[3, 16385]),
+        (&[2, 16383], &[1, 16383], &[2, 16383]),
+        // Columns, stride 0 along the rows
+        (&[16385, 1], &[16385, 3], &[16385, 3]),
+        (&[5, 7], &[5, 1], &[5, 7]),
+        // Outer products
+        (&[16384, 1], &[1, 3], &[16384, 3]),
+        (&[9, 1], &[15], &[9, 15]),
+        (&[4, 1, 5], &[3, 1], &[4, 3, 5]),
+        (&[1, 1], &[1], &[1, 1]),
+        (&[], &[3, 4], &[3, 4]),
+        (&[0, 5], &[5], &[0, 5]),
+    ];
+
+    for_each_backend(|backend| {
+        for (left, right, shape) in cases {
+            check(backend, &array(left, 1), &array(right, 2), shape);
+        }
+    });
+}
+
+#[test]
+fn transpose() {
+    for_each_backend(|backend| {
+        // Gathered along the last axis
+        let a = transposed(&[1000, 40], 1);
+        check(backend, &a, &array(&[1000, 40], 2), &[1000, 40]);
+        check(backend, &array(&[1000, 40], 2), &a, &[1000, 40]);
+        check(backend, &a, &transposed(&[1000, 40], 2), &[1000, 40]);
+
+        // Against rows, columns and stretched axes
+        check(backend, &a, &array(&[40], 2), &[1000, 40]);
+        check(backend, &a, &array(&[1000, 1], 2), &[1000, 40]);
+        check(
+            backend,
+            &transposed(&[3, 16385], 1),
+            &array(&[16385], 2),
+            &[3, 16385],
+        );
+        check(
+            backend,
+            &transposed(&[17, 1], 1),
+            &array(&[1, 9], 2),
+            &[17, 9],
+        );
+        check(
+            backend,
+            &transposed(&[4, 3, 5], 1),
+            &array(&[3, 1], 2),
+            &[4, 3, 5],
+        );
+
+        assert!(!a.is_contiguous());
+        assert_eq!(a.as_slice(), None);
+        assert_eq!(a.strides(), [1, 1000]);
+    });
+}
+
+#[test]
+fn scalars() {
+    for_each_backend(|backend| {
+        for shape in [&[17][..], &[1000, 40], &[0, 3]] {
+            let a = array(shape, 1);
+            let t = transposed(shape, 1);
+
+            for a in [&a, &t] {
+                let x = a.to_vec();
+
+                assert_eq!(
+                    a.simd_add(2.5).to_vec(),
+                    x.iter().map(|x| x + 2.5).collect::<Vec<_>>(),
+                    "{:?}",
+                    backend
+                );
+                assert_eq!(
+                    2.5.simd_sub(a).to_vec(),
+                    x.iter().map(|x| 2.5 - x).collect::<Vec<_>>(),
+                    "{:?}",
+                    backend
+                );
+                assert_eq!(
+                    a.simd_mul(-3.0).to_vec(),
+                    x.iter().map(|x| x * -3.0).collect::<Vec<_>>(),
+                    "{:?}",
+                    backend
+                );
+                assert_eq!(
+                    1.0.simd_div(a).to_vec(),
+                    x.iter().map(|x| 1.0 / x).collect::<Vec<_>>(),
+                    "{:?}",
+                    backend
+                );
+                assert_eq!(a.simd_max(0.0).shape(), shape, "{:?}", backend);
+            }
+        }
+    });
+}
+
+fn mismatch(axis: usize, left: usize, right: usize) -> ArithmeticsError {
+    ArithmeticsError::BroadcastMismatch { axis, left, right }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        NdArray::try_new(vec![0.0; 11], &[3, 4]).err(),
+        Some(ArithmeticsError::InvalidShape { len: 11, size: 12 })
+    );
+
+    for_each_backend(|backend| {
+        let cases: [(&[usize], &[usize], ArithmeticsError); 3] = [
+            (&[3, 4], &[5], mismatch(1, 4, 5)),
+            (&[2, 3, 4], &[2, 4], mismatch(1, 3, 2)),
+            (&[0, 4], &[2, 1], mismatch(0, 0, 2)),
+        ];
+
+        for (left, right, err) in cases {
+            assert_eq!(
+                array(left, 1).try_simd_add(&array(right, 2)).err(),
+                Some(err),
+                "{:?} {:?} {:?}",
+                backend,
+                left,
+                right
+            );
+        }
+    });
+}
@@ -0,0 +1,392 @@
+//! The `_assign` and `_into` variants agree with the allocating operations on every
+//! backend the CPU supports, and leave their buffers untouched on errors
+
+mod common;
+
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::add::{SimdAdd, SimdAddAssign, SimdAddInto};
+use arithmetics::ops::atan2::{SimdAtan2, SimdAtan2Assign, SimdAtan2Into};
+use arithmetics::ops::clamp::SimdClamp;
+use arithmetics::ops::div::{SimdDiv, SimdDivAssign, SimdDivInto};
+use arithmetics::ops::fma::SimdFma;
+use arithmetics::ops::max::{SimdMax, SimdMaxAssign, SimdMaxInto};
+use arithmetics::ops::min::{SimdMin, SimdMinAssign, SimdMinInto};
+use arithmetics::ops::mul::{SimdMul, SimdMulAssign, SimdMulInto};
+use arithmetics::ops::sub::{SimdSub, SimdSubAssign, SimdSubInto};
+use arithmetics::ops::unary::{SimdUnary, SimdUnaryAssign, SimdUnaryInto, UnaryFn};
+use common::{assert_same, for_each_backend, values, LENGTHS};
+use std::mem::MaybeUninit;
+
+type SliceVariant<'a> = &'a dyn Fn(&[f32], &[f32]) -> Vec<f32>;
+type ScalarVariant<'a> = &'a dyn Fn(&[f32], f32) -> Vec<f32>;
+type Consuming = fn(Vec<f32>, &[f32]) -> Vec<f32>;
+
+// Every variant of a binary operation against the allocating one (the first of
+// each list), with a slice on the right and then with the scalar 0.75
+fn check(name: &str, slices: &[SliceVariant], scalar: &[ScalarVariant]) {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values(len, 1);
+            let b = values(len, 2);
+
+            let want = slices[0](&a, &b);
+            for variant in &slices[1..] {
+                assert_same(name, backend, &variant(&a, &b), &want);
+            }
+
+            let want = scalar[0](&a, 0.75);
+            for variant in &scalar[1..] {
+                assert_same(name, backend, &variant(&a, 0.75), &want);
+            }
+        }
+    });
+}
+
+fn uninit(len: usize) -> Vec<MaybeUninit<f32>> {
+    vec![MaybeUninit::uninit(); len]
+}
+
+#[test]
+fn add() {
+    check(
+        "add",
+        &[
+            &|a, b| a.simd_add(b),
+            &|a, b| {
+                let mut out = a.to_vec();
+                out.simd_add_assign(b);
+                out
+            },
+            &|a, b| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_add_into(b, &mut out);
+                out
+            },
+            &|a, b| a.simd_add_into_uninit(b, &mut uninit(a.len())).to_vec(),
+        ],
+        &[
+            &|a, s| a.simd_add(s),
+            &|a, s| {
+                let mut out = a.to_vec();
+                out.simd_add_assign(s);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_add_into(s, &mut out);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                s.simd_add_into(a, &mut out);
+                out
+            },
+        ],
+    );
+}
+
+#[test]
+fn sub() {
+    check(
+        "sub",
+        &[
+            &|a, b| a.simd_sub(b),
+            &|a, b| {
+                let mut out = a.to_vec();
+                out.simd_sub_assign(b);
+                out
+            },
+            &|a, b| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_sub_into(b, &mut out);
+                out
+            },
+            &|a, b| a.simd_sub_into_uninit(b, &mut uninit(a.len())).to_vec(),
+        ],
+        &[
+            &|a, s| a.simd_sub(s),
+            &|a, s| {
+                let mut out = a.to_vec();
+                out.simd_sub_assign(s);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_sub_into(s, &mut out);
+                out
+            },
+            // s - a, negated
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                s.simd_sub_into(a, &mut out);
+                out.iter().map(|x| -x).collect()
+            },
+        ],
+    );
+}
+
+#[test]
+fn mul() {
+    check(
+        "mul",
+        &[
+            &|a, b| a.simd_mul(b),
+            &|a, b| {
+                let mut out = a.to_vec();
+                out.simd_mul_assign(b);
+                out
+            },
+            &|a, b| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_mul_into(b, &mut out);
+                out
+            },
+            &|a, b| a.simd_mul_into_uninit(b, &mut uninit(a.len())).to_vec(),
+        ],
+        &[
+            &|a, s| a.simd_mul(s),
+            &|a, s| {
+                let mut out = a.to_vec();
+                out.simd_mul_assign(s);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_mul_into(s, &mut out);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                s.simd_mul_into(a, &mut out);
+                out
+            },
+        ],
+    );
+}
+
+#[test]
+fn div() {
+    check(
+        "div",
+        &[
+            &|a, b| a.simd_div(b),
+            &|a, b| {
+                let mut out = a.to_vec();
+                out.simd_div_assign(b);
+                out
+            },
+            &|a, b| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_div_into(b, &mut out);
+                out
+            },
+            &|a, b| a.simd_div_into_uninit(b, &mut uninit(a.len())).to_vec(),
+        ],
+        &[
+            &|a, s| a.simd_div(s),
+            &|a, s| {
+                let mut out = a.to_vec();
+                out.simd_div_assign(s);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_div_into(s, &mut out);
+                out
+            },
+        ],
+    );
+}
+
+#[test]
+fn min_max() {
+    check(
+        "min",
+        &[
+            &|a, b| a.simd_min(b),
+            &|a, b| {
+                let mut out = a.to_vec();
+                out.simd_min_assign(b);
+                out
+            },
+            &|a, b| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_min_into(b, &mut out);
+                out
+            },
+        ],
+        &[
+            &|a, s| a.simd_min(s),
+            &|a, s| {
+                let mut out = a.to_vec();
+                out.simd_min_assign(s);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                s.simd_min_into(a, &mut out);
+                out
+            },
+        ],
+    );
+    check(
+        "max",
+        &[
+            &|a, b| a.simd_max(b),
+            &|a, b| {
+                let mut out = a.to_vec();
+                out.simd_max_assign(b);
+                out
+            },
+            &|a, b| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_max_into(b, &mut out);
+                out
+            },
+        ],
+        &[
+            &|a, s| a.simd_max(s),
+            &|a, s| {
+                let mut out = a.to_vec();
+                out.simd_max_assign(s);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                s.simd_max_into(a, &mut out);
+                out
+            },
+        ],
+    );
+}
+
+#[test]
+fn atan2() {
+    check(
+        "atan2",
+        &[
+            &|a, b| a.simd_atan2(b),
+            &|a, b| {
+                let mut out = a.to_vec();
+                out.simd_atan2_assign(b);
+                out
+            },
+            &|a, b| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_atan2_into(b, &mut out);
+                out
+            },
+        ],
+        &[
+            &|a, s| a.simd_atan2(s),
+            &|a, s| {
+                let mut out = a.to_vec();
+                out.simd_atan2_assign(s);
+                out
+            },
+            &|a, s| {
+                let mut out = vec![f32::NAN; a.len()];
+                a.simd_atan2_into(s, &mut out);
+                out
+            },
+        ],
+    );
+}
+
+#[test]
+fn unary() {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values(len, 1);
+
+            for f in [UnaryFn::SQRT, UnaryFn::RECIP, UnaryFn::ROUND] {
+                let want = a.as_slice().simd_map(f);
+
+                let mut assigned = a.clone();
+                assigned.simd_map_assign(f);
+                assert_same(f.name(), backend, &assigned, &want);
+
+                let mut out = vec![f32::NAN; len];
+                a.as_slice().simd_map_into(f, &mut out);
+                assert_same(f.name(), backend, &out, &want);
+
+                let out = a
+                    .as_slice()
+                    .simd_map_into_uninit(f, &mut uninit(len))
+                    .to_vec();
+                assert_same(f.name(), backend, &out, &want);
+            }
+        }
+    });
+}
+
+#[test]
+fn reuse_vec() {
+    // The operations consuming a `Vec` write their results into its buffer
+    for_each_backend(|backend| {
+        let a = values(40000, 1);
+        let b = values(40000, 2);
+
+        let ops: [(&str, Consuming); 9] = [
+            ("add", |a, b| a.simd_add(b.to_vec())),
+            ("sub scalar", |a, _| a.simd_sub(2.0)),
+            ("mul", |a, b| a.simd_mul(b.to_vec())),
+            ("div scalar", |a, _| a.simd_div(2.0)),
+            ("min", |a, b| a.simd_min(b.to_vec())),
+            ("max scalar", |a, _| a.simd_max(2.0)),
+            ("fma", |a, b| a.simd_fma(b.to_vec(), b.to_vec())),
+            ("clamp", |a, _| a.simd_clamp(-1.0, 1.0)),
+            ("map", |a, _| a.simd_map(UnaryFn::SQRT)),
+        ];
+
+        for (name, op) in ops {
+            let a = a.clone();
+            let ptr = a.as_ptr();
+
+            let y = op(a, &b);
+
+            assert_eq!(y.as_ptr(), ptr, "{:?} {}", backend, name);
+        }
+    });
+}
+
+#[test]
+fn length_mismatch() {
+    let a = values(17, 1);
+    let b = values(16, 2);
+    let err = ArithmeticsError::LengthMismatch {
+        left: 17,
+        right: 16,
+    };
+
+    for_each_backend(|backend| {
+        // The left operand of the assignments is left untouched
+        let mut assigned = a.clone();
+        assert_eq!(assigned.try_simd_add_assign(b.as_slice()), Err(err));
+        assert_eq!(assigned.try_simd_sub_assign(b.as_slice()), Err(err));
+        assert_eq!(assigned.try_simd_mul_assign(b.as_slice()), Err(err));
+        assert_eq!(assigned.try_simd_div_assign(b.as_slice()), Err(err));
+        assert_eq!(assigned.try_simd_min_assign(b.as_slice()), Err(err));
+        assert_eq!(assigned.try_simd_max_assign(b.as_slice()), Err(err));
+        assert_eq!(assigned.try_simd_atan2_assign(b.as_slice()), Err(err));
+        assert_eq!(assigned, a, "{:?}", backend);
+
+        // And so are the output buffers of the wrong size
+        let mut out = vec![0.0; 16];
+        assert_eq!(
+            a.as_slice().try_simd_add_into(a.as_slice(), &mut out),
+            Err(err)
+        );
+        assert_eq!(a.as_slice().try_simd_sub_into(1.0, &mut out), Err(err));
+        assert_eq!(1.0.try_simd_mul_into(a.as_slice(), &mut out), Err(err));
+        assert_eq!(
+            a.as_slice().try_simd_div_into(b.as_slice(), &mut out),
+            Err(err)
+        );
+        assert_eq!(
+            a.as_slice().try_simd_map_into(UnaryFn::SQRT, &mut out),
+            Err(err)
+        );
+        assert_eq!(out, vec![0.0; 16], "{:?}", backend);
+    });
+}
@@ -0,0 +1,53 @@
+//! The AVX-512 backend is compiled on every toolchain and `F32x16` is usable directly,
+//! the registers are only exercised when the CPU has AVX-512F
+
+#![cfg(target_arch = "x86_64")]
+
+mod common;
+
+use arithmetics::simd::backend::Backend;
+use arithmetics::simd::f32x16::{self, F32x16};
+use arithmetics::simd::utils::SimdVec;
+use common::values;
+
+#[test]
+fn detected() {
+    assert_eq!(
+        Backend::Avx512.is_supported(),
+        is_x86_feature_detected!("avx512f")
+    );
+    assert_eq!(Backend::Avx512.lanes(), f32x16::SIZE);
+    assert_eq!(F32x16::SIZE, 16);
+}
+
+#[test]
+fn registers() {
+    if !Backend::Avx512.is_supported() {
+        return;
+    }
+
+    let a = values(16, 1);
+    let b = values(16, 2);
+    let (x, y) = (F32x16::new(&a), F32x16::new(&b));
+
+    let lanes = |f: fn(f32, f32) -> f32| {
+        a.iter()
+            .zip(&b)
+            .map(|(&a, &b)| f(a, b))
+            .collect::<Vec<f32>>()
+    };
+    assert_eq!((x + y).to_vec(), lanes(|a, b| a + b));
+    assert_eq!((x - y).to_vec(), lanes(|a, b| a - b));
+    assert_eq!((x * y).to_vec(), lanes(|a, b| a * b));
+    assert_eq!((x / y).to_vec(), lanes(|a, b| a / b));
+    assert_eq!(x.simd_fma(y, y).to_vec(), lanes(|a, b| a.mul_add(b, b)));
+
+    // Partial registers keep their length through the operations
+    for len in [0, 1, 7, 15] {
+        let x = F32x16::new(&a[..len]);
+        let y = F32x16::new(&b[..len]);
+        let want: Vec<f32> = a[..len].iter().zip(&b[..len]).map(|(a, b)| a + b).collect();
+
+        assert_eq!((x + y).store_partial(), want, "{} lanes", len);
+    }
+}
@@ -0,0 +1,157 @@
+//! Helpers shared by the integration tests, each test binary uses a subset of them
+
+#![allow(dead_code)]
+
+use std::sync::Mutex;
+
+use arithmetics::simd::backend::Backend;
+
+// The active backend and accuracy are global, the tests switching them take turns
+pub static GLOBALS: Mutex<()> = Mutex::new(());
+
+// Around the register sizes and the 16384 elements of a parallel chunk
+pub const LENGTHS: [usize; 15] = [
+    0, 1, 3, 4, 5, 7, 8, 9, 15, 16, 17, 16383, 16384, 16385, 40000,
+];
+
+// Runs `f` with each backend the CPU supports set active, holding `GLOBALS`
+pub fn for_each_backend(f: impl Fn(Backend)) {
+    let _guard = GLOBALS.lock().unwrap_or_else(|err| err.into_inner());
+
+    for backend in Backend::ALL {
+        if backend.is_supported() {
+            Backend::set_active(backend);
+            f(backend);
+        }
+    }
+}
+
+// Deterministic values spread over several binades, with both signs
+pub fn values(len: usize, seed: u32) -> Vec<f32> {
+    let mut state = seed;
+
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let mantissa = (state >> 8) as f32 / (1 << 24) as f32;
+            let exponent = (state & 0x1f) as i32 - 16;
+
+            (mantissa - 0.5) * 2f32.powi(exponent)
+        })
+        .collect()
+}
+
+// Compared bitwise with NaN matching any NaN
+pub fn assert_same(name: &str, backend: Backend, got: &[f32], want: &[f32]) {
+    assert_eq!(got.len(), want.len(), "{:?} {}", backend, name);
+
+    for (i, (&x, &y)) in got.iter().zip(want).enumerate() {
+        assert!(
+            x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()),
+            "{:?} {} of {} elements, element {} = {:e}, expected {:e}",
+            backend,
+            name,
+            want.len(),
+            i,
+            x,
+            y
+        );
+    }
+}
+
+// Deterministic values with both signs, signed zeros, infinities and a NaN every
+// `nan` elements
+pub fn values_with_nans(len: usize, seed: u32, nan: usize) -> Vec<f32> {
+    let mut state = seed;
+
+    (0..len)
+        .map(|i| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+
+            match (i % nan, state >> 28) {
+                (0, _) => f32::NAN,
+                (_, 0) => 0.0,
+                (_, 1) => -0.0,
+                (_, 2) => f32::INFINITY,
+                (_, 3) => f32::NEG_INFINITY,
+                _ => ((state >> 8) & 0xff) as f32 - 128.0,
+            }
+        })
+        .collect()
+}
+
+// NaN matches any NaN, and the sign of a zero is not compared
+pub fn assert_equal(name: &str, backend: Backend, x: &[f32], got: &[f32], want: &[f32]) {
+    assert_eq!(got.len(), want.len(), "{:?} {}", backend, name);
+
+    for (i, (&y, &expected)) in got.iter().zip(want).enumerate() {
+        assert!(
+            y == expected || (y.is_nan() && expected.is_nan()),
+            "{:?} {} of {} elements, element {} ({:e}) = {:e}, expected {:e}",
+            backend,
+            name,
+            want.len(),
+            i,
+            x[i],
+            y,
+            expected
+        );
+    }
+}
+
+// Distance between `got` and the exact `want` in units of the f32 spacing at `want`
+pub fn ulp_error(got: f32, want: f64) -> f64 {
+    let exponent = (want.abs() as f32).max(f32::MIN_POSITIVE).log2().floor();
+
+    (got as f64 - want).abs() / 2f64.powi(exponent as i32 - 23)
+}
+
+// Every entry of a reference table within `ulp` of its exact result
+pub fn check(
+    name: &str,
+    backend: Backend,
+    table: &[(f32, f64)],
+    ulp: f64,
+    f: impl Fn(&[f32]) -> Vec<f32>,
+) {
+    let x: Vec<f32> = table.iter().map(|&(x, _)| x).collect();
+    let y = f(&x);
+
+    for (&(x, want), &got) in table.iter().zip(&y) {
+        let err = ulp_error(got, want);
+
+        assert!(
+            err <= ulp,
+            "{:?} {}({:e}) = {:e}, expected {:e} ({:.2} ulp)",
+            backend,
+            name,
+            x,
+            got,
+            want,
+            err
+        );
+    }
+}
+
+// Special values, compared bitwise with NaN matching any NaN
+pub fn check_exact(
+    name: &str,
+    backend: Backend,
+    cases: &[(f32, f32)],
+    f: impl Fn(&[f32]) -> Vec<f32>,
+) {
+    let x: Vec<f32> = cases.iter().map(|&(x, _)| x).collect();
+    let y = f(&x);
+
+    for (&(x, want), &got) in cases.iter().zip(&y) {
+        assert!(
+            got.to_bits() == want.to_bits() || (got.is_nan() && want.is_nan()),
+            "{:?} {}({:e}) = {:e}, expected {:e}",
+            backend,
+            name,
+            x,
+            got,
+            want
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Runtime backend selection, every backend the CPU supports must agree with the
+//! scalar additions across the register tails and the parallel chunk boundaries
+
+mod common;
+
+use arithmetics::backend_info;
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::add::SimdAdd;
+use arithmetics::simd::backend::Backend;
+use common::{for_each_backend, values, GLOBALS, LENGTHS};
+
+#[test]
+fn add() {
+    for_each_backend(|backend| {
+        assert_eq!(Backend::active(), backend);
+        assert_eq!(backend_info().backend, backend);
+
+        for len in LENGTHS {
+            let a = values(len, 1);
+            let b = values(len, 2);
+            let want: Vec<f32> = a.iter().zip(&b).map(|(a, b)| a + b).collect();
+
+            assert_eq!(
+                a.as_slice().simd_add(b.as_slice()),
+                want,
+                "{:?} {} elements",
+                backend,
+                len
+            );
+            assert_eq!(a.simd_add(b), want, "{:?} {} elements", backend, len);
+        }
+    });
+}
+
+#[test]
+fn length_mismatch() {
+    for_each_backend(|backend| {
+        let a = values(17, 1);
+        let b = values(16, 2);
+
+        assert_eq!(
+            a.as_slice().try_simd_add(b.as_slice()),
+            Err(ArithmeticsError::LengthMismatch {
+                left: 17,
+                right: 16
+            }),
+            "{:?}",
+            backend
+        );
+    });
+}
+
+#[test]
+fn set_active() {
+    let _guard = GLOBALS.lock().unwrap_or_else(|err| err.into_inner());
+
+    assert!(Backend::detect().is_supported());
+    assert!(Backend::Scalar.is_supported());
+
+    Backend::set_active(Backend::Scalar);
+
+    for backend in Backend::ALL {
+        if backend.is_supported() {
+            assert_eq!(Backend::try_set_active(backend), Ok(()));
+            assert_eq!(Backend::active(), backend);
+        } else {
+            // The active backend is left unchanged
+            let active = Backend::active();
+
+            assert_eq!(
+                Backend::try_set_active(backend),
+                Err(ArithmeticsError::UnsupportedBackend(backend))
+            );
+            assert_eq!(Backend::active(), active);
+        }
+    }
+
+    Backend::set_active(Backend::detect());
+    assert_eq!(Backend::active(), Backend::detect());
+}
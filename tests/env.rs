@@ -0,0 +1,81 @@
+//! Backend and accuracy picked from the environment, the variables are process-wide
+//! so each one is only set by a single test
+
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::accuracy::{Accuracy, ACCURACY_ENV};
+use arithmetics::simd::backend::{Backend, BACKEND_ENV};
+
+#[test]
+fn backend() {
+    std::env::remove_var(BACKEND_ENV);
+    assert_eq!(Backend::from_env(), Ok(None));
+
+    std::env::set_var(BACKEND_ENV, " Scalar ");
+    assert_eq!(Backend::from_env(), Ok(Some(Backend::Scalar)));
+
+    for backend in Backend::ALL {
+        std::env::set_var(BACKEND_ENV, backend.name());
+
+        let want = if backend.is_supported() {
+            Ok(Some(backend))
+        } else {
+            Err(ArithmeticsError::UnsupportedBackend(backend))
+        };
+        assert_eq!(Backend::from_env(), want);
+    }
+
+    // Unknown names are reported to the caller, the first detection falls back to the
+    // CPU features and keeps the error
+    std::env::set_var(BACKEND_ENV, "avx1024");
+    assert_eq!(
+        Backend::from_env(),
+        Err(ArithmeticsError::InvalidEnv(BACKEND_ENV))
+    );
+    assert_eq!(Backend::env_error(), None);
+    assert_eq!(Backend::active(), Backend::detect());
+    assert_eq!(
+        Backend::env_error(),
+        Some(ArithmeticsError::InvalidEnv(BACKEND_ENV))
+    );
+
+    // The variable is only read once
+    std::env::set_var(BACKEND_ENV, "scalar");
+    assert_eq!(Backend::active(), Backend::detect());
+    assert_eq!(
+        Backend::env_error(),
+        Some(ArithmeticsError::InvalidEnv(BACKEND_ENV))
+    );
+}
+
+#[test]
+fn accuracy() {
+    std::env::remove_var(ACCURACY_ENV);
+    assert_eq!(Accuracy::from_env(), Ok(None));
+
+    for accuracy in Accuracy::ALL {
+        std::env::set_var(ACCURACY_ENV, accuracy.name().to_uppercase());
+        assert_eq!(Accuracy::from_env(), Ok(Some(accuracy)));
+    }
+
+    // Unknown names are reported to the caller, the first call falls back to the
+    // default tier and keeps the error
+    std::env::set_var(ACCURACY_ENV, "precise");
+    assert_eq!(
+        Accuracy::from_env(),
+        Err(ArithmeticsError::InvalidEnv(ACCURACY_ENV))
+    );
+    assert_eq!(Accuracy::env_error(), None);
+    assert_eq!(Accuracy::active(), Accuracy::Default);
+    assert_eq!(
+        Accuracy::env_error(),
+        Some(ArithmeticsError::InvalidEnv(ACCURACY_ENV))
+    );
+
+    // The variable is only read once
+    std::env::set_var(ACCURACY_ENV, "fast");
+    assert_eq!(Accuracy::active(), Accuracy::Default);
+    assert_eq!(
+        Accuracy::env_error(),
+        Some(ArithmeticsError::InvalidEnv(ACCURACY_ENV))
+    );
+}
@@ -0,0 +1,160 @@
+//! Accuracy of the exponential functions against 120 digit references from mpmath,
+//! on every backend the CPU supports
+
+mod common;
+
+use arithmetics::ops::unary::SimdUnary;
+use common::{check, check_exact, for_each_backend};
+
+#[test]
+fn exp() {
+    for_each_backend(|backend| {
+        check("exp", backend, &EXP, 1.0, |x| x.simd_exp());
+        check_exact(
+            "exp",
+            backend,
+            &[
+                (0.0, 1.0),
+                (-0.0, 1.0),
+                (8.872284e1, f32::INFINITY),
+                (-1.1e2, 0.0),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, 0.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_exp(),
+        );
+    });
+}
+
+#[test]
+fn exp2() {
+    for_each_backend(|backend| {
+        check("exp2", backend, &EXP2, 1.0, |x| x.simd_exp2());
+        check_exact(
+            "exp2",
+            backend,
+            &[
+                (0.0, 1.0),
+                (-0.0, 1.0),
+                (1.28e2, f32::INFINITY),
+                (-1.51e2, 0.0),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, 0.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_exp2(),
+        );
+    });
+}
+
+#[test]
+fn expm1() {
+    for_each_backend(|backend| {
+        check("expm1", backend, &EXPM1, 1.0, |x| x.simd_expm1());
+        check_exact(
+            "expm1",
+            backend,
+            &[
+                (0.0, 0.0),
+                (-0.0, -0.0),
+                (8.872284e1, f32::INFINITY),
+                (-1.0e2, -1.0),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, -1.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_expm1(),
+        );
+    });
+}
+
+const EXP: [(f32, f64); 34] = [
+    (-1.03e2, 1.8521167695179754e-45),
+    (-1.0e2, 3.720075976020836e-44),
+    (-9.55e1, 3.348705675813844e-42),
+    (-9.0e1, 8.194012623990515e-40),
+    (-8.733655e1, 1.1754907056089184e-38),
+    (-8.7e1, 1.6458114310822737e-38),
+    (-5.0e1, 1.9287498479639178e-22),
+    (-2.0e1, 2.061153622438558e-9),
+    (-1.0e1, 4.5399929762484854e-5),
+    (-5.0e0, 6.737946999085467e-3),
+    (-2.0e0, 1.353352832366127e-1),
+    (-1.0e0, 3.6787944117144233e-1),
+    (-5.0e-1, 6.065306597126334e-1),
+    (-1.0e-1, 9.048374166876467e-1),
+    (-1.0e-3, 9.99000499785925e-1),
+    (-1.0e-7, 9.999999000000038e-1),
+    (1.0e-30, 1.0e0),
+    (1.0e-7, 1.0000001000000063e0),
+    (1.0e-3, 1.0010005002142532e0),
+    (1.0e-1, 1.1051709197224806e0),
+    (3.465736e-1, 1.414213563719889e0),
+    (5.0e-1, 1.6487212707001282e0),
+    (6.931472e-1, 2.0000000038093084e0),
+    (1.0e0, std::f64::consts::E),
+    (2.0e0, 7.38905609893065e0),
+    (5.0e0, 1.484131591025766e2),
+    (1.0e1, 2.2026465794806718e4),
+    (2.0e1, 4.851651954097903e8),
+    (4.25e1, 2.8675795916805714e18),
+    (5.0e1, 5.184705528587072e21),
+    (6.4e1, 6.235149080811617e27),
+    (8.0e1, 5.54062238439351e34),
+    (8.8e1, 1.6516362549940018e38),
+    (8.872283e1, 3.4027985374118487e38),
+];
+
+const EXP2: [(f32, f64); 23] = [
+    (-1.49e2, 1.401298464324817e-45),
+    (-1.405e2, 5.073242350542713e-43),
+    (-1.275e2, 4.15600013356459e-39),
+    (-1.26e2, 1.1754943508222875e-38),
+    (-1.0025e2, 6.633503073341491e-31),
+    (-5.05e1, 6.280369834735101e-16),
+    (-1.0e1, 9.765625e-4),
+    (-1.0e0, 5.0e-1),
+    (-5.0e-1, std::f64::consts::FRAC_1_SQRT_2),
+    (-1.0e-7, 9.999999306852836e-1),
+    (1.0e-30, 1.0e0),
+    (1.0e-7, 1.0000000693147213e0),
+    (2.5e-1, 1.189207115002721e0),
+    (5.0e-1, std::f64::consts::SQRT_2),
+    (1.0e0, 2.0e0),
+    (1.5e0, 2.8284271247461903e0),
+    (std::f32::consts::LOG2_10, 9.999999510670195e0),
+    (1.0e1, 1.024e3),
+    (2.07e1, 1.7034176383402438e6),
+    (6.4e1, 1.8446744073709552e19),
+    (1.00125e2, 1.3823827818666394e30),
+    (1.27e2, 1.7014118346046923e38),
+    (1.2799999e2, 3.402805674127326e38),
+];
+
+const EXPM1: [(f32, f64); 24] = [
+    (-2.0e1, -9.999999979388464e-1),
+    (-1.0e1, -9.999546000702375e-1),
+    (-5.0e0, -9.932620530009145e-1),
+    (-1.0e0, -6.321205588285577e-1),
+    (-5.0e-1, -3.934693402873666e-1),
+    (-1.0e-1, -9.516258331235325e-2),
+    (-1.0e-3, -9.99500214074986e-4),
+    (-1.0e-7, -9.999999616860979e-8),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (1.0e-30, 1.0000000031710769e-30),
+    (1.0e-7, 1.0000000616861003e-7),
+    (1.0e-3, 1.0005002142533142e-3),
+    (1.0e-1, 1.0517091972248063e-1),
+    (3.0e-1, 3.4985882366757415e-1),
+    (5.0e-1, 6.487212707001282e-1),
+    (6.931472e-1, 1.0000000038093086e0),
+    (1.0e0, 1.7182818284590453e0),
+    (2.0e0, 6.38905609893065e0),
+    (5.0e0, 1.474131591025766e2),
+    (1.0e1, 2.2025465794806718e4),
+    (2.0e1, 4.851651944097903e8),
+    (5.0e1, 5.184705528587072e21),
+    (8.8e1, 1.6516362549940018e38),
+    (8.872283e1, 3.4027985374118487e38),
+];
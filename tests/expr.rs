@@ -0,0 +1,137 @@
+//! Fused expressions give the results of the operations they chain, on every backend
+//! the CPU supports, across the register tails and the parallel chunk boundaries
+
+mod common;
+
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::accuracy::Accuracy;
+use arithmetics::ops::atan2::SimdAtan2;
+use arithmetics::ops::expr::expr;
+use arithmetics::ops::fma::SimdFma;
+use common::{assert_same, for_each_backend, values, LENGTHS};
+
+#[test]
+fn arithmetic() {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let (a, b, c, d) = (
+                values(len, 1),
+                values(len, 2),
+                values(len, 3),
+                values(len, 4),
+            );
+
+            // Each operator rounds its own result, the product is not fused into the sum
+            let want: Vec<f32> = (0..len).map(|i| a[i] + b[i] * c[i] - d[i]).collect();
+            let got = (expr(&a) + expr(&b) * c.as_slice() - &d).eval();
+            assert_same("a + b * c - d", backend, &got, &want);
+
+            let want: Vec<f32> = (0..len).map(|i| (a[i] - b[i]) / c[i]).collect();
+            let got = ((expr(&a) - &b) / &c).eval();
+            assert_same("(a - b) / c", backend, &got, &want);
+
+            // Scalars are broadcast on either side
+            let want: Vec<f32> = a.iter().map(|a| 1.0 - (2.0 * a + 0.5) / 3.0).collect();
+            let got = (1.0 - (2.0 * expr(&a) + 0.5) / 3.0).eval();
+            assert_same("1 - (2a + 0.5) / 3", backend, &got, &want);
+
+            let mut out = vec![f32::NAN; len];
+            (expr(&a) + expr(&b) * c.as_slice() - &d).eval_into(&mut out);
+            let want: Vec<f32> = (0..len).map(|i| a[i] + b[i] * c[i] - d[i]).collect();
+            assert_same("eval_into", backend, &out, &want);
+        }
+    });
+}
+
+#[test]
+fn functions() {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let (a, b, c) = (values(len, 1), values(len, 2), values(len, 3));
+
+            let want: Vec<f32> = (0..len).map(|i| a[i].min(b[i]).max(c[i])).collect();
+            assert_same("min max", backend, &expr(&a).min(&b).max(&c).eval(), &want);
+
+            let want: Vec<f32> = a.iter().map(|a| a.clamp(-0.25, 0.5)).collect();
+            assert_same("clamp", backend, &expr(&a).clamp(-0.25, 0.5).eval(), &want);
+
+            // Same kernels as the slice operations, including the unfused SSE fallback
+            let want = a.as_slice().simd_fma(b.as_slice(), c.as_slice());
+            assert_same("fma", backend, &expr(&a).fma(&b, &c).eval(), &want);
+
+            let want = a.as_slice().simd_atan2(b.as_slice());
+            assert_same("atan2", backend, &expr(&a).atan2(&b).eval(), &want);
+        }
+    });
+}
+
+#[test]
+fn accuracy() {
+    // atan2 nodes run the tier active when they are built, like `simd_atan2`
+    for_each_backend(|backend| {
+        let (a, b) = (values(40000, 1), values(40000, 2));
+
+        for accuracy in Accuracy::ALL {
+            Accuracy::set_active(accuracy);
+            let want = a.as_slice().simd_atan2(b.as_slice());
+            let node = expr(&a).atan2(&b) * 2.0;
+
+            Accuracy::set_active(Accuracy::Default);
+            let want: Vec<f32> = want.iter().map(|x| x * 2.0).collect();
+            assert_same(accuracy.name(), backend, &node.eval(), &want);
+        }
+    });
+}
+
+#[test]
+fn scalars_only() {
+    // Without a slice the size comes from the output buffer
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let mut out = vec![f32::NAN; len];
+            (expr(2.0) * 3.0 + 1.0).eval_into(&mut out);
+
+            assert_same("2 * 3 + 1", backend, &out, &vec![7.0; len]);
+            assert!((expr(2.0) * 3.0).eval().is_empty(), "{:?}", backend);
+        }
+    });
+}
+
+#[test]
+fn errors() {
+    let (a, b, c) = (values(17, 1), values(16, 2), values(17, 3));
+    let err = ArithmeticsError::LengthMismatch {
+        left: 17,
+        right: 16,
+    };
+
+    for_each_backend(|backend| {
+        // Mismatches are found anywhere in the tree
+        assert_eq!((expr(&a) + &b).try_eval(), Err(err), "{:?}", backend);
+        assert_eq!(
+            (expr(&a) * 2.0 - (expr(&c) / &b)).try_eval(),
+            Err(err),
+            "{:?}",
+            backend
+        );
+        assert_eq!(expr(&a).fma(&c, &b).try_eval(), Err(err), "{:?}", backend);
+        assert!(
+            matches!(
+                expr(&a).clamp(1.0, -1.0).try_eval(),
+                Err(ArithmeticsError::InvalidBounds { .. })
+            ),
+            "{:?}",
+            backend
+        );
+
+        // The output buffer is left untouched
+        let mut out = vec![0.0; 16];
+        assert_eq!(
+            (expr(&a) + &c).try_eval_into(&mut out),
+            Err(err),
+            "{:?}",
+            backend
+        );
+        assert_eq!(out, vec![0.0; 16], "{:?}", backend);
+    });
+}
@@ -0,0 +1,90 @@
+//! Every CPU feature is reported, the ones the build guarantees from the `cpu_feature`
+//! cfgs and the others detected at runtime
+
+use arithmetics::backend_info;
+use arithmetics::simd::features::CpuFeatures;
+
+// Every field with its `target_feature` name, in the order of `CpuFeatures::names`
+fn fields(features: &CpuFeatures) -> [(&'static str, bool); 12] {
+    [
+        ("sse4.1", features.sse41),
+        ("avx2", features.avx2),
+        ("fma", features.fma),
+        ("f16c", features.f16c),
+        ("bmi2", features.bmi2),
+        ("avx512f", features.avx512f),
+        ("avx512bw", features.avx512bw),
+        ("avx512vl", features.avx512vl),
+        ("avx512dq", features.avx512dq),
+        ("neon", features.neon),
+        ("fp16", features.fp16),
+        ("dotprod", features.dotprod),
+    ]
+}
+
+#[test]
+fn compiled() {
+    // The tests are compiled for the same target as the crate
+    let want = [
+        cfg!(all(target_arch = "x86_64", target_feature = "sse4.1")),
+        cfg!(all(target_arch = "x86_64", target_feature = "avx2")),
+        cfg!(all(target_arch = "x86_64", target_feature = "fma")),
+        cfg!(all(target_arch = "x86_64", target_feature = "f16c")),
+        cfg!(all(target_arch = "x86_64", target_feature = "bmi2")),
+        cfg!(all(target_arch = "x86_64", target_feature = "avx512f")),
+        cfg!(all(target_arch = "x86_64", target_feature = "avx512bw")),
+        cfg!(all(target_arch = "x86_64", target_feature = "avx512vl")),
+        cfg!(all(target_arch = "x86_64", target_feature = "avx512dq")),
+        cfg!(all(target_arch = "aarch64", target_feature = "neon")),
+        cfg!(all(target_arch = "aarch64", target_feature = "fp16")),
+        cfg!(all(target_arch = "aarch64", target_feature = "dotprod")),
+    ];
+
+    for ((name, got), want) in fields(&CpuFeatures::compiled()).into_iter().zip(want) {
+        assert_eq!(got, want, "{}", name);
+    }
+    assert_eq!(backend_info().build_features, CpuFeatures::compiled());
+}
+
+#[test]
+fn detected() {
+    let features = CpuFeatures::get();
+    assert_eq!(*features, CpuFeatures::detect());
+    assert_eq!(backend_info().features, *features);
+
+    // Compile time features are a subset of the runtime ones
+    let compiled = fields(&CpuFeatures::compiled());
+    for ((name, got), (_, guaranteed)) in fields(features).into_iter().zip(compiled) {
+        assert!(got || !guaranteed, "{}", name);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    for (name, got) in fields(features) {
+        let want = match name {
+            "sse4.1" => is_x86_feature_detected!("sse4.1"),
+            "avx2" => is_x86_feature_detected!("avx2"),
+            "fma" => is_x86_feature_detected!("fma"),
+            "f16c" => is_x86_feature_detected!("f16c"),
+            "bmi2" => is_x86_feature_detected!("bmi2"),
+            "avx512f" => is_x86_feature_detected!("avx512f"),
+            "avx512bw" => is_x86_feature_detected!("avx512bw"),
+            "avx512vl" => is_x86_feature_detected!("avx512vl"),
+            "avx512dq" => is_x86_feature_detected!("avx512dq"),
+            _ => false,
+        };
+        assert_eq!(got, want, "{}", name);
+    }
+}
+
+#[test]
+fn names() {
+    let features = CpuFeatures::get();
+    let want: Vec<&str> = fields(features)
+        .into_iter()
+        .filter(|(_, detected)| *detected)
+        .map(|(name, _)| name)
+        .collect();
+
+    assert_eq!(features.names(), want);
+    assert_eq!(features.to_string(), want.join(","));
+}
@@ -0,0 +1,122 @@
+//! Fused multiply-add on every backend the CPU supports, across the register tails
+//! and the parallel chunk boundaries
+
+mod common;
+
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::fma::{SimdFma, SimdFmaAssign, SimdFmaInto};
+use arithmetics::simd::backend::Backend;
+use common::{for_each_backend, values, LENGTHS};
+
+// SSE has no FMA instruction, the product is rounded before the addition
+fn fma(backend: Backend, a: f32, b: f32, c: f32) -> f32 {
+    match backend {
+        Backend::Sse41 => a * b + c,
+        _ => a.mul_add(b, c),
+    }
+}
+
+#[test]
+fn fma_slices() {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values(len, 1);
+            let b = values(len, 2);
+            let c = values(len, 3);
+            let want: Vec<f32> = (0..len).map(|i| fma(backend, a[i], b[i], c[i])).collect();
+
+            assert_eq!(
+                a.as_slice().simd_fma(b.as_slice(), c.as_slice()),
+                want,
+                "{:?} {} elements",
+                backend,
+                len
+            );
+            assert_eq!(
+                a.clone().simd_fma(b.clone(), c.clone()),
+                want,
+                "{:?} {} elements",
+                backend,
+                len
+            );
+
+            let mut out = vec![f32::NAN; len];
+            a.as_slice()
+                .simd_fma_into(b.as_slice(), c.as_slice(), &mut out);
+            assert_eq!(out, want, "{:?} {} elements", backend, len);
+
+            let mut assigned = a.clone();
+            assigned.simd_fma_assign(b.as_slice(), c.as_slice());
+            assert_eq!(assigned, want, "{:?} {} elements", backend, len);
+        }
+    });
+}
+
+#[test]
+fn rounded_once() {
+    // (1 + 2^-13) * (1 - 2^-13) - 1 is -2^-26 exactly, the rounded product cancels to 0
+    let a = vec![1.0 + 2f32.powi(-13); 19];
+    let b = vec![1.0 - 2f32.powi(-13); 19];
+    let c = vec![-1.0; 19];
+
+    for_each_backend(|backend| {
+        let want = match backend {
+            Backend::Sse41 => 0.0,
+            _ => -2f32.powi(-26),
+        };
+
+        assert_eq!(
+            a.as_slice().simd_fma(b.as_slice(), c.as_slice()),
+            vec![want; 19],
+            "{:?}",
+            backend
+        );
+    });
+}
+
+#[test]
+fn length_mismatch() {
+    let a = values(17, 1);
+    let b = values(16, 2);
+    let c = values(17, 3);
+
+    for_each_backend(|backend| {
+        let err = Err(ArithmeticsError::LengthMismatch {
+            left: 17,
+            right: 16,
+        });
+
+        assert_eq!(
+            a.as_slice().try_simd_fma(b.as_slice(), c.as_slice()),
+            err,
+            "{:?}",
+            backend
+        );
+        assert_eq!(
+            c.as_slice().try_simd_fma(a.as_slice(), b.as_slice()),
+            err,
+            "{:?}",
+            backend
+        );
+
+        // The outputs are left untouched
+        let mut assigned = a.clone();
+        assert_eq!(
+            assigned.try_simd_fma_assign(b.as_slice(), c.as_slice()),
+            err.map(|_| ()),
+            "{:?}",
+            backend
+        );
+        assert_eq!(assigned, a, "{:?}", backend);
+
+        let mut out = vec![0.0; 16];
+        assert!(
+            a.as_slice()
+                .try_simd_fma_into(a.as_slice(), c.as_slice(), &mut out)
+                .is_err(),
+            "{:?}",
+            backend
+        );
+        assert_eq!(out, vec![0.0; 16], "{:?}", backend);
+    });
+}
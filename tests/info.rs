@@ -0,0 +1,83 @@
+//! `backend_info` reports the backend, accuracy and parallelism the operations run with
+
+mod common;
+
+use arithmetics::backend_info;
+use arithmetics::ops::accuracy::Accuracy;
+use arithmetics::simd::backend::Backend;
+use arithmetics::simd::features::CpuFeatures;
+use common::{for_each_backend, GLOBALS};
+
+#[test]
+fn snapshot() {
+    let _guard = GLOBALS.lock().unwrap_or_else(|err| err.into_inner());
+    let info = backend_info();
+
+    assert_eq!(info.backend, Backend::active());
+    assert_eq!(info.instruction_set, info.backend.name());
+    assert_eq!(info.lanes, info.backend.lanes());
+    assert_eq!(info.accuracy, Accuracy::active());
+    assert_eq!(info.backend_env_error, Backend::env_error());
+    assert_eq!(info.accuracy_env_error, Accuracy::env_error());
+    assert_eq!(info.features, *CpuFeatures::get());
+    assert!(
+        ["stable", "beta", "nightly"].contains(&info.rustc_channel),
+        "{}",
+        info.rustc_channel
+    );
+    assert_eq!(info.threads, rayon::current_num_threads());
+    assert_eq!(info.chunk_size, 1 << 14);
+}
+
+#[test]
+fn follows_backend() {
+    for_each_backend(|backend| {
+        let info = backend_info();
+
+        assert_eq!(info.backend, backend);
+        assert_eq!(info.instruction_set, backend.name());
+        assert_eq!(info.lanes, backend.lanes());
+
+        let line = info.to_string();
+        assert!(
+            line.starts_with(&format!(
+                "backend={} lanes={} ",
+                backend.name(),
+                backend.lanes()
+            )),
+            "{}",
+            line
+        );
+    });
+}
+
+#[test]
+fn follows_accuracy() {
+    let _guard = GLOBALS.lock().unwrap_or_else(|err| err.into_inner());
+
+    for accuracy in Accuracy::ALL {
+        Accuracy::set_active(accuracy);
+        let info = backend_info();
+
+        assert_eq!(info.accuracy, accuracy);
+        assert!(
+            info.to_string()
+                .contains(&format!(" accuracy={} ", accuracy.name())),
+            "{}",
+            info
+        );
+    }
+
+    Accuracy::set_active(Accuracy::Default);
+}
+
+#[test]
+fn threads() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .unwrap();
+
+    // The pool running the kernels is the one of the caller
+    assert_eq!(pool.install(|| backend_info().threads), 3);
+}
@@ -0,0 +1,213 @@
+//! Accuracy of the logarithms against 120 digit references from mpmath, on every
+//! backend the CPU supports
+
+mod common;
+
+use arithmetics::ops::unary::SimdUnary;
+use common::{check, check_exact, for_each_backend};
+
+#[test]
+fn ln() {
+    for_each_backend(|backend| {
+        check("ln", backend, &LN, 1.0, |x| x.simd_ln());
+        check_exact(
+            "ln",
+            backend,
+            &[
+                (1.0, 0.0),
+                (0.0, f32::NEG_INFINITY),
+                (-0.0, f32::NEG_INFINITY),
+                (-1.0, f32::NAN),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, f32::NAN),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_ln(),
+        );
+    });
+}
+
+#[test]
+fn log2() {
+    for_each_backend(|backend| {
+        check("log2", backend, &LOG2, 1.0, |x| x.simd_log2());
+        check_exact(
+            "log2",
+            backend,
+            &[
+                (1.0, 0.0),
+                (0.0, f32::NEG_INFINITY),
+                (-0.0, f32::NEG_INFINITY),
+                (-1.0, f32::NAN),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, f32::NAN),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_log2(),
+        );
+    });
+}
+
+#[test]
+fn log10() {
+    for_each_backend(|backend| {
+        check("log10", backend, &LOG10, 1.0, |x| x.simd_log10());
+        check_exact(
+            "log10",
+            backend,
+            &[
+                (1.0, 0.0),
+                (0.0, f32::NEG_INFINITY),
+                (-0.0, f32::NEG_INFINITY),
+                (-1.0, f32::NAN),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, f32::NAN),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_log10(),
+        );
+    });
+}
+
+#[test]
+fn log1p() {
+    for_each_backend(|backend| {
+        check("log1p", backend, &LOG1P, 1.0, |x| x.simd_log1p());
+        check_exact(
+            "log1p",
+            backend,
+            &[
+                (0.0, 0.0),
+                (-0.0, -0.0),
+                (-1.0, f32::NEG_INFINITY),
+                (-2.0, f32::NAN),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, f32::NAN),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_log1p(),
+        );
+    });
+}
+
+const LN: [(f32, f64); 29] = [
+    (1.0e-45, -1.0327892990343184e2),
+    (3.0e-42, -9.560990161484217e1),
+    (1.1754942e-38, -8.73365448697624e1),
+    (1.1754944e-38, -8.73365447505531e1),
+    (1.0e-30, -6.907755278665029e1),
+    (1.0e-10, -2.3025850916589025e1),
+    (1.0e-3, -6.907755231484687e0),
+    (1.0e-1, -2.3025850780928847e0),
+    (3.0e-1, -1.2039727645895069e0),
+    (5.0e-1, -std::f64::consts::LN_2),
+    (7.0710677e-1, -3.465736073942438e-1),
+    (7.5e-1, -2.876820724517809e-1),
+    (9.999999e-1, -1.1920929665620916e-7),
+    (1.0e0, 0.0e0),
+    (1.0000001e0, 1.1920928244535446e-7),
+    (1.25e0, 2.2314355131420976e-1),
+    (std::f32::consts::SQRT_2, 3.465735731657015e-1),
+    (1.5e0, 4.054651081081644e-1),
+    (2.0e0, std::f64::consts::LN_2),
+    (2.7182817e0, 9.9999996963214e-1),
+    (3.0e0, 1.0986122886681098e0),
+    (1.0e1, std::f64::consts::LN_10),
+    (1.0e2, 4.605170185988092e0),
+    (1.024e3, 6.931471805599453e0),
+    (1.2345678e4, 9.421061299776206e0),
+    (1.0e10, 2.3025850929940457e1),
+    (1.0e20, 4.6051701879921794e1),
+    (1.0e30, 6.907755280486883e1),
+    (3.4028235e38, 8.872283905206835e1),
+];
+
+const LOG2: [(f32, f64); 29] = [
+    (1.0e-45, -1.49e2),
+    (3.0e-42, -1.379359309196145e2),
+    (1.1754942e-38, -1.2600000017198266e2),
+    (1.1754944e-38, -1.26e2),
+    (1.0e-30, -9.965784284204598e1),
+    (1.0e-10, -3.321928092961158e1),
+    (1.0e-3, -9.96578421613775e0),
+    (1.0e-1, -3.321928073389531e0),
+    (3.0e-1, -1.736965536838657e0),
+    (5.0e-1, -1.0e0),
+    (7.0710677e-1, -5.000000246906742e-1),
+    (7.5e-1, -4.150374992788438e-1),
+    (9.999999e-1, -1.7198266111377426e-7),
+    (1.0e0, 0.0e0),
+    (1.0000001e0, 1.7198264061184464e-7),
+    (1.25e0, 3.2192809488736235e-1),
+    (std::f32::consts::SQRT_2, 4.9999997530932583e-1),
+    (1.5e0, 5.849625007211562e-1),
+    (2.0e0, 1.0e0),
+    (2.7182817e0, 1.4426949970774023e0),
+    (3.0e0, 1.584962500721156e0),
+    (1.0e1, std::f64::consts::LOG2_10),
+    (1.0e2, 6.643856189774724e0),
+    (1.024e3, 1.0e1),
+    (1.2345678e4, 1.3591718417098063e1),
+    (1.0e10, 3.3219280948873624e1),
+    (1.0e20, 6.643856192666011e1),
+    (1.0e30, 9.965784286832978e1),
+    (3.4028235e38, 1.2799999991400867e2),
+];
+
+const LOG10: [(f32, f64); 29] = [
+    (1.0e-45, -4.48534693539332e1),
+    (3.0e-42, -4.152285268663876e1),
+    (1.1754942e-38, -3.792977950543357e1),
+    (1.1754944e-38, -3.792977945366163e1),
+    (1.0e-30, -2.999999999862282e1),
+    (1.0e-10, -9.999999994201547e0),
+    (1.0e-3, -2.9999999793721193e0),
+    (1.0e-1, -9.99999993528508e-1),
+    (3.0e-1, -5.228787280230257e-1),
+    (5.0e-1, -std::f64::consts::LOG10_2),
+    (7.0710677e-1, -1.5051500526462414e-1),
+    (7.5e-1, -1.2493873660829995e-1),
+    (9.999999e-1, -5.177193972935941e-8),
+    (1.0e0, 0.0e0),
+    (1.0000001e0, 5.1771933557663626e-8),
+    (1.25e0, 9.691001300805642e-2),
+    (std::f32::consts::SQRT_2, 1.5051499039935706e-1),
+    (1.5e0, 1.7609125905568124e-1),
+    (2.0e0, std::f64::consts::LOG10_2),
+    (2.7182817e0, 4.342944687146578e-1),
+    (3.0e0, 4.7712125471966244e-1),
+    (1.0e1, 1.0e0),
+    (1.0e2, 2.0e0),
+    (1.024e3, 3.010299956639812e0),
+    (1.2345678e4, 4.091514936165083e0),
+    (1.0e10, 1.0e1),
+    (1.0e20, 2.0000000008703644e1),
+    (1.0e30, 3.0000000006535032e1),
+    (3.4028235e38, 3.8531839419103626e1),
+];
+
+const LOG1P: [(f32, f64); 23] = [
+    (-9.9999994e-1, -1.6635532333438686e1),
+    (-9.0e-1, -2.302584854575495e0),
+    (-5.0e-1, -std::f64::consts::LN_2),
+    (-2.9289323e-1, -3.465736073942438e-1),
+    (-1.0e-1, -1.0536051731351088e-1),
+    (-1.0e-3, -1.0005003811285299e-3),
+    (-1.0e-7, -1.000000061686102e-7),
+    (-1.0e-30, -1.0000000031710769e-30),
+    (1.0e-30, 1.0000000031710769e-30),
+    (1.0e-7, 9.999999616860996e-8),
+    (1.0e-3, 9.995003805335345e-4),
+    (1.0e-1, 9.531018115897588e-2),
+    (4.1421357e-1, 3.4657359423912587e-1),
+    (5.0e-1, 4.054651081081644e-1),
+    (1.0e0, std::f64::consts::LN_2),
+    (1.7182817e0, 9.9999996963214e-1),
+    (2.0e0, 1.0986122886681098e0),
+    (1.0e1, 2.3978952727983707e0),
+    (1.0e3, 6.90875477931522e0),
+    (1.0e10, 2.3025850930040455e1),
+    (1.0e20, 4.6051701879921794e1),
+    (1.0e30, 6.907755280486883e1),
+    (3.4028235e38, 8.872283905206835e1),
+];
@@ -0,0 +1,164 @@
+//! NaN semantics of min, max and clamp on every backend the CPU supports, across
+//! the register tails and the parallel chunk boundaries
+
+mod common;
+
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::clamp::{SimdClamp, SimdClampAssign};
+use arithmetics::ops::max::SimdMax;
+use arithmetics::ops::min::SimdMin;
+use common::{assert_equal, for_each_backend, values_with_nans, LENGTHS};
+
+#[test]
+fn min() {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values_with_nans(len, 1, 5);
+            let b = values_with_nans(len, 2, 7);
+            let want: Vec<f32> = a.iter().zip(&b).map(|(a, b)| a.min(*b)).collect();
+
+            assert_equal(
+                "min",
+                backend,
+                &a,
+                &a.as_slice().simd_min(b.as_slice()),
+                &want,
+            );
+            assert_equal("min", backend, &a, &a.clone().simd_min(b.clone()), &want);
+
+            // A NaN scalar is ignored, NaN elements give the scalar
+            assert_equal("min NaN", backend, &a, &a.as_slice().simd_min(f32::NAN), &a);
+
+            let want: Vec<f32> = a.iter().map(|a| a.min(1.5)).collect();
+            assert_equal(
+                "min scalar",
+                backend,
+                &a,
+                &a.as_slice().simd_min(1.5),
+                &want,
+            );
+            assert_equal(
+                "min scalar",
+                backend,
+                &a,
+                &1.5.simd_min(a.as_slice()),
+                &want,
+            );
+        }
+    });
+}
+
+#[test]
+fn max() {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values_with_nans(len, 1, 5);
+            let b = values_with_nans(len, 2, 7);
+            let want: Vec<f32> = a.iter().zip(&b).map(|(a, b)| a.max(*b)).collect();
+
+            assert_equal(
+                "max",
+                backend,
+                &a,
+                &a.as_slice().simd_max(b.as_slice()),
+                &want,
+            );
+            assert_equal("max", backend, &a, &a.clone().simd_max(b.clone()), &want);
+
+            // A NaN scalar is ignored, NaN elements give the scalar
+            assert_equal("max NaN", backend, &a, &a.as_slice().simd_max(f32::NAN), &a);
+
+            let want: Vec<f32> = a.iter().map(|a| a.max(1.5)).collect();
+            assert_equal(
+                "max scalar",
+                backend,
+                &a,
+                &a.as_slice().simd_max(1.5),
+                &want,
+            );
+            assert_equal(
+                "max scalar",
+                backend,
+                &a,
+                &1.5.simd_max(a.as_slice()),
+                &want,
+            );
+        }
+    });
+}
+
+#[test]
+fn two_nans() {
+    let nans = vec![f32::NAN; 17];
+
+    for_each_backend(|backend| {
+        let min = nans.as_slice().simd_min(nans.as_slice());
+        let max = nans.as_slice().simd_max(nans.as_slice());
+
+        assert!(min.iter().all(|y| y.is_nan()), "{:?} {:?}", backend, min);
+        assert!(max.iter().all(|y| y.is_nan()), "{:?} {:?}", backend, max);
+    });
+}
+
+#[test]
+fn clamp() {
+    let bounds = [
+        (-1.5, 2.5),
+        (0.0, 0.0),
+        (f32::NEG_INFINITY, 0.0),
+        (-7.0, f32::INFINITY),
+    ];
+
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values_with_nans(len, 1, 5);
+
+            for (lo, hi) in bounds {
+                // NaN elements are propagated, like `f32::clamp`
+                let want: Vec<f32> = a.iter().map(|a| a.clamp(lo, hi)).collect();
+
+                assert_equal(
+                    "clamp",
+                    backend,
+                    &a,
+                    &a.as_slice().simd_clamp(lo, hi),
+                    &want,
+                );
+                assert_equal("clamp", backend, &a, &a.clone().simd_clamp(lo, hi), &want);
+
+                let mut assigned = a.clone();
+                assigned.simd_clamp_assign(lo, hi);
+                assert_equal("clamp_assign", backend, &a, &assigned, &want);
+            }
+        }
+    });
+}
+
+#[test]
+fn invalid_bounds() {
+    let a = values_with_nans(17, 1, 5);
+
+    for_each_backend(|backend| {
+        for (lo, hi) in [(1.0, -1.0), (f32::NAN, 1.0), (-1.0, f32::NAN)] {
+            let got = a.as_slice().try_simd_clamp(lo, hi);
+
+            assert!(
+                matches!(got, Err(ArithmeticsError::InvalidBounds { .. })),
+                "{:?} clamp({}, {}) = {:?}",
+                backend,
+                lo,
+                hi,
+                got
+            );
+
+            // The operand is left untouched
+            let mut assigned = a.clone();
+            assert!(
+                assigned.try_simd_clamp_assign(lo, hi).is_err(),
+                "{:?}",
+                backend
+            );
+            assert_equal("clamp_assign", backend, &a, &assigned, &a);
+        }
+    });
+}
@@ -0,0 +1,59 @@
+//! Accuracy of the refined reciprocal square root against f64, on every backend the
+//! CPU supports
+
+mod common;
+
+use arithmetics::ops::unary::SimdUnary;
+use arithmetics::simd::backend::Backend;
+use common::{for_each_backend, ulp_error};
+
+#[test]
+fn rsqrt() {
+    // Every positive f32 from the smallest subnormal to the largest finite, with a
+    // stride coprime with the mantissa so that every binade is sampled throughout
+    let x: Vec<f32> = (1..0x7f80_0000)
+        .step_by(4099)
+        .map(f32::from_bits)
+        .chain([f32::MIN_POSITIVE, 1.0, 4.0, f32::MAX])
+        .collect();
+
+    for_each_backend(|backend| {
+        // Without FMA the residual of the last Newton-Raphson step is rounded
+        let ulp = match backend {
+            Backend::Sse41 => 1.5,
+            _ => 0.6,
+        };
+        let y = x.as_slice().simd_rsqrt();
+
+        for (&x, &got) in x.iter().zip(&y) {
+            let want = 1.0 / (x as f64).sqrt();
+            let err = ulp_error(got, want);
+
+            assert!(
+                err <= ulp,
+                "{:?} rsqrt({:e}) = {:e}, expected {:e} ({:.2} ulp)",
+                backend,
+                x,
+                got,
+                want,
+                err
+            );
+        }
+
+        let specials = [0.0, -0.0, f32::INFINITY, -1.0, f32::NEG_INFINITY, f32::NAN];
+        let got = specials.as_slice().simd_rsqrt();
+
+        assert_eq!(
+            got[..3],
+            [f32::INFINITY, f32::NEG_INFINITY, 0.0],
+            "{:?}",
+            backend
+        );
+        assert!(
+            got[3..].iter().all(|y| y.is_nan()),
+            "{:?} {:?}",
+            backend,
+            got
+        );
+    });
+}
@@ -0,0 +1,106 @@
+//! Binary operations with an f32 broadcast on either side, on every backend the CPU
+//! supports, across the register tails and the parallel chunk boundaries
+
+mod common;
+
+use arithmetics::ops::add::SimdAdd;
+use arithmetics::ops::atan2::SimdAtan2;
+use arithmetics::ops::div::SimdDiv;
+use arithmetics::ops::mul::SimdMul;
+use arithmetics::ops::sub::SimdSub;
+use common::{assert_same, for_each_backend, values, LENGTHS};
+
+// Broadcast values, including the ones whose results are not finite
+const SCALARS: [f32; 6] = [0.75, -3.0, 0.0, -0.0, f32::INFINITY, f32::NAN];
+
+// `slice op scalar`, `scalar op slice` and `Vec op scalar` against `op` lane by lane
+fn check(
+    name: &str,
+    op: impl Fn(f32, f32) -> f32,
+    slice_scalar: impl Fn(&[f32], f32) -> Vec<f32>,
+    scalar_slice: impl Fn(f32, &[f32]) -> Vec<f32>,
+    vec_scalar: impl Fn(Vec<f32>, f32) -> Vec<f32>,
+) {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values(len, 1);
+
+            for s in SCALARS {
+                let name = format!("{} with {:e}", name, s);
+
+                let want: Vec<f32> = a.iter().map(|&a| op(a, s)).collect();
+                assert_same(&name, backend, &slice_scalar(&a, s), &want);
+                assert_same(&name, backend, &vec_scalar(a.clone(), s), &want);
+
+                let want: Vec<f32> = a.iter().map(|&a| op(s, a)).collect();
+                assert_same(&name, backend, &scalar_slice(s, &a), &want);
+            }
+        }
+    });
+}
+
+#[test]
+fn add() {
+    check(
+        "add",
+        |a, b| a + b,
+        |a, s| a.simd_add(s),
+        |s, a| s.simd_add(a),
+        |a, s| a.simd_add(s),
+    );
+}
+
+#[test]
+fn sub() {
+    check(
+        "sub",
+        |a, b| a - b,
+        |a, s| a.simd_sub(s),
+        |s, a| s.simd_sub(a),
+        |a, s| a.simd_sub(s),
+    );
+}
+
+#[test]
+fn mul() {
+    check(
+        "mul",
+        |a, b| a * b,
+        |a, s| a.simd_mul(s),
+        |s, a| s.simd_mul(a),
+        |a, s| a.simd_mul(s),
+    );
+}
+
+#[test]
+fn div() {
+    check(
+        "div",
+        |a, b| a / b,
+        |a, s| a.simd_div(s),
+        |s, a| s.simd_div(a),
+        |a, s| a.simd_div(s),
+    );
+}
+
+#[test]
+fn atan2() {
+    // Broadcasting the scalar must not change the kernel computing each lane
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let a = values(len, 1);
+
+            for s in SCALARS {
+                let name = format!("atan2 with {:e}", s);
+                let splat = vec![s; len];
+
+                let want = a.as_slice().simd_atan2(splat.as_slice());
+                assert_same(&name, backend, &a.as_slice().simd_atan2(s), &want);
+                assert_same(&name, backend, &a.clone().simd_atan2(s), &want);
+
+                let want = splat.as_slice().simd_atan2(a.as_slice());
+                assert_same(&name, backend, &s.simd_atan2(a.as_slice()), &want);
+            }
+        }
+    });
+}
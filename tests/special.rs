@@ -0,0 +1,330 @@
+//! Accuracy of the special functions against 50 digit references from mpmath,
+//! on every backend the CPU supports
+
+mod common;
+
+use arithmetics::ops::unary::SimdUnary;
+use common::{check, check_exact, for_each_backend};
+
+#[test]
+fn erf() {
+    for_each_backend(|backend| {
+        check("erf", backend, &ERF, 1.0, |x| x.simd_erf());
+        check_exact(
+            "erf",
+            backend,
+            &[
+                (0.0, 0.0),
+                (-0.0, -0.0),
+                (f32::INFINITY, 1.0),
+                (f32::NEG_INFINITY, -1.0),
+                (10.0, 1.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_erf(),
+        );
+    });
+}
+
+#[test]
+fn erfc() {
+    for_each_backend(|backend| {
+        check("erfc", backend, &ERFC, 1.0, |x| x.simd_erfc());
+        check_exact(
+            "erfc",
+            backend,
+            &[
+                (0.0, 1.0),
+                (f32::INFINITY, 0.0),
+                (f32::NEG_INFINITY, 2.0),
+                (11.0, 0.0),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_erfc(),
+        );
+    });
+}
+
+#[test]
+fn tgamma() {
+    for_each_backend(|backend| {
+        check("tgamma", backend, &TGAMMA, 1.0, |x| x.simd_tgamma());
+        check("tgamma", backend, &TGAMMA_NEG, 1.0, |x| x.simd_tgamma());
+        check_exact(
+            "tgamma",
+            backend,
+            &[
+                (1.0, 1.0),
+                (2.0, 1.0),
+                (3.0, 2.0),
+                (11.0, 3628800.0),
+                (0.0, f32::INFINITY),
+                (-0.0, f32::NEG_INFINITY),
+                (36.0, f32::INFINITY),
+                (1.0e-39, f32::INFINITY),
+                (-1.0e-39, f32::NEG_INFINITY),
+                (-43.5, 0.0),
+                (f32::INFINITY, f32::INFINITY),
+                (-1.0, f32::NAN),
+                (-20.0, f32::NAN),
+                (f32::NEG_INFINITY, f32::NAN),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_tgamma(),
+        );
+    });
+}
+
+#[test]
+fn lgamma() {
+    for_each_backend(|backend| {
+        check("lgamma", backend, &LGAMMA, 1.0, |x| x.simd_lgamma());
+        check("lgamma", backend, &LGAMMA_NEG, 1.0, |x| x.simd_lgamma());
+        check_exact(
+            "lgamma",
+            backend,
+            &[
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (0.0, f32::INFINITY),
+                (-0.0, f32::INFINITY),
+                (-3.0, f32::INFINITY),
+                (f32::INFINITY, f32::INFINITY),
+                (f32::NEG_INFINITY, f32::INFINITY),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_lgamma(),
+        );
+    });
+}
+
+const ERF: [(f32, f64); 37] = [
+    (-3.7000346e0, -9.999998328932228e-1),
+    (-3.5360086e0, -9.999994286896202e-1),
+    (-3.4411566e0, -9.999988642552254e-1),
+    (-3.4205098e0, -9.999986840605747e-1),
+    (-3.2742958e0, -9.999963531053034e-1),
+    (-3.0095844e0, -9.999792064738443e-1),
+    (-2.7932067e0, -9.999219110774029e-1),
+    (-2.2140882e0, -9.982590262871243e-1),
+    (-1.4093379e0, -9.537500210692694e-1),
+    (-1.0744886e0, -8.713774988813847e-1),
+    (-8.265562e-1, -7.57566954301836e-1),
+    (-6.038465e-1, -6.06877222404337e-1),
+    (-5.3083456e-1, -5.471748599930027e-1),
+    (0.0e0, 0.0e0),
+    (1.0e-30, 1.1283791706736896e-30),
+    (1.0e-8, 1.1283791602378209e-8),
+    (1.0e-3, 1.1283788445643173e-3),
+    (5.9485864e-2, 6.704352089200141e-2),
+    (1.0e-1, 1.1246291768297051e-1),
+    (2.5e-1, 2.7632639016823696e-1),
+    (2.8705603e-1, 3.152269281474899e-1),
+    (5.0e-1, 5.204998778130465e-1),
+    (6.168236e-1, 6.169663446852848e-1),
+    (7.5e-1, 7.111556336535151e-1),
+    (9.0e-1, 7.969082004549685e-1),
+    (1.0e0, 8.427007929497149e-1),
+    (1.0194658e0, 8.506249330098726e-1),
+    (1.2074758e0, 9.122947267656359e-1),
+    (1.5e0, 9.661051464753108e-1),
+    (2.0e0, 9.953222650189527e-1),
+    (2.5e0, 9.99593047982555e-1),
+    (2.614817e0, 9.997826176664252e-1),
+    (3.0e0, 9.999779095030014e-1),
+    (3.5e0, 9.999992569016276e-1),
+    (3.5816715e0, 9.999995921318011e-1),
+    (3.9e0, 9.999999652077781e-1),
+    (4.5e0, 9.999999998033839e-1),
+];
+
+const ERFC: [(f32, f64); 38] = [
+    (-3.0e0, 1.9999779095030015e0),
+    (-1.4410079e0, 1.9584393500461883e0),
+    (-1.284786e0, 1.930777032086596e0),
+    (-1.2465323e0, 1.9220763867863333e0),
+    (-1.0e0, 1.8427007929497148e0),
+    (-5.8649313e-1, 1.5931369140870193e0),
+    (-3.0e-1, 1.3286267717527154e0),
+    (-2.68939e-1, 1.2963048489418154e0),
+    (0.0e0, 1.0e0),
+    (1.0e-6, 9.999988716208358e-1),
+    (1.6871656e-1, 8.114147925809269e-1),
+    (2.0e-1, 7.772974075585481e-1),
+    (4.7150454e-1, 5.048947091349852e-1),
+    (4.9e-1, 4.88331730347372e-1),
+    (5.0e-1, 4.795001221869535e-1),
+    (7.5e-1, 2.8884436634648486e-1),
+    (1.0e0, 1.5729920705028513e-1),
+    (1.4753114e0, 3.694191730256152e-2),
+    (1.7017819e0, 1.609813647841208e-2),
+    (1.7697661e0, 1.2320567852025836e-2),
+    (2.0e0, 4.677734981047266e-3),
+    (2.4687705e0, 4.805525454046387e-4),
+    (3.0e0, 2.209049699858544e-5),
+    (3.1311076e0, 9.50867507038367e-6),
+    (4.0e0, 1.541725790028002e-8),
+    (4.5729337e0, 9.989835624112458e-11),
+    (4.979202e0, 1.8997012945977784e-12),
+    (5.0267425e0, 1.1698216937124888e-12),
+    (5.5e0, 7.357847917974398e-15),
+    (5.6669617e0, 1.1077140385117572e-15),
+    (6.1647997e0, 2.8230182444317666e-18),
+    (7.0e0, 4.183825607779414e-23),
+    (7.793516e0, 3.003452214563146e-28),
+    (8.301621e0, 7.923719245810678e-32),
+    (8.5e0, 2.7623240713337716e-33),
+    (9.5e0, 3.7692144856548797e-41),
+    (9.715061e0, 5.914894078611881e-43),
+    (1.0e1, 2.088487583762545e-45),
+];
+
+const TGAMMA: [(f32, f64); 39] = [
+    (3.0e-39, 3.3333326156490436e38),
+    (1.0e-6, 9.999994253100815e5),
+    (1.0e-1, 9.513507550899174e0),
+    (5.0e-1, 1.772453850905516e0),
+    (9.0e-1, 1.0686287213534196e0),
+    (1.0e0, 1.0e0),
+    (1.2e0, 9.181687297451048e-1),
+    (1.372254e0, 8.891302934299948e-1),
+    (2.0e0, 1.0e0),
+    (2.5e0, 1.329340388179137e0),
+    (3.0e0, 2.0e0),
+    (4.1323023e0, 7.102170043032385e0),
+    (5.3194585e0, 3.92610578726213e1),
+    (5.5e0, 5.234277778455352e1),
+    (8.543378e0, 1.5361419510594504e4),
+    (1.0e1, 3.6288e5),
+    (1.0077822e1, 4.325184902701599e5),
+    (1.0491845e1, 1.112196971459587e6),
+    (1.4634298e1, 3.293682492978349e10),
+    (1.53e1, 1.9506657665034842e11),
+    (1.5861453e1, 8.950390658450939e11),
+    (1.7113708e1, 2.8789567021752117e13),
+    (1.8381878e1, 1.0655549511424305e15),
+    (2.0055908e1, 1.4363344076351467e17),
+    (2.010483e1, 1.6613295555265866e17),
+    (2.07e1, 9.852452834821711e17),
+    (2.3387554e1, 3.769341512826575e21),
+    (2.4464806e1, 1.1266075714607342e23),
+    (2.45e1, 1.2599063430729375e23),
+    (2.5530584e1, 3.4061864615632155e24),
+    (2.6499933e1, 7.869553021615549e25),
+    (2.6759981e1, 1.8385711901111376e26),
+    (2.7803282e1, 5.677294102249194e27),
+    (3.0e1, 8.841761993739702e30),
+    (3.0629812e1, 7.501753448725854e31),
+    (3.33e1, 7.487557657016626e35),
+    (3.4306118e1, 2.547650429368084e37),
+    (3.5e1, 2.9523279903960416e38),
+    (3.504e1, 3.401648619573705e38),
+];
+
+const TGAMMA_NEG: [(f32, f64); 35] = [
+    (-3.9097485e1, 3.569376472796578e-46),
+    (-3.86e1, -7.032685232127098e-46),
+    (-3.7573223e1, 2.923042655573384e-44),
+    (-3.406213e1, -4.402764656208434e-38),
+    (-3.187327e1, 4.786071870479758e-35),
+    (-2.8616179e1, -1.3908248987725045e-30),
+    (-2.74501e1, 6.547573336554585e-29),
+    (-2.525e1, 1.2730538246146572e-25),
+    (-2.4568342e1, -8.358965480638452e-25),
+    (-2.1751787e1, 8.597884730209902e-21),
+    (-2.1532188e1, 1.1996772306333116e-20),
+    (-2.1036066e1, 4.868741855454311e-19),
+    (-1.99e1, 5.650871123425478e-18),
+    (-1.6804192e1, -2.677785956289299e-14),
+    (-1.6225204e1, -1.2268668110175595e-13),
+    (-1.4114846e1, -7.505041490432957e-11),
+    (-1.3433911e1, 1.6549902332444095e-10),
+    (-1.3253891e1, 3.631645591345354e-10),
+    (-1.2188186e1, -7.305008172751356e-9),
+    (-1.1940319e1, 4.0905760470020646e-8),
+    (-1.15e1, 2.2957581048241013e-8),
+    (-7.2e0, 7.067706165660018e-4),
+    (-7.1230087e0, 1.289385058418782e-3),
+    (-6.401289e0, -2.1349950628811666e-3),
+    (-4.9808874e0, -4.5072067933074317e-1),
+    (-3.7e0, 2.516440060427754e-1),
+    (-2.3e0, -1.447107623162728e0),
+    (-2.2127562e0, -2.06479765247742e0),
+    (-1.5e0, 2.363271801207355e0),
+    (-9.0e-1, -1.0570561762642418e1),
+    (-5.0e-1, -3.544907701811032e0),
+    (-2.7616242e-1, -4.569217496700635e0),
+    (-1.0e-1, -1.0686286873976423e1),
+    (-1.0e-6, -1.0000005797414113e6),
+    (-3.0e-39, -3.3333326156490436e38),
+];
+
+const LGAMMA: [(f32, f64); 41] = [
+    (1.0e-30, 6.907755278665029e1),
+    (1.0e-6, 1.381550998327419e1),
+    (1.0e-1, 2.252712636201601e0),
+    (5.0e-1, 5.723649429247001e-1),
+    (9.0e-1, 6.637625773360459e-2),
+    (9.9e-1, 5.854801101922376e-3),
+    (1.01e0, -5.690302597049016e-3),
+    (1.2e0, -8.53741037858107e-2),
+    (1.5e0, -1.2078223763524522e-1),
+    (1.99e0, -4.1955251185052085e-3),
+    (2.01e0, 4.2600188137990585e-3),
+    (2.3581767e0, 1.9000660227064933e-1),
+    (2.5e0, 2.846828704729192e-1),
+    (3.0e0, std::f64::consts::LN_2),
+    (3.223252e0, 9.087286372033766e-1),
+    (4.6838317e0, 2.713195451039716e0),
+    (5.173609e0, 3.442822662790838e0),
+    (5.5e0, 3.9578139676187165e0),
+    (6.036836e0, 4.8504612910083695e0),
+    (6.7219353e0, 6.064518159153115e0),
+    (7.9e0, 8.324266058957152e0),
+    (8.1e0, 8.727389037383563e0),
+    (9.904593e0, 1.2587475814742824e1),
+    (1.0e1, 1.2801827480081469e1),
+    (1.1136843e1, 1.5427120011208087e1),
+    (1.4350846e1, 2.3469905997510782e1),
+    (1.5637988e1, 2.6911251221461704e1),
+    (1.661186e1, 2.9588305234952387e1),
+    (1.7967497e1, 3.341206874358888e1),
+    (2.1977596e1, 4.53114127646323e1),
+    (3.0729319e1, 7.373431382974064e1),
+    (3.2771194e1, 8.07622306541158e1),
+    (3.455938e1, 8.702341869835553e1),
+    (3.485688e1, 8.807433834961617e1),
+    (3.5335354e1, 8.976993814528677e1),
+    (3.5367714e1, 8.988485303092335e1),
+    (3.830925e1, 1.0045272018177057e2),
+    (1.0e2, 3.591342053695754e2),
+    (1.0e4, 8.209971749644238e4),
+    (1.0e10, 2.2025850928881058e11),
+    (1.0e30, 6.807755382926351e31),
+];
+
+const LGAMMA_NEG: [(f32, f64); 23] = [
+    (-1.005e2, -3.6490096830942736e2),
+    (-3.187327e1, -7.902476824860251e1),
+    (-1.99e1, -3.971472195948191e1),
+    (-1.15e1, -1.7589617626087044e1),
+    (-7.2e0, -7.254804390870157e0),
+    (-5.0000005e0, 9.768598235436858e0),
+    (-4.01e0, 1.4121853941292222e0),
+    (-3.9552944e0, 1.7780596858250852e-6),
+    (-3.5e0, -1.309006684993042e0),
+    (-3.143581e0, -1.9605461541614247e-7),
+    (-2.99e0, 2.8261231291653655e0),
+    (-2.7476826e0, -1.4418167987778365e-7),
+    (-2.6869106e0, -8.47659014352658e-2),
+    (-2.4671e0, -1.4778148360373321e-2),
+    (-2.4570248e0, -1.1287842529645816e-7),
+    (-1.5e0, 8.60047015376481e-1),
+    (-9.0e-1, 2.3580729453613305e0),
+    (-5.0e-1, 1.2655121234846454e0),
+    (-1.0e-1, 2.3689613189525565e0),
+    (-1.0e-6, 1.3815511137705517e1),
+    (-1.0e-30, 6.907755278665029e1),
+    (-1.0e-40, 9.210340910966488e1),
+    (-1.0e-45, 1.0327892990343184e2),
+];
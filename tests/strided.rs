@@ -0,0 +1,170 @@
+//! Strided views gathered on every backend the CPU supports, across the register
+//! tails and the parallel chunk boundaries
+
+mod common;
+
+use arithmetics::error::ArithmeticsError;
+use arithmetics::ops::add::{SimdAdd, SimdAddInto};
+use arithmetics::ops::clamp::SimdClamp;
+use arithmetics::ops::div::SimdDiv;
+use arithmetics::ops::expr::expr;
+use arithmetics::ops::fma::SimdFma;
+use arithmetics::ops::max::SimdMax;
+use arithmetics::ops::min::SimdMin;
+use arithmetics::ops::mul::SimdMul;
+use arithmetics::ops::strided::StridedSlice;
+use arithmetics::ops::sub::{SimdSub, SimdSubInto};
+use common::{assert_same, for_each_backend, values, LENGTHS};
+
+// Contiguous, interleaved channels, and strides past a register width
+const STRIDES: [usize; 6] = [1, 2, 3, 7, 16, 17];
+
+// Data ending exactly at the last element of a view of `len` elements starting at 1,
+// so that the gathers cannot rely on padding
+fn strided_data(len: usize, stride: usize, seed: u32) -> Vec<f32> {
+    values(1 + (len.max(1) - 1) * stride + usize::from(len > 0), seed)
+}
+
+#[test]
+fn view() {
+    for stride in STRIDES {
+        for len in LENGTHS {
+            let data = strided_data(len, stride, 1);
+            let view = StridedSlice::new(&data[1..], stride);
+            let want: Vec<f32> = (0..len).map(|i| data[1 + i * stride]).collect();
+
+            assert_eq!(view.len(), len);
+            assert_eq!(view.stride(), stride);
+            assert_eq!(view.to_vec(), want);
+            assert_eq!(view.get(len / 2), want.get(len / 2).copied());
+            assert_eq!(view.get(len), None);
+        }
+    }
+
+    assert_eq!(
+        StridedSlice::try_new(&[1.0], 0).err(),
+        Some(ArithmeticsError::ZeroStride)
+    );
+}
+
+#[test]
+fn binary() {
+    for_each_backend(|backend| {
+        for stride in STRIDES {
+            for len in LENGTHS {
+                let data = strided_data(len, stride, 1);
+                let a = StridedSlice::new(&data[1..], stride);
+                let x = a.to_vec();
+                let b = values(len, 2);
+
+                // Against the same operations on the contiguous copy
+                let pairs: [(&str, Vec<f32>, Vec<f32>); 8] = [
+                    (
+                        "add",
+                        a.simd_add(b.as_slice()),
+                        x.as_slice().simd_add(b.as_slice()),
+                    ),
+                    (
+                        "sub",
+                        b.as_slice().simd_sub(a),
+                        b.as_slice().simd_sub(x.as_slice()),
+                    ),
+                    ("mul", a.simd_mul(a), x.as_slice().simd_mul(x.as_slice())),
+                    ("div", 1.5.simd_div(a), 1.5.simd_div(x.as_slice())),
+                    ("min", a.simd_min(0.25), x.as_slice().simd_min(0.25)),
+                    (
+                        "max",
+                        b.as_slice().simd_max(a),
+                        b.as_slice().simd_max(x.as_slice()),
+                    ),
+                    (
+                        "fma",
+                        a.simd_fma(b.as_slice(), a),
+                        x.as_slice().simd_fma(b.as_slice(), x.as_slice()),
+                    ),
+                    (
+                        "clamp",
+                        a.simd_clamp(-0.5, 0.5),
+                        x.as_slice().simd_clamp(-0.5, 0.5),
+                    ),
+                ];
+
+                for (name, got, want) in pairs {
+                    let name = format!("{} with stride {}", name, stride);
+                    assert_same(&name, backend, &got, &want);
+                }
+
+                let want = (expr(&x) * 2.0 - &b).eval();
+                let got = (expr(a) * 2.0 - &b).eval();
+                assert_same(
+                    &format!("expr with stride {}", stride),
+                    backend,
+                    &got,
+                    &want,
+                );
+
+                let mut out = vec![f32::NAN; len];
+                a.simd_add_into(a, &mut out);
+                assert_same(
+                    &format!("add_into with stride {}", stride),
+                    backend,
+                    &out,
+                    &x.as_slice().simd_add(x.as_slice()),
+                );
+
+                3.0.simd_sub_into(a, &mut out);
+                assert_same(
+                    &format!("sub_into with stride {}", stride),
+                    backend,
+                    &out,
+                    &3.0.simd_sub(x.as_slice()),
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn matrix_columns() {
+    // Columns of a row-major matrix, the last one ending at the end of the data
+    let (rows, cols) = (1031, 13);
+    let m = values(rows * cols, 1);
+
+    for_each_backend(|backend| {
+        for j in 0..cols {
+            let column = StridedSlice::new(&m[j..], cols);
+            let want: Vec<f32> = (0..rows).map(|i| m[i * cols + j] * 2.0).collect();
+
+            assert_eq!(column.len(), rows);
+            assert_same(
+                &format!("column {}", j),
+                backend,
+                &column.simd_mul(2.0),
+                &want,
+            );
+        }
+    });
+}
+
+#[test]
+fn length_mismatch() {
+    let data = values(34, 1);
+    let a = StridedSlice::new(&data, 2);
+    let b = values(16, 2);
+
+    for_each_backend(|backend| {
+        assert_eq!(
+            a.try_simd_add(b.as_slice()),
+            Err(ArithmeticsError::LengthMismatch {
+                left: 17,
+                right: 16
+            }),
+            "{:?}",
+            backend
+        );
+
+        let mut out = vec![0.0; 16];
+        assert!(a.try_simd_add_into(a, &mut out).is_err(), "{:?}", backend);
+        assert_eq!(out, vec![0.0; 16], "{:?}", backend);
+    });
+}
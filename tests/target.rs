@@ -0,0 +1,33 @@
+//! Backend cfg the build script derives from the target features Cargo passes,
+//! the tests are compiled with the same target so `target_feature` gives the expected one
+
+use arithmetics::backend_info;
+use arithmetics::simd::backend::Backend;
+
+// Widest backend guaranteed by the target features, in the priority order of `build.rs`
+fn expected() -> &'static str {
+    if cfg!(all(target_arch = "x86_64", target_feature = "avx512f")) {
+        "avx512"
+    } else if cfg!(all(target_arch = "x86_64", target_feature = "avx2")) {
+        "avx2"
+    } else if cfg!(all(target_arch = "x86_64", target_feature = "sse4.1")) {
+        "sse4.1"
+    } else if cfg!(all(target_arch = "aarch64", target_feature = "neon")) {
+        "neon"
+    } else {
+        "baseline"
+    }
+}
+
+#[test]
+fn build_backend() {
+    assert_eq!(backend_info().build_backend, expected());
+}
+
+#[test]
+fn build_backend_runs() {
+    // The target features are guaranteed at runtime, so is the backend they select
+    let backend = Backend::from_name(backend_info().build_backend).unwrap_or(Backend::Scalar);
+
+    assert!(backend.is_supported(), "{:?}", backend);
+}
@@ -0,0 +1,321 @@
+//! Accuracy of the trigonometric functions against 120 digit references from mpmath,
+//! on every backend the CPU supports
+
+mod common;
+
+use arithmetics::ops::unary::SimdUnary;
+use common::{check, check_exact, for_each_backend};
+
+const SPECIALS: [f32; 4] = [f32::INFINITY, f32::NEG_INFINITY, f32::NAN, -0.0];
+
+#[test]
+fn sin() {
+    for_each_backend(|backend| {
+        check("sin", backend, &SIN, 1.0, |x| x.simd_sin());
+        check_exact(
+            "sin",
+            backend,
+            &[
+                (0.0, 0.0),
+                (-0.0, -0.0),
+                (f32::INFINITY, f32::NAN),
+                (f32::NEG_INFINITY, f32::NAN),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_sin(),
+        );
+    });
+}
+
+#[test]
+fn cos() {
+    for_each_backend(|backend| {
+        check("cos", backend, &COS, 1.0, |x| x.simd_cos());
+        check_exact(
+            "cos",
+            backend,
+            &[
+                (0.0, 1.0),
+                (-0.0, 1.0),
+                (f32::INFINITY, f32::NAN),
+                (f32::NEG_INFINITY, f32::NAN),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_cos(),
+        );
+    });
+}
+
+#[test]
+fn tan() {
+    for_each_backend(|backend| {
+        check("tan", backend, &TAN, 1.0, |x| x.simd_tan());
+        check_exact(
+            "tan",
+            backend,
+            &[
+                (0.0, 0.0),
+                (-0.0, -0.0),
+                (f32::INFINITY, f32::NAN),
+                (f32::NEG_INFINITY, f32::NAN),
+                (f32::NAN, f32::NAN),
+            ],
+            |x| x.simd_tan(),
+        );
+    });
+}
+
+#[test]
+fn sincos() {
+    // The tables, then a sweep across the f64 fallback past |x| = 8192 mixed with
+    // lanes below it
+    let x: Vec<f32> = SIN
+        .iter()
+        .map(|&(x, _)| x)
+        .chain(SPECIALS)
+        .chain((-40_000..40_000).map(|i| i as f32 * 0.37))
+        .collect();
+
+    for_each_backend(|backend| {
+        let (sin, cos) = x.as_slice().simd_sincos();
+        let want_sin = x.as_slice().simd_sin();
+        let want_cos = x.as_slice().simd_cos();
+
+        for (i, &x) in x.iter().enumerate() {
+            assert!(
+                sin[i].to_bits() == want_sin[i].to_bits()
+                    || (sin[i].is_nan() && want_sin[i].is_nan()),
+                "{:?} sincos({:e}).0 = {:e}, sin gives {:e}",
+                backend,
+                x,
+                sin[i],
+                want_sin[i]
+            );
+            assert!(
+                cos[i].to_bits() == want_cos[i].to_bits()
+                    || (cos[i].is_nan() && want_cos[i].is_nan()),
+                "{:?} sincos({:e}).1 = {:e}, cos gives {:e}",
+                backend,
+                x,
+                cos[i],
+                want_cos[i]
+            );
+        }
+
+        check("sincos", backend, &SIN, 1.0, |x| x.simd_sincos().0);
+        check("sincos", backend, &COS, 1.0, |x| x.simd_sincos().1);
+    });
+}
+
+const SIN: [(f32, f64); 68] = [
+    (-1.0e30, 7.911634385219837e-1),
+    (-1.0e6, 3.4999350217129294e-1),
+    (-1.0e4, 3.0561438888825215e-1),
+    (-8.1118433e3, -2.48401952842438e-1),
+    (-7.805538e3, -9.686302253989484e-1),
+    (-6.428362e3, -6.156788474839666e-1),
+    (-5.7629116e3, -9.427206727197069e-1),
+    (-3.924997e3, 9.11881932829554e-1),
+    (-3.9144885e3, -6.403517405007973e-2),
+    (-3.8025488e3, -9.396881077326313e-1),
+    (-3.4510447e3, -9.999867502819302e-1),
+    (-3.0955098e3, 8.629109455924082e-1),
+    (-2.8590122e3, -1.6217285873276313e-1),
+    (-2.828419e3, -8.335799510268881e-1),
+    (-1.7443281e3, 6.773851840498657e-1),
+    (-3.4336982e0, 2.879691950044179e-1),
+    (-2.3869526e0, -6.850264574021907e-1),
+    (-2.3795838e0, -6.903761177289717e-1),
+    (-2.0e0, -9.092974268256817e-1),
+    (-8.915055e-1, -7.780184336641854e-1),
+    (-6.8449926e-1, -6.32285152536226e-1),
+    (-5.0e-1, -4.79425538604203e-1),
+    (0.0e0, 0.0e0),
+    (1.0e-30, 1.0000000031710769e-30),
+    (1.0e-8, 9.99999993922529e-9),
+    (1.0e-3, 9.999998808307693e-4),
+    (1.0e-1, 9.98334181294999e-2),
+    (3.3954075e-1, 3.3305409670828695e-1),
+    (5.0e-1, 4.79425538604203e-1),
+    (7.853982e-1, 7.071067966408575e-1),
+    (1.0e0, 8.414709848078965e-1),
+    (1.5707964e0, 9.99999999999999e-1),
+    (1.8677646e0, 9.562280361521714e-1),
+    (2.0e0, 9.092974268256817e-1),
+    (2.4244764e0, 6.572139274040786e-1),
+    (3.1415927e0, -8.742278000372475e-8),
+    (4.712389e0, -9.999999999999999e-1),
+    (6.2831855e0, 1.7484556000744883e-7),
+    (1.0e1, -5.440211108893698e-1),
+    (1.0e2, -5.063656411097588e-1),
+    (1.8828006e2, -2.138353046001613e-1),
+    (2.5289821e2, 1.0e0),
+    (3.353175e2, 7.39891494145771e-1),
+    (5.0579642e2, -8.371413607514415e-9),
+    (1.0e3, 8.268795405320025e-1),
+    (1.01159283e3, 1.674282721502883e-8),
+    (1.1344285e3, -3.084078607602995e-1),
+    (2.0231857e3, 3.3485654430057654e-8),
+    (2.2383848e3, 9.999999999999983e-1),
+    (2.9020295e3, -7.18797362491241e-1),
+    (4.0463713e3, 6.697130886011527e-8),
+    (4.4767695e3, 1.1545536480926656e-7),
+    (4.667988e3, -4.0674691605522106e-1),
+    (6.4300664e3, 6.988265428285979e-1),
+    (8.0927427e3, 1.3394261772023025e-7),
+    (8.1265234e3, 7.009704726280244e-1),
+    (8.1398486e3, 1.7931677541485686e-2),
+    (8.191e3, -7.630067893524556e-1),
+    (8.192e3, -9.561731528431463e-1),
+    (8.1925e3, -6.987442181107376e-1),
+    (1.0e4, -3.0561438888825215e-1),
+    (1.0e5, 3.574879797201651e-2),
+    (1.0e6, -3.4999350217129294e-1),
+    (1.6e7, 5.330138724433453e-1),
+    (1.0e10, -4.875060250875107e-1),
+    (1.0e20, 6.565766778545903e-1),
+    (1.0e30, -7.911634385219837e-1),
+    (3.4e38, -2.4408487440838297e-1),
+];
+
+const COS: [(f32, f64); 68] = [
+    (-1.0e30, -6.116047854178966e-1),
+    (-1.0e6, 9.367521275331447e-1),
+    (-1.0e4, -9.521553682590148e-1),
+    (-8.1118433e3, 9.686570444817212e-1),
+    (-7.805538e3, -2.4850651187359754e-1),
+    (-6.428362e3, 7.879971806807525e-1),
+    (-5.7629116e3, 3.335831728773249e-1),
+    (-3.924997e3, -4.1045260454653815e-1),
+    (-3.9144885e3, 9.979476421558278e-1),
+    (-3.8025488e3, 3.420325425831094e-1),
+    (-3.4510447e3, -5.147743251624793e-3),
+    (-3.0955098e3, -5.053560131004833e-1),
+    (-2.8590122e3, 9.86762364447714e-1),
+    (-2.828419e3, 5.523988280635748e-1),
+    (-1.7443281e3, -7.356285152369568e-1),
+    (-3.4336982e0, -9.576396726997621e-1),
+    (-2.3869526e0, -7.285181896555531e-1),
+    (-2.3795838e0, -7.234506313975219e-1),
+    (-2.0e0, -4.161468365471424e-1),
+    (-8.915055e-1, 6.282414479153119e-1),
+    (-6.8449926e-1, 7.747357522938008e-1),
+    (-5.0e-1, 8.775825618903728e-1),
+    (0.0e0, 1.0e0),
+    (1.0e-30, 1.0e0),
+    (1.0e-8, 1.0e0),
+    (1.0e-3, 9.999994999999942e-1),
+    (1.0e-1, 9.950041651292624e-1),
+    (3.3954075e-1, 9.429077201220843e-1),
+    (5.0e-1, 8.775825618903728e-1),
+    (7.853982e-1, 7.071067657322372e-1),
+    (1.0e0, 5.403023058681398e-1),
+    (1.5707964e0, -4.371139000186241e-8),
+    (1.8677646e0, -2.926225262630367e-1),
+    (2.0e0, -4.161468365471424e-1),
+    (2.4244764e0, -7.537040889010133e-1),
+    (3.1415927e0, -9.999999999999962e-1),
+    (4.712389e0, 1.1924880454806035e-8),
+    (6.2831855e0, 9.999999999999847e-1),
+    (1.0e1, -8.390715290764524e-1),
+    (1.0e2, 8.623188722876839e-1),
+    (1.8828006e2, 9.768697264766455e-1),
+    (2.5289821e2, -4.185706803757208e-9),
+    (3.353175e2, -6.727262272951297e-1),
+    (5.0579642e2, -1.0e0),
+    (1.0e3, 5.623790762907029e-1),
+    (1.01159283e3, 9.999999999999999e-1),
+    (1.1344285e3, -9.512542201857797e-1),
+    (2.0231857e3, 9.999999999999994e-1),
+    (2.2383848e3, 5.772768240463338e-8),
+    (2.9020295e3, 6.952196427573342e-1),
+    (4.0463713e3, 9.999999999999978e-1),
+    (4.4767695e3, -9.999999999999933e-1),
+    (4.667988e3, 9.135408837482683e-1),
+    (6.4300664e3, -7.15291173605707e-1),
+    (8.0927427e3, 9.99999999999991e-1),
+    (8.1265234e3, -7.13190294734613e-1),
+    (8.1398486e3, -9.998392145442927e-1),
+    (8.191e3, -6.463904697642574e-1),
+    (8.192e3, 2.928018131467037e-1),
+    (8.1925e3, 7.153715941081348e-1),
+    (1.0e4, -9.521553682590148e-1),
+    (1.0e5, -9.993608074382124e-1),
+    (1.0e6, 9.367521275331447e-1),
+    (1.6e7, 8.46106501442312e-1),
+    (1.0e10, 8.73119622676856e-1),
+    (1.0e20, 7.542592830701055e-1),
+    (1.0e30, -6.116047854178966e-1),
+    (3.4e38, 9.697538729415026e-1),
+];
+
+const TAN: [(f32, f64); 68] = [
+    (-1.0e30, -1.2935860826880194e0),
+    (-1.0e6, 3.73624453987599e-1),
+    (-1.0e4, -3.209711346238147e-1),
+    (-8.1118433e3, -2.5643952548277305e-1),
+    (-7.805538e3, 3.8978062107750344e0),
+    (-6.428362e3, -7.813211297939927e-1),
+    (-5.7629116e3, -2.8260438456420345e0),
+    (-3.924997e3, -2.221649765962595e0),
+    (-3.9144885e3, -6.416686742377287e-2),
+    (-3.8025488e3, -2.7473646239503644e0),
+    (-3.4510447e3, 1.94257308766575e2),
+    (-3.0955098e3, -1.7075307767651513e0),
+    (-2.8590122e3, -1.6434844353182285e-1),
+    (-2.828419e3, -1.5090183191535531e0),
+    (-1.7443281e3, -9.208250768143075e-1),
+    (-3.4336982e0, -3.0070725264814885e-1),
+    (-2.3869526e0, 9.403011031558106e-1),
+    (-2.3795838e0, 9.542822796288687e-1),
+    (-2.0e0, 2.185039863261519e0),
+    (-8.915055e-1, -1.2384067244303558e0),
+    (-6.8449926e-1, -8.161300813395873e-1),
+    (-5.0e-1, -5.463024898437905e-1),
+    (0.0e0, 0.0e0),
+    (1.0e-30, 1.0000000031710769e-30),
+    (1.0e-8, 9.99999993922529e-9),
+    (1.0e-3, 1.0000003808309655e-3),
+    (1.0e-1, 1.0033467359056773e-1),
+    (3.3954075e-1, 3.532202458424715e-1),
+    (5.0e-1, 5.463024898437905e-1),
+    (7.853982e-1, 1.000000043711391e0),
+    (1.0e0, 1.5574077246549023e0),
+    (1.5707964e0, -2.287733242885646e7),
+    (1.8677646e0, -3.2677868254497384e0),
+    (2.0e0, -2.185039863261519e0),
+    (2.4244764e0, -8.719787209359202e-1),
+    (3.1415927e0, 8.742278000372508e-8),
+    (4.712389e0, -8.385828300668408e7),
+    (6.2831855e0, 1.748455600074515e-7),
+    (1.0e1, 6.483608274590866e-1),
+    (1.0e2, -5.872139151569291e-1),
+    (1.8828006e2, -2.1889848646596746e-1),
+    (2.5289821e2, -2.3890827687748507e8),
+    (3.353175e2, -1.099840416688817e0),
+    (5.0579642e2, 8.371413607514415e-9),
+    (1.0e3, 1.4703241557027185e0),
+    (1.01159283e3, 1.6742827215028834e-8),
+    (1.1344285e3, 3.242118186871934e-1),
+    (2.0231857e3, 3.3485654430057674e-8),
+    (2.2383848e3, 1.732271171031345e7),
+    (2.9020295e3, -1.0339140586425242e0),
+    (4.0463713e3, 6.697130886011543e-8),
+    (4.4767695e3, -1.1545536480926733e-7),
+    (4.667988e3, -4.4524215970098024e-1),
+    (6.4300664e3, -9.769819181549344e-1),
+    (8.0927427e3, 1.3394261772023144e-7),
+    (8.1265234e3, -9.82865972522613e-1),
+    (8.1398486e3, -1.79345611580744e-2),
+    (8.191e3, 1.1804115701624265e0),
+    (8.192e3, -3.265598469378572e0),
+    (8.1925e3, -9.767570083375664e-1),
+    (1.0e4, 3.209711346238147e-1),
+    (1.0e5, -3.5771662952898776e-2),
+    (1.0e6, -3.73624453987599e-1),
+    (1.6e7, 6.299607337075716e-1),
+    (1.0e10, -5.583496378112418e-1),
+    (1.0e20, 8.70492007976472e-1),
+    (1.0e30, 1.2935860826880194e0),
+    (3.4e38, -2.516977567390511e-1),
+];
@@ -0,0 +1,87 @@
+//! The exact unary operations give the results of std on every backend the CPU
+//! supports, across the register tails and the parallel chunk boundaries
+
+mod common;
+
+use arithmetics::ops::unary::{SimdUnary, SimdUnaryAssign, UnaryFn};
+use common::{assert_same, for_each_backend, values, LENGTHS};
+
+// Signed zeros, infinities, NaN, ties of the rounding modes, the largest f32 with a
+// fraction and subnormals
+const SPECIALS: [f32; 18] = [
+    0.0,
+    -0.0,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+    0.5,
+    -0.5,
+    1.5,
+    -1.5,
+    2.5,
+    -2.5,
+    0.49999997,
+    8388607.5,
+    -8388607.5,
+    16777216.0,
+    f32::MAX,
+    1e-45,
+    -1e-40,
+];
+
+// The std counterpart of each function
+type Reference = fn(f32) -> f32;
+
+const CASES: [(UnaryFn, Reference); 8] = [
+    (UnaryFn::ABS, f32::abs),
+    (UnaryFn::NEG, |x| -x),
+    (UnaryFn::SQRT, f32::sqrt),
+    (UnaryFn::RECIP, f32::recip),
+    (UnaryFn::FLOOR, f32::floor),
+    (UnaryFn::CEIL, f32::ceil),
+    (UnaryFn::ROUND, f32::round_ties_even),
+    (UnaryFn::TRUNC, f32::trunc),
+];
+
+#[test]
+fn exact() {
+    for_each_backend(|backend| {
+        for len in LENGTHS {
+            let x: Vec<f32> = SPECIALS.iter().copied().chain(values(len, 1)).collect();
+
+            for (f, std) in CASES {
+                let want: Vec<f32> = x.iter().map(|&x| std(x)).collect();
+                assert_same(f.name(), backend, &x.as_slice().simd_map(f), &want);
+
+                let mut y = x.clone();
+                y.simd_map_assign(f);
+                assert_same(f.name(), backend, &y, &want);
+            }
+        }
+    });
+}
+
+#[test]
+fn shorthands() {
+    let x: Vec<f32> = SPECIALS.iter().copied().chain(values(100, 2)).collect();
+    let x = x.as_slice();
+
+    for_each_backend(|backend| {
+        let pairs = [
+            (x.simd_abs(), UnaryFn::ABS),
+            (x.simd_neg(), UnaryFn::NEG),
+            (x.simd_sqrt(), UnaryFn::SQRT),
+            (x.simd_rsqrt(), UnaryFn::RSQRT),
+            (x.simd_rsqrt_approx(), UnaryFn::RSQRT_APPROX),
+            (x.simd_recip(), UnaryFn::RECIP),
+            (x.simd_floor(), UnaryFn::FLOOR),
+            (x.simd_ceil(), UnaryFn::CEIL),
+            (x.simd_round(), UnaryFn::ROUND),
+            (x.simd_trunc(), UnaryFn::TRUNC),
+        ];
+
+        for (got, f) in pairs {
+            assert_same(f.name(), backend, &got, &x.simd_map(f));
+        }
+    });
+}